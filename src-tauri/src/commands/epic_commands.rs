@@ -2,11 +2,14 @@
 use tauri::Emitter;
 
 use crate::commands::epic_login_window::EpicLoginWindow;
+use crate::services::launch_service;
 use crate::utils::{
     epic_api::{self, EpicApi},
     mod_profile,
 };
 
+pub use launch_service::EpicLaunchPrecheckResult;
+
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EpicLoginStatus {
@@ -133,3 +136,10 @@ pub async fn epic_logout() -> Result<(), String> {
     ensure_epic_login_enabled()?;
     epic_api::clear_session()
 }
+
+/// ゲームを起動せずに、保存済みセッションでEpic起動用トークンを発行できるか確認する。
+#[tauri::command]
+pub async fn epic_launch_precheck() -> Result<EpicLaunchPrecheckResult, String> {
+    ensure_epic_login_enabled()?;
+    launch_service::epic_launch_precheck().await
+}
@@ -1,14 +1,86 @@
 // Among Usのインストール場所検出を公開するコマンド群。
-use crate::utils::finder::{detect_platform, get_among_us_paths};
+use crate::utils::finder::{detect_platform, get_among_us_paths, scan_among_us_paths};
+use crate::utils::settings;
 use serde::Serialize;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, Runtime};
 
-#[derive(Debug, Serialize)]
+const FINDER_PROGRESS_EVENT: &str = "finder-progress";
+
+// 同時に複数のスキャンが走っても互いに中断し合わないよう、スキャンごとに中断フラグを持つ。
+static FINDER_SCAN_CANCEL_TOKENS: Mutex<Vec<(String, Arc<AtomicBool>)>> = Mutex::new(Vec::new());
+static NEXT_FINDER_SCAN_ID: AtomicU64 = AtomicU64::new(1);
+
+/// 実行中スキャンの中断トークン。破棄時に登録を外す。
+struct FinderScanCancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl FinderScanCancelToken {
+    fn register(scan_id: &str) -> Self {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        if let Ok(mut tokens) = FINDER_SCAN_CANCEL_TOKENS.lock() {
+            tokens.push((scan_id.to_string(), Arc::clone(&cancelled)));
+        }
+        Self { cancelled }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for FinderScanCancelToken {
+    fn drop(&mut self) {
+        if let Ok(mut tokens) = FINDER_SCAN_CANCEL_TOKENS.lock() {
+            tokens.retain(|(_, flag)| !Arc::ptr_eq(flag, &self.cancelled));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DetectedPlatform {
     pub path: String,
     pub platform: String,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinderProgressPayload {
+    pub scan_id: String,
+    pub checking_path: String,
+    pub index: usize,
+    pub total: usize,
+    pub detected: Option<DetectedPlatform>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinderScanResult {
+    pub scan_id: String,
+    pub detected: Vec<DetectedPlatform>,
+    pub cancelled: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PathDriftStatus {
+    pub saved_path: String,
+    pub saved_path_valid: bool,
+    pub stale: bool,
+    pub detected: Option<DetectedPlatform>,
+}
+
+fn to_detected_platform(path: &str) -> Option<DetectedPlatform> {
+    detect_platform(path).ok().map(|platform| DetectedPlatform {
+        path: path.to_string(),
+        platform,
+    })
+}
+
 /// 検出された全Among Usインストール候補とそのプラットフォームを返す。
 /// 検出されたもののみ表示する（SteamだけならSteamのみ、EpicだけならEpicのみ）。
 #[tauri::command]
@@ -16,18 +88,92 @@ pub fn finder_detect_platforms() -> Result<Vec<DetectedPlatform>, String> {
     let paths = get_among_us_paths();
     let mut result = Vec::with_capacity(paths.len());
     for path in paths {
-        let path_str = path.to_string_lossy().to_string();
         // 検証に失敗した候補は返却対象から除外する。
-        if let Ok(platform) = detect_platform(&path_str) {
-            result.push(DetectedPlatform {
-                path: path_str,
-                platform,
-            });
+        if let Some(detected) = to_detected_platform(&path.to_string_lossy()) {
+            result.push(detected);
         }
     }
     Ok(result)
 }
 
+/// 候補ごとに "finder-progress" を通知しながら非同期で検出する。
+/// 中断された場合もそれまでに見つかった候補を返す。
+/// `scan_id` 未指定時は採番し、進捗イベントと結果に含めて返す。
+#[tauri::command]
+pub async fn finder_scan<R: Runtime>(
+    app: AppHandle<R>,
+    scan_id: Option<String>,
+) -> Result<FinderScanResult, String> {
+    let scan_id = scan_id
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .unwrap_or_else(|| {
+            format!(
+                "scan-{}",
+                NEXT_FINDER_SCAN_ID.fetch_add(1, Ordering::SeqCst)
+            )
+        });
+    // 開始前に届いた中断要求が紛れ込まないよう、トークンは開始時に新しく作る。
+    let cancel = FinderScanCancelToken::register(&scan_id);
+
+    // ドライブ走査はブロッキングI/Oのため、専用スレッドで実行する。
+    tauri::async_runtime::spawn_blocking(move || {
+        let emit_progress = |index, total, candidate: &Path, detected| {
+            let _ = app.emit(
+                FINDER_PROGRESS_EVENT,
+                FinderProgressPayload {
+                    scan_id: scan_id.clone(),
+                    checking_path: candidate.to_string_lossy().to_string(),
+                    index,
+                    total,
+                    detected,
+                },
+            );
+        };
+
+        let mut detected = Vec::new();
+        let (_, cancelled) = scan_among_us_paths(
+            |index, total, candidate| emit_progress(index, total, candidate, None),
+            |index, total, candidate| {
+                // 見つかった候補は即座に通知し、UIが部分結果を表示できるようにする。
+                if let Some(found) = to_detected_platform(&candidate.to_string_lossy()) {
+                    emit_progress(index, total, candidate, Some(found.clone()));
+                    detected.push(found);
+                }
+            },
+            || cancel.is_cancelled(),
+        );
+        FinderScanResult {
+            scan_id: scan_id.clone(),
+            detected,
+            cancelled,
+        }
+    })
+    .await
+    .map_err(|e| format!("Finder scan task failed: {e}"))
+}
+
+/// 実行中の検出スキャンへ中断を要求する。`scan_id` 未指定時は実行中の全スキャンが対象。
+/// 中断を受け付けたスキャンがあれば true を返す。
+#[tauri::command]
+pub fn finder_scan_cancel(scan_id: Option<String>) -> bool {
+    let scan_id = scan_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|id| !id.is_empty());
+    let Ok(tokens) = FINDER_SCAN_CANCEL_TOKENS.lock() else {
+        return false;
+    };
+    let mut requested = false;
+    for (token_scan_id, flag) in tokens.iter() {
+        if scan_id.is_none_or(|scan_id| scan_id == token_scan_id) {
+            flag.store(true, Ordering::SeqCst);
+            requested = true;
+        }
+    }
+    requested
+}
+
 /// Among Usのインストール候補を検出し、先頭候補を返す。
 #[tauri::command]
 pub fn finder_detect_among_us() -> Result<String, String> {
@@ -38,6 +184,44 @@ pub fn finder_detect_among_us() -> Result<String, String> {
         .ok_or_else(|| "Among Us installation not found".to_string())
 }
 
+/// 保存済みのAmong Usパスが無効になっていないかを確認し、移動先の候補を返す。
+/// Steamライブラリの移動や再インストール後の起動失敗を防ぐために使う。
+#[tauri::command]
+pub fn path_drift_check<R: Runtime>(app: AppHandle<R>) -> Result<PathDriftStatus, String> {
+    let launcher_settings = settings::load_or_init_settings(&app)?;
+    let saved_path = launcher_settings.among_us_path.trim().to_string();
+    let saved_path_valid = !saved_path.is_empty() && detect_platform(&saved_path).is_ok();
+
+    // 未設定は初回セットアップの領分なので、移動とはみなさない。
+    if saved_path.is_empty() || saved_path_valid {
+        return Ok(PathDriftStatus {
+            saved_path,
+            saved_path_valid,
+            stale: false,
+            detected: None,
+        });
+    }
+
+    // 同じプラットフォームの候補を優先し、無ければ他の候補で代替する。
+    let candidates = get_among_us_paths()
+        .iter()
+        .filter_map(|path| to_detected_platform(&path.to_string_lossy()))
+        .collect::<Vec<_>>();
+    let saved_platform = launcher_settings.game_platform.as_str();
+    let detected = candidates
+        .iter()
+        .find(|candidate| candidate.platform == saved_platform)
+        .or_else(|| candidates.first())
+        .cloned();
+
+    Ok(PathDriftStatus {
+        saved_path,
+        saved_path_valid,
+        stale: detected.is_some(),
+        detected,
+    })
+}
+
 /// 指定パスからプラットフォーム種別を判定する。
 #[tauri::command]
 pub fn finder_detect_platform(path: String) -> Result<String, String> {
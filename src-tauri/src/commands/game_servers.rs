@@ -15,3 +15,13 @@ pub async fn game_servers_join_direct(
     ensure_game_servers_enabled()?;
     game_server_service::join_direct(query).await
 }
+
+/// 参加前に、ルームコードの部屋が存在し参加可能かを rooms API で確認する。
+#[tauri::command]
+pub async fn game_servers_room_check(
+    server_id: String,
+    room_code: String,
+) -> Result<game_server_service::GameServerRoomCheck, String> {
+    ensure_game_servers_enabled()?;
+    game_server_service::check_room(server_id, room_code).await
+}
@@ -3,7 +3,8 @@
 
 use tauri::{AppHandle, Runtime};
 
-use crate::services::launch_service;
+use crate::services::{launch_service, profile_service};
+use crate::utils::antivirus;
 
 pub use launch_service::{
     clear_autolaunch_error, launch_modded_from_saved_settings, set_autolaunch_error,
@@ -24,12 +25,44 @@ pub fn launch_game_running_get<R: Runtime>(app: AppHandle<R>) -> Result<bool, St
     launch_service::is_game_running(app)
 }
 
+/// 実行状態に加え、追跡中ならPIDと起動時刻を返す。ウィンドウ再生成後の同期に使う。
+#[tauri::command]
+pub fn game_state_get<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<launch_service::GameStateSnapshot, String> {
+    launch_service::game_state_snapshot(app)
+}
+
 /// Steam クライアントが起動中かを返す。
 #[tauri::command]
 pub fn launch_steam_running_get() -> bool {
     launch_service::is_steam_running()
 }
 
+/// 起動前チェックをまとめて実行し、項目ごとの結果を返す。
+#[tauri::command]
+pub async fn launch_preflight<R: Runtime>(
+    app: AppHandle<R>,
+    modded: bool,
+) -> Result<launch_service::LaunchPreflightReport, String> {
+    launch_service::launch_preflight(app, modded).await
+}
+
+/// 任意のフォルダがModdedプロファイルとして使えるかを構成要素ごとに検証する。
+#[tauri::command]
+pub fn profile_validate(path: String) -> Result<launch_service::ProfileValidationReport, String> {
+    launch_service::validate_profile(&path)
+}
+
+/// Windows セキュリティ センターからウイルス対策のリアルタイム保護状態を取得する。
+#[tauri::command]
+pub async fn av_status() -> Result<antivirus::AvStatus, String> {
+    // PowerShell の起動待ちで UI を止めないよう、別スレッドで照会する。
+    tauri::async_runtime::spawn_blocking(antivirus::av_status)
+        .await
+        .map_err(|e| format!("Antivirus status task failed: {e}"))
+}
+
 /// Mod起動ショートカットを作成する。
 #[tauri::command]
 pub fn launch_shortcut_create() -> Result<String, String> {
@@ -37,6 +70,33 @@ pub fn launch_shortcut_create() -> Result<String, String> {
     launch_service::create_modded_launch_shortcut()
 }
 
+/// デスクトップ上のMod起動ショートカットを列挙する。
+#[tauri::command]
+pub fn shortcuts_list() -> Result<Vec<String>, String> {
+    launch_service::list_modded_launch_shortcuts()
+}
+
+/// 重複したMod起動ショートカットを削除し、1つだけ残す。
+#[tauri::command]
+pub fn shortcuts_cleanup() -> Result<launch_service::ShortcutCleanupResult, String> {
+    launch_service::cleanup_modded_launch_shortcuts()
+}
+
+/// Windowsログオン時の自動起動登録状態を返す。
+#[tauri::command]
+pub fn startup_autolaunch_get() -> Result<launch_service::StartupAutolaunchStatus, String> {
+    launch_service::startup_autolaunch_status()
+}
+
+/// Windowsログオン時の自動起動を登録または解除する。
+#[tauri::command]
+pub fn startup_autolaunch_set(
+    enable: bool,
+    autolaunch_modded: Option<bool>,
+) -> Result<launch_service::StartupAutolaunchStatus, String> {
+    launch_service::set_startup_autolaunch(enable, autolaunch_modded.unwrap_or(false))
+}
+
 /// Modded起動時にBepInExの初回セットアップが必要かを返す。
 #[tauri::command]
 pub fn launch_modded_first_setup_pending<R: Runtime>(
@@ -47,6 +107,27 @@ pub fn launch_modded_first_setup_pending<R: Runtime>(
     launch_service::modded_first_setup_pending(&app, game_exe)
 }
 
+/// 初回Modded起動中のinterop生成状況を返す。
+#[tauri::command]
+pub fn interop_generation_status<R: Runtime>(
+    app: AppHandle<R>,
+    game_exe: String,
+) -> Result<launch_service::InteropGenerationStatus, String> {
+    launch_service::interop_generation_status(&app, game_exe)
+}
+
+/// ゲームフォルダに残っているDoorstop関連ファイルを返す。
+#[tauri::command]
+pub fn launch_doorstop_artifacts_get(game_exe: String) -> Result<Vec<String>, String> {
+    launch_service::doorstop_artifacts(game_exe)
+}
+
+/// ゲームフォルダのDoorstop関連ファイルを改名して無効化する。
+#[tauri::command]
+pub fn launch_doorstop_artifacts_disable(game_exe: String) -> Result<Vec<String>, String> {
+    launch_service::disable_doorstop_artifacts(game_exe)
+}
+
 /// Modded起動を実行する。
 #[tauri::command]
 pub async fn launch_modded<R: Runtime>(
@@ -57,7 +138,28 @@ pub async fn launch_modded<R: Runtime>(
 ) -> Result<(), String> {
     // 起動前検証と実プロセス制御はサービス層へ集約し、この境界は委譲に徹する。
     // 実行失敗時の詳細メッセージはそのままフロントへ伝搬する。
-    launch_service::launch_modded(app, game_exe, profile_path, platform).await
+    let notify_app = app.clone();
+    let checked_profile_path = profile_path.clone();
+    launch_service::launch_modded(app, game_exe, profile_path, platform)
+        .await
+        .inspect_err(|_| {
+            profile_service::emit_if_profile_missing(&notify_app, Some(&checked_profile_path))
+        })
+}
+
+/// 起動済みゲームのウィンドウが表示されるまで待機する。
+#[tauri::command]
+pub async fn launch_wait_ready<R: Runtime>(
+    app: AppHandle<R>,
+    timeout_ms: Option<u64>,
+) -> Result<launch_service::GameReadyResult, String> {
+    launch_service::wait_for_game_ready(app, timeout_ms).await
+}
+
+/// 実行中のAmong Usプロセスを一覧する。
+#[tauri::command]
+pub fn game_processes_list<R: Runtime>(app: AppHandle<R>) -> Vec<launch_service::GameProcessInfo> {
+    launch_service::list_game_processes(&app)
 }
 
 /// Modded起動を管理者権限で再実行する。
@@ -68,7 +170,13 @@ pub async fn launch_modded_elevated<R: Runtime>(
     profile_path: String,
     platform: String,
 ) -> Result<(), String> {
-    launch_service::launch_modded_elevated(app, game_exe, profile_path, platform).await
+    let notify_app = app.clone();
+    let checked_profile_path = profile_path.clone();
+    launch_service::launch_modded_elevated(app, game_exe, profile_path, platform)
+        .await
+        .inspect_err(|_| {
+            profile_service::emit_if_profile_missing(&notify_app, Some(&checked_profile_path))
+        })
 }
 
 /// Vanilla起動を実行する。
@@ -2,7 +2,9 @@
 use std::path::PathBuf;
 use tauri::{AppHandle, Runtime};
 
-use crate::utils::{migration, mod_profile};
+use crate::services::launch_service;
+use crate::services::snr_service::{self, OperationKind};
+use crate::utils::{file_dialog, migration, mod_profile, settings};
 
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -14,6 +16,17 @@ pub struct MigrationExportResult {
     pub encrypted: bool,
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationEstimateResult {
+    pub included_files: usize,
+    pub profile_files: usize,
+    pub locallow_files: usize,
+    pub source_bytes: u64,
+    pub estimated_archive_bytes: u64,
+    pub encrypted: bool,
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MigrationImportResult {
@@ -21,12 +34,53 @@ pub struct MigrationImportResult {
     pub profile_files: usize,
     pub locallow_files: usize,
     pub encrypted: bool,
+    pub format: migration::MigrationArchiveFormat,
+    pub reexported_archive_path: Option<String>,
+    pub missing_profile_files: Option<Vec<String>>,
+}
+
+/// 選択ダイアログ経由の取り込み結果。キャンセル時はエラーではなく cancelled を返す。
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum MigrationDialogImportResult {
+    Imported(MigrationImportResult),
+    Cancelled,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MigrationPasswordValidationResult {
     pub encrypted: bool,
+    pub format: migration::MigrationArchiveFormat,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationArchiveInfoResult {
+    pub format: migration::MigrationArchiveFormat,
+    pub version: Option<u8>,
+    pub encrypted: bool,
+    pub file_size: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetainedMigrationBackupResult {
+    pub backup_id: String,
+    pub path: String,
+    pub has_profile_backup: bool,
+    pub has_locallow_backup: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalLowWritableCheck {
+    /// 書き込み可能で、ゲームも起動していない場合は true。
+    pub ready: bool,
+    pub path: Option<String>,
+    pub writable: bool,
+    pub game_running: bool,
+    pub message: Option<String>,
 }
 
 fn ensure_migration_enabled() -> Result<(), String> {
@@ -34,6 +88,37 @@ fn ensure_migration_enabled() -> Result<(), String> {
     mod_profile::ensure_feature_enabled(mod_profile::Feature::Migration)
 }
 
+fn check_locallow_writable<R: Runtime>(app: &AppHandle<R>) -> LocalLowWritableCheck {
+    // 起動中のゲームがファイルを掴んでいると取り込みが途中で失敗するため、先に確認する。
+    let game_running = launch_service::is_game_running(app.clone()).unwrap_or(false);
+    let probe = migration::probe_locallow_write_access();
+    let message = if game_running {
+        Some("Close the game before importing migration data.".to_string())
+    } else {
+        probe.as_ref().err().cloned()
+    };
+
+    LocalLowWritableCheck {
+        ready: message.is_none(),
+        path: probe
+            .as_ref()
+            .ok()
+            .map(|path| path.to_string_lossy().to_string()),
+        writable: probe.is_ok(),
+        game_running,
+        message,
+    }
+}
+
+/// お引越しデータの取り込み前に、LocalLow への書き込み可否とゲームの起動状態を確認する。
+#[tauri::command]
+pub fn locallow_writable_check<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<LocalLowWritableCheck, String> {
+    ensure_migration_enabled()?;
+    Ok(check_locallow_writable(&app))
+}
+
 /// お引越しデータを書き出す。
 #[tauri::command]
 pub fn migration_export<R: Runtime>(
@@ -41,14 +126,21 @@ pub fn migration_export<R: Runtime>(
     output_path: Option<String>,
     encryption_enabled: Option<bool>,
     password: Option<String>,
+    excluded_preset_ids: Option<Vec<i32>>,
 ) -> Result<MigrationExportResult, String> {
     ensure_migration_enabled()?;
-    // オプションの未指定時は既定値(暗号化なし)を適用する。
+    let _operation = snr_service::begin_operation(OperationKind::MigrationExport);
+    // 暗号化指定が省略された場合は設定の既定値を使う。パスワードは保存しない。
+    let encryption_enabled = match encryption_enabled {
+        Some(enabled) => enabled,
+        None => settings::load_or_init_settings(&app)?.migration_encrypt_by_default,
+    };
     let result = migration::export_migration_data(
         &app,
         output_path,
-        encryption_enabled.unwrap_or(false),
+        encryption_enabled,
         password,
+        &excluded_preset_ids.unwrap_or_default(),
     )?;
 
     Ok(MigrationExportResult {
@@ -60,12 +152,64 @@ pub fn migration_export<R: Runtime>(
     })
 }
 
+/// 見積もる書き出しの種類。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MigrationEstimateScope {
+    /// `migration_export` の出力。`excluded_preset_ids` と暗号化指定を反映する。
+    #[default]
+    Migration,
+    /// `presets_export` の出力。`preset_ids` で選んだプリセットだけを含む。
+    Presets,
+}
+
+/// 書き出しサイズを見積もる。ファイルは書き出さない。
+/// 見積もり対象の書き出しと同じ入力を受け取り、実際に含まれるファイルだけを合計する。
+#[tauri::command]
+pub fn migration_estimate<R: Runtime>(
+    app: AppHandle<R>,
+    scope: Option<MigrationEstimateScope>,
+    encryption_enabled: Option<bool>,
+    excluded_preset_ids: Option<Vec<i32>>,
+    preset_ids: Option<Vec<i32>>,
+) -> Result<MigrationEstimateResult, String> {
+    ensure_migration_enabled()?;
+    let result = match scope.unwrap_or_default() {
+        MigrationEstimateScope::Migration => {
+            let encryption_enabled = match encryption_enabled {
+                Some(enabled) => enabled,
+                None => settings::load_or_init_settings(&app)?.migration_encrypt_by_default,
+            };
+            migration::estimate_migration_export(
+                &app,
+                encryption_enabled,
+                &excluded_preset_ids.unwrap_or_default(),
+            )?
+        }
+        MigrationEstimateScope::Presets => {
+            mod_profile::ensure_feature_enabled(mod_profile::Feature::Presets)?;
+            migration::estimate_presets_export(&app, preset_ids.unwrap_or_default())?
+        }
+    };
+
+    Ok(MigrationEstimateResult {
+        included_files: result.profile_files + result.locallow_files,
+        profile_files: result.profile_files,
+        locallow_files: result.locallow_files,
+        source_bytes: result.source_bytes,
+        estimated_archive_bytes: result.estimated_archive_bytes,
+        encrypted: result.encrypted,
+    })
+}
+
 /// お引越しデータを読み込む。
 #[tauri::command]
 pub fn migration_import<R: Runtime>(
     app: AppHandle<R>,
     archive_path: String,
     password: Option<String>,
+    reexport_legacy: Option<bool>,
+    verify_profile: Option<bool>,
 ) -> Result<MigrationImportResult, String> {
     ensure_migration_enabled()?;
     // 空文字の誤入力を防ぐため、パスはトリムして検証する。
@@ -74,16 +218,65 @@ pub fn migration_import<R: Runtime>(
         return Err("Migration archive path is required".to_string());
     }
 
-    let result = migration::import_migration_data(&app, &PathBuf::from(normalized), password)?;
+    // 確認から完了まで排他し、別の取り込みや復元と同じバックアップ置き場を使わせない。
+    let _import_guard = migration::MigrationImportGuard::acquire()?;
+    if let Some(message) = check_locallow_writable(&app).message {
+        return Err(message);
+    }
+
+    let _operation = snr_service::begin_operation(OperationKind::MigrationImport);
+    let result = migration::import_migration_data(
+        &app,
+        &PathBuf::from(normalized),
+        password,
+        reexport_legacy.unwrap_or(false),
+        verify_profile.unwrap_or(false),
+    )?;
 
     Ok(MigrationImportResult {
         imported_files: result.imported_files,
         profile_files: result.profile_files,
         locallow_files: result.locallow_files,
         encrypted: result.encrypted,
+        format: result.format,
+        reexported_archive_path: result
+            .reexported_archive_path
+            .map(|path| path.to_string_lossy().to_string()),
+        missing_profile_files: result.missing_profile_files.map(|paths| {
+            paths
+                .iter()
+                .map(|path| path.to_string_lossy().to_string())
+                .collect()
+        }),
     })
 }
 
+/// 開くダイアログで選んだお引越しアーカイブを取り込む。
+#[tauri::command]
+pub async fn migration_import_dialog<R: Runtime>(
+    app: AppHandle<R>,
+    password: Option<String>,
+    reexport_legacy: Option<bool>,
+    verify_profile: Option<bool>,
+) -> Result<MigrationDialogImportResult, String> {
+    ensure_migration_enabled()?;
+    // 拡張子の絞り込みは取り込み時の判定と同じ一覧を使う。
+    let extensions = migration::supported_archive_extensions();
+    let selected = file_dialog::pick_open_path(&app, extensions[0], extensions).await?;
+    let Some(archive_path) = selected else {
+        return Ok(MigrationDialogImportResult::Cancelled);
+    };
+
+    migration_import(
+        app,
+        archive_path.to_string_lossy().to_string(),
+        password,
+        reexport_legacy,
+        verify_profile,
+    )
+    .map(MigrationDialogImportResult::Imported)
+}
+
 /// お引越しアーカイブのパスワードを検証する。
 #[tauri::command]
 pub fn migration_validate_archive_password(
@@ -101,5 +294,75 @@ pub fn migration_validate_archive_password(
         migration::validate_migration_archive_password(&PathBuf::from(normalized), password)?;
     Ok(MigrationPasswordValidationResult {
         encrypted: result.encrypted,
+        format: result.format,
     })
 }
+
+/// お引越しアーカイブのヘッダだけを読み、形式と暗号化の有無を返す。パスワードは不要。
+#[tauri::command]
+pub fn migration_archive_info(path: String) -> Result<MigrationArchiveInfoResult, String> {
+    ensure_migration_enabled()?;
+    let normalized = path.trim();
+    if normalized.is_empty() {
+        return Err("Migration archive path is required".to_string());
+    }
+
+    let info = migration::read_migration_archive_info(&PathBuf::from(normalized))?;
+    Ok(MigrationArchiveInfoResult {
+        format: info.format,
+        version: info.version,
+        encrypted: info.encrypted,
+        file_size: info.file_size,
+    })
+}
+
+/// 巻き戻しに失敗して残された取り込みバックアップを一覧する。
+#[tauri::command]
+pub fn migration_backups_list<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Vec<RetainedMigrationBackupResult>, String> {
+    ensure_migration_enabled()?;
+    // 取り込み中は実行中の巻き戻し用バックアップと区別できないため、一覧に含めない。
+    if migration::is_migration_import_in_progress() {
+        return Ok(Vec::new());
+    }
+    let backups = migration::list_retained_migration_backups(&app)?;
+    Ok(backups
+        .into_iter()
+        .map(|backup| RetainedMigrationBackupResult {
+            backup_id: backup.backup_id,
+            path: backup.path.to_string_lossy().to_string(),
+            has_profile_backup: backup.has_profile_backup,
+            has_locallow_backup: backup.has_locallow_backup,
+        })
+        .collect())
+}
+
+/// 残された取り込みバックアップから手動で復元する。
+#[tauri::command]
+pub fn migration_recover_backup<R: Runtime>(
+    app: AppHandle<R>,
+    backup_id: String,
+) -> Result<(), String> {
+    ensure_migration_enabled()?;
+    // 実行中の取り込みのバックアップは巻き戻しに使うため、完了まで同じガードで排他する。
+    let _import_guard = migration::MigrationImportGuard::acquire()?;
+    // 取り込みと同じく LocalLow を上書きするため、同じ前提条件を確認する。
+    if let Some(message) = check_locallow_writable(&app).message {
+        return Err(message);
+    }
+    // 復元も取り込み処理の一部として実行状態に記録する。
+    let _operation = snr_service::begin_operation(OperationKind::MigrationImport);
+    migration::recover_migration_backup(&app, &backup_id)
+}
+
+/// 不要になった取り込みバックアップを削除する。
+#[tauri::command]
+pub fn migration_backup_discard<R: Runtime>(
+    app: AppHandle<R>,
+    backup_id: String,
+) -> Result<(), String> {
+    ensure_migration_enabled()?;
+    let _import_guard = migration::MigrationImportGuard::acquire()?;
+    migration::discard_migration_backup(&app, &backup_id)
+}
@@ -6,8 +6,10 @@ pub mod finder;
 pub mod game_servers;
 pub mod launch;
 pub mod migration;
+pub mod modprofile;
 pub mod notifications;
 pub mod presets;
+pub mod profiles;
 pub mod reporting;
 pub mod settings;
 pub mod snr;
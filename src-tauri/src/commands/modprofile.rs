@@ -0,0 +1,95 @@
+//! mod.config.json 関連のcommand境界。
+//! 同梱設定の検証ロジックを外部ファイル向けに公開する。
+
+use std::path::PathBuf;
+
+use crate::utils::mod_profile::{self, FeatureFlags, ModProfile, OfficialLink};
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModLinks {
+    pub wiki_url: String,
+    pub support_discord_url: Option<String>,
+    pub official: Vec<OfficialLink>,
+}
+
+/// 実際に使われている mod.config.json の解釈結果。派生値はOS依存の区切りで解決済み。
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectiveModProfile {
+    pub profile: ModProfile,
+    pub github_releases_api_url: String,
+    pub github_release_by_tag_api_base_url: String,
+    pub launcher_user_agent: String,
+    pub save_data_root: String,
+    pub local_low_root: String,
+    pub local_low_additional_prefixes: Vec<String>,
+    pub profile_required_files: Vec<String>,
+    pub default_game_server_id: Option<String>,
+}
+
+fn path_to_string(path: PathBuf) -> String {
+    path.to_string_lossy().to_string()
+}
+
+/// 指定した mod.config.json を検証し、正規化済みの内容を返す。
+#[tauri::command]
+pub fn modprofile_validate_file(path: String) -> Result<ModProfile, String> {
+    let trimmed = path.trim();
+    if trimmed.is_empty() {
+        return Err("Mod config path is required".to_string());
+    }
+    mod_profile::validate_mod_profile_file(&PathBuf::from(trimmed))
+}
+
+/// 同梱 mod.config.json の正規化後の内容と、そこから導出した値を返す。
+/// 配布者が設定の解釈を確認するための読み取り専用ダンプ。
+#[tauri::command]
+pub fn modprofile_effective() -> EffectiveModProfile {
+    let profile = mod_profile::get();
+    EffectiveModProfile {
+        profile: profile.clone(),
+        github_releases_api_url: mod_profile::github_releases_api_url(),
+        github_release_by_tag_api_base_url: mod_profile::github_release_by_tag_api_base_url(),
+        launcher_user_agent: mod_profile::launcher_user_agent(),
+        save_data_root: path_to_string(mod_profile::save_data_root_path()),
+        local_low_root: path_to_string(mod_profile::local_low_root_path()),
+        local_low_additional_prefixes: mod_profile::local_low_additional_prefix_paths()
+            .into_iter()
+            .map(path_to_string)
+            .collect(),
+        profile_required_files: profile
+            .paths
+            .profile_required_files
+            .iter()
+            .map(|file| path_to_string(mod_profile::to_relative_path(file)))
+            .collect(),
+        default_game_server_id: mod_profile::default_game_server_id().map(str::to_string),
+    }
+}
+
+/// mod.config.json の機能フラグを返す。UIのタブ表示判定に使う。
+#[tauri::command]
+pub fn features_get() -> FeatureFlags {
+    mod_profile::get().features.clone()
+}
+
+/// mod.config.json に設定された公式リンク群を返す。
+/// connectLinks 無効時は、UIと同様にWiki以外のリンクを返さない。
+#[tauri::command]
+pub fn links_get() -> ModLinks {
+    let links = &mod_profile::get().links;
+    if !mod_profile::feature_enabled(mod_profile::Feature::ConnectLinks) {
+        return ModLinks {
+            wiki_url: links.wiki_url.clone(),
+            support_discord_url: None,
+            official: Vec::new(),
+        };
+    }
+
+    ModLinks {
+        wiki_url: links.wiki_url.clone(),
+        support_discord_url: Some(links.support_discord_url.clone()),
+        official: links.official.clone(),
+    }
+}
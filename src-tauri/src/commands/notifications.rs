@@ -1,9 +1,47 @@
 // バックグラウンド通知の遷移先取得コマンド。
-use crate::utils::background_notifications::{self, NotificationOpenTarget};
+use tauri::{AppHandle, Runtime};
+
+use crate::utils::background_notifications::{self, NotificationOpenTarget, UnreadCounts};
+use crate::utils::mod_profile;
+
+/// 報告スレッドとお知らせの未読件数をまとめて取得する。
+#[tauri::command]
+pub async fn unread_counts_get<R: Runtime>(
+    app: AppHandle<R>,
+    announce_read_at: Option<i64>,
+) -> Result<UnreadCounts, String> {
+    Ok(background_notifications::unread_counts(&app, announce_read_at).await)
+}
+
+/// お知らせの通知監視を即時に再取得させる。
+#[tauri::command]
+pub fn announce_refresh() -> Result<(), String> {
+    mod_profile::ensure_feature_enabled(mod_profile::Feature::Announce)?;
+    background_notifications::request_announce_refresh();
+    Ok(())
+}
+
+/// 通知ワーカーに設定を読み直させ、報告とお知らせを即時に取得させる。
+#[tauri::command]
+pub fn notifications_poll_now() -> Result<(), String> {
+    background_notifications::request_poll_now()
+}
+
+/// 通知ワーカーのポーリングを一時停止または再開する。
+#[tauri::command]
+pub fn notifications_worker_set_paused(paused: bool) -> Result<(), String> {
+    background_notifications::set_worker_paused(paused)
+}
 
 #[tauri::command]
 pub fn notifications_take_open_target() -> Option<NotificationOpenTarget> {
     // 通知クリック時の遷移先を一度だけ取り出す。
     // take系APIのため、同じ値は次回呼び出しでは取得できない。
-    background_notifications::take_pending_open_target()
+    let target = background_notifications::take_pending_open_target()?;
+    // 遷移先の機能が無効化されている場合は遷移させない。
+    let feature = match &target {
+        NotificationOpenTarget::Report { .. } => mod_profile::Feature::Reporting,
+        NotificationOpenTarget::Announce { .. } => mod_profile::Feature::Announce,
+    };
+    mod_profile::feature_enabled(feature).then_some(target)
 }
@@ -2,7 +2,8 @@
 use std::path::PathBuf;
 use tauri::{AppHandle, Runtime};
 
-use crate::utils::{mod_profile, presets};
+use crate::services::profile_service;
+use crate::utils::{file_dialog, mod_profile, presets};
 
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -19,6 +20,22 @@ pub struct PresetExportResult {
     pub exported_presets: usize,
 }
 
+/// 保存ダイアログ経由の書き出し結果。キャンセル時はエラーではなく cancelled を返す。
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum PresetDialogExportResult {
+    Exported(PresetExportResult),
+    Cancelled,
+}
+
+/// 選択ダイアログ経由の取り込み結果。キャンセル時はエラーではなく cancelled を返す。
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum PresetDialogImportResult {
+    Imported(PresetImportResult),
+    Cancelled,
+}
+
 #[derive(Debug, Clone, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PresetImportSelectionInput {
@@ -32,6 +49,7 @@ pub struct ImportedPresetResult {
     pub source_id: i32,
     pub target_id: i32,
     pub name: String,
+    pub action: presets::PresetImportAction,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -41,6 +59,24 @@ pub struct PresetImportResult {
     pub imported: Vec<ImportedPresetResult>,
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsBackupResult {
+    pub backup_id: String,
+    pub current_preset: i32,
+    pub preset_count: usize,
+}
+
+impl From<presets::OptionsBackupSummary> for OptionsBackupResult {
+    fn from(summary: presets::OptionsBackupSummary) -> Self {
+        Self {
+            backup_id: summary.backup_id,
+            current_preset: summary.current_preset,
+            preset_count: summary.preset_count,
+        }
+    }
+}
+
 fn ensure_presets_enabled() -> Result<(), String> {
     // プリセット機能の有効化状態を事前に確認する。
     mod_profile::ensure_feature_enabled(mod_profile::Feature::Presets)
@@ -51,7 +87,8 @@ fn ensure_presets_enabled() -> Result<(), String> {
 pub fn presets_list_local<R: Runtime>(app: AppHandle<R>) -> Result<Vec<PresetSummary>, String> {
     ensure_presets_enabled()?;
     // サービス層の返却型をフロント向けDTOへ変換する。
-    let presets = presets::list_local_presets(&app)?;
+    let presets = presets::list_local_presets(&app)
+        .inspect_err(|_| profile_service::emit_if_profile_missing(&app, None))?;
     Ok(presets
         .into_iter()
         .map(|preset| PresetSummary {
@@ -70,7 +107,50 @@ pub fn presets_export<R: Runtime>(
     output_path: Option<String>,
 ) -> Result<PresetExportResult, String> {
     ensure_presets_enabled()?;
-    let result = presets::export_selected_presets(&app, preset_ids, output_path)?;
+    let result = presets::export_selected_presets(&app, preset_ids, output_path)
+        .inspect_err(|_| profile_service::emit_if_profile_missing(&app, None))?;
+    Ok(PresetExportResult {
+        archive_path: result.archive_path.to_string_lossy().to_string(),
+        exported_presets: result.exported_presets,
+    })
+}
+
+/// 書き出し先が未指定なら保存ダイアログで選ばせてから、指定プリセットを書き出す。
+#[tauri::command]
+pub async fn presets_export_dialog<R: Runtime>(
+    app: AppHandle<R>,
+    preset_ids: Vec<i32>,
+    output_path: Option<String>,
+) -> Result<PresetDialogExportResult, String> {
+    ensure_presets_enabled()?;
+    let output_path = match output_path.filter(|path| !path.trim().is_empty()) {
+        Some(path) => path,
+        None => {
+            let selected = file_dialog::pick_save_path(
+                &app,
+                presets::preset_archive_extension(),
+                presets::default_archive_file_name(),
+            )
+            .await?;
+            let Some(selected) = selected else {
+                return Ok(PresetDialogExportResult::Cancelled);
+            };
+            selected.to_string_lossy().to_string()
+        }
+    };
+
+    presets_export(app, preset_ids, Some(output_path)).map(PresetDialogExportResult::Exported)
+}
+
+/// ローカルの全プリセットをアーカイブへ書き出す。
+#[tauri::command]
+pub fn presets_export_all<R: Runtime>(
+    app: AppHandle<R>,
+    output_path: Option<String>,
+) -> Result<PresetExportResult, String> {
+    ensure_presets_enabled()?;
+    let result = presets::export_all_presets(&app, output_path)
+        .inspect_err(|_| profile_service::emit_if_profile_missing(&app, None))?;
     Ok(PresetExportResult {
         archive_path: result.archive_path.to_string_lossy().to_string(),
         exported_presets: result.exported_presets,
@@ -98,12 +178,27 @@ pub fn presets_inspect_archive(archive_path: String) -> Result<Vec<PresetSummary
         .collect())
 }
 
+/// プリセットアーカイブが書き出し後に変更されていないかを確認する。
+#[tauri::command]
+pub fn presets_verify_archive(
+    archive_path: String,
+) -> Result<presets::PresetArchiveIntegrity, String> {
+    ensure_presets_enabled()?;
+    let normalized = archive_path.trim();
+    if normalized.is_empty() {
+        return Err("Preset archive path is required".to_string());
+    }
+
+    presets::verify_preset_archive(&PathBuf::from(normalized))
+}
+
 /// プリセットアーカイブを取り込む。
 #[tauri::command]
 pub fn presets_import_archive<R: Runtime>(
     app: AppHandle<R>,
     archive_path: String,
     selections: Vec<PresetImportSelectionInput>,
+    strategy: Option<presets::PresetImportStrategy>,
 ) -> Result<PresetImportResult, String> {
     ensure_presets_enabled()?;
     let normalized = archive_path.trim();
@@ -120,8 +215,13 @@ pub fn presets_import_archive<R: Runtime>(
         })
         .collect();
 
-    let result =
-        presets::import_presets_from_archive(&app, &PathBuf::from(normalized), selections)?;
+    let result = presets::import_presets_from_archive(
+        &app,
+        &PathBuf::from(normalized),
+        selections,
+        strategy.unwrap_or_default(),
+    )
+    .inspect_err(|_| profile_service::emit_if_profile_missing(&app, None))?;
 
     Ok(PresetImportResult {
         imported_presets: result.imported_presets,
@@ -132,7 +232,75 @@ pub fn presets_import_archive<R: Runtime>(
                 source_id: item.source_id,
                 target_id: item.target_id,
                 name: item.name,
+                action: item.action,
             })
             .collect(),
     })
 }
+
+/// 開くダイアログで選んだプリセットアーカイブを取り込む。
+/// selections 省略時は、アーカイブ内でデータを持つ全プリセットを取り込む。
+#[tauri::command]
+pub async fn presets_import_archive_dialog<R: Runtime>(
+    app: AppHandle<R>,
+    selections: Option<Vec<PresetImportSelectionInput>>,
+    strategy: Option<presets::PresetImportStrategy>,
+) -> Result<PresetDialogImportResult, String> {
+    ensure_presets_enabled()?;
+    // 拡張子の絞り込みは取り込み時の判定と同じ一覧を使う。
+    let selected = file_dialog::pick_open_path(
+        &app,
+        presets::preset_archive_extension(),
+        presets::supported_archive_extensions(),
+    )
+    .await?;
+    let Some(archive_path) = selected else {
+        return Ok(PresetDialogImportResult::Cancelled);
+    };
+
+    let selections = match selections {
+        Some(selections) => selections,
+        None => presets::inspect_preset_archive(&archive_path)?
+            .into_iter()
+            .filter(|preset| preset.has_data_file)
+            .map(|preset| PresetImportSelectionInput {
+                source_id: preset.id,
+                name: None,
+            })
+            .collect(),
+    };
+
+    presets_import_archive(
+        app,
+        archive_path.to_string_lossy().to_string(),
+        selections,
+        strategy,
+    )
+    .map(PresetDialogImportResult::Imported)
+}
+
+/// Options.data のみを退避する。
+#[tauri::command]
+pub fn options_backup<R: Runtime>(app: AppHandle<R>) -> Result<OptionsBackupResult, String> {
+    ensure_presets_enabled()?;
+    presets::backup_options_data(&app).map(OptionsBackupResult::from)
+}
+
+/// 退避済みの Options.data を書き戻す。
+#[tauri::command]
+pub fn options_restore<R: Runtime>(
+    app: AppHandle<R>,
+    backup_id: String,
+) -> Result<OptionsBackupResult, String> {
+    ensure_presets_enabled()?;
+    presets::restore_options_data(&app, &backup_id).map(OptionsBackupResult::from)
+}
+
+/// チェックサムだけが壊れた Options.data を修復する。
+#[tauri::command]
+pub fn options_repair<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<presets::OptionsRepairResult, String> {
+    ensure_presets_enabled()?;
+    presets::repair_options_data(&app)
+}
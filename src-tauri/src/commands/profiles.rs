@@ -0,0 +1,61 @@
+//! プロファイル管理のcommand境界。
+//! 実処理はservices層へ委譲する。
+
+use tauri::{AppHandle, Runtime};
+
+use crate::services::profile_service;
+
+pub use profile_service::{
+    InstalledProfileSummary, LegacyProfileDetection, LegacyProfileMigrationResult, ProfileHealth,
+    SaveDataPaths,
+};
+
+/// 親ディレクトリ配下の導入済みプロファイル一覧を返す。
+#[tauri::command]
+pub fn profiles_list(parent_dir: String) -> Result<Vec<InstalledProfileSummary>, String> {
+    profile_service::list_installed_profiles(&parent_dir)
+}
+
+/// 設定中プロファイルの導入状態を返す。
+#[tauri::command]
+pub fn profile_health<R: Runtime>(app: AppHandle<R>) -> Result<ProfileHealth, String> {
+    profile_service::profile_health(&app)
+}
+
+/// 設定中プロファイルのSaveData関連パスと存在状況を返す。
+#[tauri::command]
+pub fn savedata_paths<R: Runtime>(app: AppHandle<R>) -> Result<SaveDataPaths, String> {
+    profile_service::savedata_paths(&app)
+}
+
+/// 選択中プロファイルのディレクトリ名を変更し、変更後のパスを返す。
+#[tauri::command]
+pub fn profile_rename<R: Runtime>(
+    app: AppHandle<R>,
+    new_dir_name: String,
+) -> Result<String, String> {
+    profile_service::rename_active_profile(&app, &new_dir_name)
+}
+
+/// 旧レイアウトのプロファイルが残っていないかを調べる。
+#[tauri::command]
+pub fn profile_legacy_detect<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<LegacyProfileDetection, String> {
+    profile_service::detect_legacy_profiles(&app)
+}
+
+/// 検出した旧レイアウトのプロファイルを現在の保存先へ移す。SaveDataは上書きしない。
+#[tauri::command]
+pub fn profile_legacy_migrate<R: Runtime>(
+    app: AppHandle<R>,
+    source_path: String,
+) -> Result<LegacyProfileMigrationResult, String> {
+    profile_service::migrate_legacy_profile(&app, &source_path)
+}
+
+/// プロファイルまたはゲームフォルダ内のファイルのSHA256を返す。
+#[tauri::command]
+pub fn file_hash<R: Runtime>(app: AppHandle<R>, path: String) -> Result<String, String> {
+    profile_service::hash_managed_file(&app, &path)
+}
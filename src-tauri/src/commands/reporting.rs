@@ -1,6 +1,7 @@
 // 通報API連携をフロントへ公開するコマンド群。
 use tauri::{AppHandle, Runtime};
 
+use crate::services::snr_service::{self, OperationKind};
 use crate::utils::{mod_profile, reporting_api};
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -38,6 +39,22 @@ pub async fn reporting_prepare<R: Runtime>(
     })
 }
 
+/// 通報機能の利用規約本文を取得する。
+#[tauri::command]
+pub async fn reporting_terms_get() -> Result<String, String> {
+    ensure_reporting_enabled()?;
+    reporting_api::get_terms_text().await
+}
+
+/// 通報APIの疎通と認証を段階ごとに診断する。
+#[tauri::command]
+pub async fn reporting_healthcheck<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<reporting_api::ReportingHealthcheck, String> {
+    ensure_reporting_enabled()?;
+    reporting_api::healthcheck(&app).await
+}
+
 /// 報告スレッド一覧を取得する。
 #[tauri::command]
 pub async fn reporting_threads_list<R: Runtime>(
@@ -47,6 +64,25 @@ pub async fn reporting_threads_list<R: Runtime>(
     reporting_api::list_threads(&app).await
 }
 
+/// 報告スレッドを状態ごとに集計した件数を取得する。
+#[tauri::command]
+pub async fn reporting_status_summary<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<reporting_api::ReportStatusSummary, String> {
+    ensure_reporting_enabled()?;
+    reporting_api::status_summary(&app).await
+}
+
+/// 通知から開くスレッドがまだ存在するか確認する。削除済みなら None を返す。
+#[tauri::command]
+pub async fn reporting_thread_resolve<R: Runtime>(
+    app: AppHandle<R>,
+    thread_id: String,
+) -> Result<Option<reporting_api::ReportThread>, String> {
+    ensure_reporting_enabled()?;
+    reporting_api::resolve_thread(&app, &thread_id).await
+}
+
 /// 指定スレッドのメッセージ一覧を取得する。
 #[tauri::command]
 pub async fn reporting_messages_list<R: Runtime>(
@@ -57,6 +93,18 @@ pub async fn reporting_messages_list<R: Runtime>(
     reporting_api::get_messages(&app, &thread_id).await
 }
 
+/// 指定スレッドの全メッセージを記録ファイルとして書き出す。
+#[tauri::command]
+pub async fn reporting_thread_export<R: Runtime>(
+    app: AppHandle<R>,
+    thread_id: String,
+    output_path: String,
+) -> Result<String, String> {
+    ensure_reporting_enabled()?;
+    let path = reporting_api::export_thread_transcript(&app, &thread_id, &output_path).await?;
+    Ok(path.to_string_lossy().to_string())
+}
+
 /// スレッドへ返信メッセージを送信する。
 #[tauri::command]
 pub async fn reporting_message_send<R: Runtime>(
@@ -77,6 +125,7 @@ pub async fn reporting_report_send<R: Runtime>(
     input: reporting_api::SendReportInput,
 ) -> Result<ReportingSendResult, String> {
     ensure_reporting_enabled()?;
+    let _operation = snr_service::begin_operation(OperationKind::ReportSend);
     reporting_api::send_report(&app, input).await?;
     Ok(ReportingSendResult { success: true })
 }
@@ -98,3 +147,12 @@ pub fn reporting_log_source_get<R: Runtime>(
     ensure_reporting_enabled()?;
     reporting_api::get_log_source_info(&app)
 }
+
+/// 暗号化済みログを復号して平文を返す。
+/// リリースビルドでは復号手段を公開しないよう、コマンド自体をデバッグビルドに限る。
+#[cfg(debug_assertions)]
+#[tauri::command]
+pub fn reporting_log_decrypt(encoded: String) -> Result<String, String> {
+    ensure_reporting_enabled()?;
+    reporting_api::decrypt_and_decompress_log(&encoded)
+}
@@ -1,25 +1,79 @@
 // 設定読み書きとフォルダ起動を公開するコマンド群。
 use std::path::{Path, PathBuf};
 use std::process::Command;
-use tauri::{AppHandle, Runtime};
+use tauri::{AppHandle, Emitter, Runtime};
+use tauri_plugin_opener::OpenerExt;
 
+use crate::services::env_report_service;
 use crate::utils::settings::{
-    self, default_profile_path, is_profile_ready, LauncherSettings, LauncherSettingsInput,
+    self, default_profile_path, is_profile_ready, GamePlatform, LauncherSettings,
+    LauncherSettingsInput, ProfileSettingsOverrides,
 };
+use crate::utils::{background_notifications, bootstrap, epic_api, finder, mod_profile};
+
+/// 初回起動準備を実行し、初期化した内容を返す。
+#[tauri::command]
+pub fn app_bootstrap<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<bootstrap::AppBootstrapSummary, String> {
+    bootstrap::app_bootstrap(&app)
+}
 
 /// ランチャー設定を取得する。
+/// `settings_update` / `locale_set` と同じく、プロファイルの上書きを合成しないグローバル設定を返す。
 #[tauri::command]
 pub fn settings_get<R: Runtime>(app: AppHandle<R>) -> Result<LauncherSettings, String> {
-    settings::load_or_init_settings(&app)
+    settings::load_or_init_global_settings(&app)
 }
 
-/// ランチャー設定を更新する。
+/// ランチャー設定を更新する。通知関連の変更は通知ワーカーへ即時反映させる。
 #[tauri::command]
 pub fn settings_update<R: Runtime>(
     app: AppHandle<R>,
     settings: LauncherSettingsInput,
 ) -> Result<LauncherSettings, String> {
-    settings::apply_settings_input(&app, settings)
+    let notifications_changed = settings.report_notifications_enabled.is_some()
+        || settings.announce_notifications_enabled.is_some()
+        || settings.notification_detail_level.is_some()
+        || settings.ui_locale.is_some();
+    let updated = settings::apply_settings_input(&app, settings)?;
+    if notifications_changed {
+        if let Err(error) = background_notifications::request_poll_now() {
+            log::warn!("[settings] failed to notify background worker: {error}");
+        }
+    }
+    Ok(updated)
+}
+
+/// サポート用に、パスを伏せた設定・環境の要約を返す。
+#[tauri::command]
+pub fn env_report<R: Runtime>(app: AppHandle<R>) -> Result<env_report_service::EnvReport, String> {
+    env_report_service::env_report(&app)
+}
+
+/// UIで選択できるロケールコードの一覧を返す。
+#[tauri::command]
+pub fn locales_supported() -> Vec<String> {
+    settings::SUPPORTED_UI_LOCALES
+        .iter()
+        .map(|locale| locale.to_string())
+        .collect()
+}
+
+/// 対応ロケールか検証してから保存し、トレイメニューを含む全ウィンドウへ変更を通知する。
+#[tauri::command]
+pub fn locale_set<R: Runtime>(app: AppHandle<R>, code: String) -> Result<LauncherSettings, String> {
+    let locale = settings::supported_ui_locale(&code)
+        .ok_or_else(|| format!("Unsupported UI locale: {}", code.trim()))?;
+    let updated = settings::apply_settings_input(
+        &app,
+        LauncherSettingsInput {
+            ui_locale: Some(locale.to_string()),
+            ..LauncherSettingsInput::default()
+        },
+    )?;
+    let _ = app.emit(settings::SETTINGS_CHANGED_EVENT, updated.clone());
+    Ok(updated)
 }
 
 /// プロファイル必須ファイルの存在を確認する。
@@ -43,6 +97,116 @@ pub fn settings_profile_ready<R: Runtime>(
     Ok(is_profile_ready(&target_path))
 }
 
+fn resolve_target_profile_path<R: Runtime>(
+    app: &AppHandle<R>,
+    profile_path: Option<String>,
+) -> Result<PathBuf, String> {
+    match profile_path
+        .map(|path| path.trim().to_string())
+        .filter(|path| !path.is_empty())
+    {
+        Some(path) => Ok(PathBuf::from(path)),
+        None => Ok(PathBuf::from(
+            settings::load_or_init_global_settings(app)?.profile_path,
+        )),
+    }
+}
+
+/// プロファイル単位の上書き設定を取得する。未指定時は選択中プロファイルを対象にする。
+#[tauri::command]
+pub fn settings_profile_overrides_get<R: Runtime>(
+    app: AppHandle<R>,
+    profile_path: Option<String>,
+) -> Result<Option<ProfileSettingsOverrides>, String> {
+    let target_path = resolve_target_profile_path(&app, profile_path)?;
+    Ok(settings::load_profile_settings_overrides(&target_path))
+}
+
+/// プロファイル単位の上書き設定を保存する。全項目未指定なら上書きを解除する。
+#[tauri::command]
+pub fn settings_profile_overrides_set<R: Runtime>(
+    app: AppHandle<R>,
+    profile_path: Option<String>,
+    overrides: ProfileSettingsOverrides,
+) -> Result<Option<ProfileSettingsOverrides>, String> {
+    let target_path = resolve_target_profile_path(&app, profile_path)?;
+    settings::save_profile_settings_overrides(&target_path, overrides)
+}
+
+fn validate_platform_installation(
+    platform: &GamePlatform,
+    among_us_path: &str,
+) -> Result<(), String> {
+    let among_us_path = among_us_path.trim();
+    if !among_us_path.is_empty() {
+        // 設定済みのゲームフォルダがある場合は、その実体と一致することを要求する。
+        let detected = finder::detect_platform(among_us_path)?;
+        if detected != platform.as_str() {
+            return Err(format!(
+                "The configured Among Us folder is a {detected} installation, not {}: {among_us_path}",
+                platform.as_str()
+            ));
+        }
+        return Ok(());
+    }
+
+    let installed = finder::get_among_us_paths().iter().any(|path| {
+        finder::detect_platform(&path.to_string_lossy())
+            .is_ok_and(|detected| detected == platform.as_str())
+    });
+    if !installed {
+        return Err(format!(
+            "No {} installation of Among Us was detected.",
+            platform.as_str()
+        ));
+    }
+    Ok(())
+}
+
+fn validate_platform_login(platform: &GamePlatform) -> Result<(), String> {
+    if *platform != GamePlatform::Epic {
+        return Ok(());
+    }
+    // Epic版の起動にはログイン済みセッションが必須。
+    if !mod_profile::feature_enabled(mod_profile::Feature::EpicLogin) {
+        return Err("Epic platform requires Epic login, which is disabled.".to_string());
+    }
+    if epic_api::load_session().is_none() {
+        return Err("Epic platform requires signing in to Epic Games first.".to_string());
+    }
+    Ok(())
+}
+
+/// 現在のゲームプラットフォームを取得する。
+#[tauri::command]
+pub fn platform_get<R: Runtime>(app: AppHandle<R>) -> Result<GamePlatform, String> {
+    Ok(settings::load_or_init_settings(&app)?.game_platform)
+}
+
+/// 検出済みインストールとEpicログイン状態を検証してからプラットフォームを保存する。
+#[tauri::command]
+pub fn platform_set<R: Runtime>(
+    app: AppHandle<R>,
+    platform: String,
+) -> Result<LauncherSettings, String> {
+    let platform = GamePlatform::from_user_value(&platform)?;
+    let current = settings::load_or_init_settings(&app)?;
+    validate_platform_installation(&platform, &current.among_us_path)?;
+    validate_platform_login(&platform)?;
+
+    // 読み出しと同じ値を更新するため、上書きで指定されていればそちらへ保存する。
+    if !settings::update_profile_platform_override(Path::new(&current.profile_path), &platform)? {
+        settings::apply_settings_input(
+            &app,
+            LauncherSettingsInput {
+                game_platform: Some(platform),
+                ..LauncherSettingsInput::default()
+            },
+        )?;
+    }
+    settings::load_or_init_settings(&app)
+}
+
 /// 指定フォルダをOS標準のファイルエクスプローラーで開く。
 #[tauri::command]
 pub fn settings_open_folder(path: String) -> Result<(), String> {
@@ -62,6 +226,46 @@ pub fn settings_open_folder(path: String) -> Result<(), String> {
     open_directory(&target)
 }
 
+fn open_configured_directory<R: Runtime>(
+    app: &AppHandle<R>,
+    path: &Path,
+    label: &str,
+) -> Result<(), String> {
+    if !path.is_dir() {
+        return Err(format!("{label} does not exist: {}", path.display()));
+    }
+
+    app.opener()
+        .open_path(path.to_string_lossy(), None::<&str>)
+        .map_err(|e| format!("Failed to open {label} {}: {e}", path.display()))
+}
+
+/// 設定済みのAmong Usインストールフォルダを開く。
+#[tauri::command]
+pub fn open_game_folder<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    let launcher_settings = settings::load_or_init_settings(&app)?;
+    let among_us_path = launcher_settings.among_us_path.trim();
+    if among_us_path.is_empty() {
+        return Err("Among Us path is not configured".to_string());
+    }
+    open_configured_directory(&app, Path::new(among_us_path), "Among Us folder")
+}
+
+/// 選択中プロファイルのBepInExフォルダを開く。
+#[tauri::command]
+pub fn open_profile_bepinex_folder<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    let launcher_settings = settings::load_or_init_settings(&app)?;
+    let profile_path = launcher_settings.profile_path.trim();
+    if profile_path.is_empty() {
+        return Err("Profile path is not configured".to_string());
+    }
+    open_configured_directory(
+        &app,
+        &Path::new(profile_path).join("BepInEx"),
+        "Profile BepInEx folder",
+    )
+}
+
 fn open_directory(path: &Path) -> Result<(), String> {
     // OSごとの既定コマンドを使ってフォルダを開く。
     #[cfg(target_os = "windows")]
@@ -1,15 +1,30 @@
 //! SNR関連のcommand境界。
 //! 実処理はservices層へ委譲し、この層は入出力契約に専念する。
 
+use serde::Serialize;
 use tauri::{AppHandle, Runtime};
 
-use crate::{services::snr_service, utils::mod_profile};
+use crate::{
+    services::{launch_service, snr_service},
+    utils::mod_profile,
+};
 
 pub use snr_service::{
-    InstallResult, PreservedSaveDataStatus, SaveDataImportResult, SaveDataPresetMergeResult,
-    SaveDataPreviewResult, SnrReleaseSummary, UninstallResult,
+    ActiveOperation, BepInExConfigPreservationStatus, GitHubRateLimitStatus, InstallResult,
+    ManagedArtifact, ManagedArtifactsCleanupResult, ModUpdateDiff, OrphanCleanupResult,
+    PatcherResyncResult, PreservedSaveDataDetails, PreservedSaveDataStatus, ReleaseDownloadResult,
+    RepatchCheck, SaveDataCompatReport, SaveDataImportResult, SaveDataPresetMergeResult,
+    SaveDataPreviewResult, SnrReleaseSummary, UninstallPreview, UninstallResult,
 };
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallAndLaunchResult {
+    pub install: InstallResult,
+    /// 起動時にBepInExのinterop生成が走り、ゲーム表示まで時間がかかる場合は true。
+    pub first_setup_pending: bool,
+}
+
 fn ensure_presets_enabled() -> Result<(), String> {
     // プリセット関連APIのみ、機能フラグで明示的にガードする。
     mod_profile::ensure_feature_enabled(mod_profile::Feature::Presets)
@@ -38,12 +53,12 @@ pub fn snr_savedata_preview(source_among_us_path: String) -> Result<SaveDataPrev
 
 /// 指定したAmong UsフォルダのSaveDataを現在のプロファイルへ取り込む。
 #[tauri::command]
-pub fn snr_savedata_import<R: Runtime>(
+pub async fn snr_savedata_import<R: Runtime>(
     app: AppHandle<R>,
     source_among_us_path: String,
 ) -> Result<SaveDataImportResult, String> {
     // 旧snr命名の互換APIとして残しつつ、実装本体は汎用mod APIへ委譲する。
-    mod_savedata_import(app, source_among_us_path)
+    mod_savedata_import(app, source_among_us_path).await
 }
 
 /// 指定したAmong UsフォルダのSaveDataからプリセットのみを現在のプロファイルへ追加取り込みする。
@@ -69,7 +84,7 @@ pub fn snr_uninstall<R: Runtime>(
     app: AppHandle<R>,
     preserve_save_data: bool,
 ) -> Result<UninstallResult, String> {
-    mod_uninstall(app, preserve_save_data)
+    mod_uninstall(app, preserve_save_data, None)
 }
 
 /// 指定タグのSNRをインストールする。
@@ -80,7 +95,7 @@ pub async fn snr_install<R: Runtime>(
     platform: String,
     restore_preserved_save_data: Option<bool>,
 ) -> Result<InstallResult, String> {
-    mod_install(app, tag, platform, restore_preserved_save_data).await
+    mod_install(app, tag, platform, restore_preserved_save_data, None).await
 }
 
 /// 利用可能なmodリリース一覧を取得する（汎用API）。
@@ -89,6 +104,14 @@ pub async fn mod_releases_list() -> Result<Vec<SnrReleaseSummary>, String> {
     snr_service::list_snr_releases().await
 }
 
+/// リリース一覧をGitHubから取り直す。UIの手動更新から呼び出す。
+#[tauri::command]
+pub async fn releases_refresh() -> Result<Vec<SnrReleaseSummary>, String> {
+    // キャッシュを破棄してから取得し、保持期間内でも最新の一覧を返す。
+    snr_service::invalidate_release_list_cache();
+    snr_service::list_snr_releases().await
+}
+
 /// 保持済みセーブデータの状態を返す（汎用API）。
 #[tauri::command]
 pub fn mod_preserved_save_data_status<R: Runtime>(
@@ -97,19 +120,68 @@ pub fn mod_preserved_save_data_status<R: Runtime>(
     snr_service::get_preserved_save_data_status(app)
 }
 
+/// 保持済みセーブデータのファイル一覧をサイズ付きで返す。
+#[tauri::command]
+pub fn preserved_savedata_details<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<PreservedSaveDataDetails, String> {
+    snr_service::get_preserved_save_data_details(app)
+}
+
+/// BepInEx/config の引き継ぎ設定と対象ファイルを返す。
+#[tauri::command]
+pub fn bepinex_config_preservation_get<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<BepInExConfigPreservationStatus, String> {
+    snr_service::bepinex_config_preservation_status(&app)
+}
+
 /// 指定したAmong UsフォルダからSaveDataの取り込み候補を検査する（汎用API）。
 #[tauri::command]
 pub fn mod_savedata_preview(source_among_us_path: String) -> Result<SaveDataPreviewResult, String> {
     snr_service::preview_savedata_from_among_us(source_among_us_path)
 }
 
+/// 取り込み元とプロファイルの Options.data 形式バージョンを比較する。
+#[tauri::command]
+pub fn savedata_compat_check<R: Runtime>(
+    app: AppHandle<R>,
+    source_among_us_path: String,
+) -> Result<SaveDataCompatReport, String> {
+    snr_service::check_savedata_compat(&app, source_among_us_path)
+}
+
 /// 指定したAmong UsフォルダのSaveDataを現在のプロファイルへ取り込む（汎用API）。
 #[tauri::command]
-pub fn mod_savedata_import<R: Runtime>(
+pub async fn mod_savedata_import<R: Runtime>(
     app: AppHandle<R>,
     source_among_us_path: String,
 ) -> Result<SaveDataImportResult, String> {
-    snr_service::import_savedata_from_among_us_into_profile(&app, source_among_us_path)
+    // コピー中も中断要求を受け付けられるよう、別スレッドで実行する。
+    tauri::async_runtime::spawn_blocking(move || {
+        snr_service::import_savedata_from_among_us_into_profile(&app, source_among_us_path)
+    })
+    .await
+    .map_err(|e| format!("SaveData import task failed: {e}"))?
+}
+
+/// SaveDataのzipバックアップを現在のプロファイルへ取り込む。
+#[tauri::command]
+pub async fn mod_savedata_import_archive<R: Runtime>(
+    app: AppHandle<R>,
+    archive_path: String,
+) -> Result<SaveDataImportResult, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        snr_service::import_savedata_from_archive_into_profile(&app, archive_path)
+    })
+    .await
+    .map_err(|e| format!("SaveData import task failed: {e}"))?
+}
+
+/// 実行中のSaveData取り込みを中断する。取り込み先の既存データは変更されない。
+#[tauri::command]
+pub fn mod_savedata_import_cancel() {
+    snr_service::request_savedata_import_cancel();
 }
 
 /// 指定したAmong UsフォルダのSaveDataからプリセットのみを追加取り込みする（汎用API）。
@@ -132,12 +204,59 @@ pub fn mod_preserved_savedata_merge_presets<R: Runtime>(
 }
 
 /// プロファイルをアンインストールし、必要ならセーブデータを退避する（汎用API）。
+/// keep_cache が false の場合は、選択中タグのダウンロードキャッシュも削除する。
 #[tauri::command]
 pub fn mod_uninstall<R: Runtime>(
     app: AppHandle<R>,
     preserve_save_data: bool,
+    keep_cache: Option<bool>,
 ) -> Result<UninstallResult, String> {
-    snr_service::uninstall_snr_profile(app, preserve_save_data)
+    // 既存UI互換のため、未指定時はキャッシュを残す従来の挙動を維持する。
+    snr_service::uninstall_snr_profile(app, preserve_save_data, keep_cache.unwrap_or(true))
+}
+
+/// アンインストール時に削除・保持される内容を、実際には削除せずに返す。
+#[tauri::command]
+pub fn mod_uninstall_preview<R: Runtime>(
+    app: AppHandle<R>,
+    preserve_save_data: bool,
+) -> Result<UninstallPreview, String> {
+    snr_service::uninstall_preview(&app, preserve_save_data)
+}
+
+/// 現在の導入内容から指定タグへ更新した場合のファイル差分を返す。
+#[tauri::command]
+pub async fn mod_update_diff<R: Runtime>(
+    app: AppHandle<R>,
+    target_tag: String,
+    platform: String,
+) -> Result<ModUpdateDiff, String> {
+    snr_service::compute_mod_update_diff(&app, target_tag, platform).await
+}
+
+/// 指定タグのリリースzipをインストールせずに保存する。
+#[tauri::command]
+pub async fn mod_download_release<R: Runtime>(
+    app: AppHandle<R>,
+    tag: String,
+    platform: String,
+    output_path: String,
+) -> Result<ReleaseDownloadResult, String> {
+    snr_service::download_release_to_path(&app, tag, platform, output_path).await
+}
+
+/// ゲーム更新後にパッチャーの再同期が必要かを返す。
+#[tauri::command]
+pub fn mod_repatch_needed<R: Runtime>(app: AppHandle<R>) -> Result<RepatchCheck, String> {
+    snr_service::check_repatch_needed(&app)
+}
+
+/// 導入済みプロファイルのパッチャーを再同期する。
+#[tauri::command]
+pub async fn mod_patchers_resync<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<PatcherResyncResult, String> {
+    snr_service::resync_profile_patchers(app).await
 }
 
 /// 指定タグのmodをインストールする（汎用API）。
@@ -147,6 +266,133 @@ pub async fn mod_install<R: Runtime>(
     tag: String,
     platform: String,
     restore_preserved_save_data: Option<bool>,
+    restore_paths: Option<Vec<String>>,
 ) -> Result<InstallResult, String> {
-    snr_service::install_snr_release(app, tag, platform, restore_preserved_save_data).await
+    // アンインストール時に残した同じタグのキャッシュがあれば、ダウンロードを省略する。
+    let reuse_cached_download = snr_service::has_reusable_cached_download(&app, &tag, &platform);
+    // restore_paths 指定時は、保持データのうち指定した相対パスだけを復元する。
+    snr_service::install_snr_release(
+        app,
+        tag,
+        platform,
+        restore_preserved_save_data,
+        restore_paths,
+        reuse_cached_download,
+    )
+    .await
+}
+
+/// ユーザーが選択したローカルのzipからmodをインストールする。
+/// `skip_patchers` を指定するとパッチャー同期を省略する。
+#[tauri::command]
+pub async fn mod_install_from_file<R: Runtime>(
+    app: AppHandle<R>,
+    archive_path: String,
+    platform: String,
+    restore_preserved_save_data: Option<bool>,
+    skip_patchers: Option<bool>,
+) -> Result<InstallResult, String> {
+    snr_service::install_snr_release_from_local_zip(
+        app,
+        archive_path,
+        platform,
+        restore_preserved_save_data,
+        skip_patchers,
+    )
+    .await
+}
+
+/// 指定タグのmodをインストールし、成功したら保存済み設定でModded起動する。
+#[tauri::command]
+pub async fn mod_install_and_launch<R: Runtime>(
+    app: AppHandle<R>,
+    tag: String,
+    platform: String,
+) -> Result<InstallAndLaunchResult, String> {
+    // 進捗は通常のインストールと同じイベントで通知し、起動状態は game-state-changed に任せる。
+    let reuse_cached_download = snr_service::has_reusable_cached_download(&app, &tag, &platform);
+    let install = snr_service::install_snr_release(
+        app.clone(),
+        tag,
+        platform,
+        None,
+        None,
+        reuse_cached_download,
+    )
+    .await?;
+    let first_setup_pending =
+        launch_service::saved_modded_first_setup_pending(&app).unwrap_or(false);
+    launch_service::launch_modded_from_saved_settings(app)
+        .await
+        .map_err(|error| format!("Installed '{}', but launch failed: {error}", install.tag))?;
+
+    Ok(InstallAndLaunchResult {
+        install,
+        first_setup_pending,
+    })
+}
+
+/// 失敗したインストールを再試行する。検証済みのダウンロードキャッシュがあれば再利用する。
+#[tauri::command]
+pub async fn mod_install_retry<R: Runtime>(
+    app: AppHandle<R>,
+    tag: String,
+    platform: String,
+) -> Result<InstallResult, String> {
+    snr_service::install_snr_release(app, tag, platform, None, None, true).await
+}
+
+/// 実行中のインストールを中断する。タグ未指定時は実行中のすべてが対象。
+/// プロファイルの差し替えが始まった後は中断せず、そのまま完了させる。
+#[tauri::command]
+pub fn mod_install_cancel(tag: Option<String>) -> bool {
+    snr_service::request_install_cancel(tag.as_deref())
+}
+
+/// modのインストールが実行中かを返す（汎用API）。
+#[tauri::command]
+pub fn mod_install_in_progress() -> bool {
+    snr_service::is_install_in_progress()
+}
+
+/// 実行中の長時間処理と直近の進捗を返す。Webview再生成後の再同期に使う。
+#[tauri::command]
+pub fn operation_status() -> Vec<ActiveOperation> {
+    snr_service::active_operations()
+}
+
+/// 中断したインストールが残した作業ディレクトリを削除する。
+#[tauri::command]
+pub fn install_cleanup_orphans<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<OrphanCleanupResult, String> {
+    snr_service::cleanup_orphaned_install_dirs(&app)
+}
+
+/// ランチャーが管理する一時ファイル・作業用ディレクトリを一覧する。
+#[tauri::command]
+pub fn managed_artifacts_list<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Vec<ManagedArtifact>, String> {
+    snr_service::list_managed_artifacts(&app)
+}
+
+/// 実行中の処理が使っていない一時ファイル・作業用ディレクトリをまとめて削除する。
+#[tauri::command]
+pub fn managed_artifacts_cleanup<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<ManagedArtifactsCleanupResult, String> {
+    snr_service::cleanup_managed_artifacts(&app)
+}
+
+/// zipアーカイブを展開せずに検証し、エントリ数を返す。
+#[tauri::command]
+pub fn install_archive_validate(archive_path: String) -> Result<usize, String> {
+    snr_service::validate_install_archive(archive_path)
+}
+
+/// GitHub APIの残りリクエスト数を返す。リリース一覧が取得できない原因の確認に使う。
+#[tauri::command]
+pub async fn github_rate_limit_status() -> Result<GitHubRateLimitStatus, String> {
+    snr_service::github_rate_limit_status().await
 }
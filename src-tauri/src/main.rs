@@ -15,6 +15,7 @@ use tauri::{
     tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
     AppHandle, Manager, PhysicalPosition, Position, RunEvent, WebviewUrl, WebviewWindowBuilder,
 };
+use utils::close_prompt::CloseAction;
 use utils::mod_profile;
 
 const TRAY_ID: &str = "main-tray";
@@ -103,6 +104,51 @@ impl TrayWebviewDestroyState {
     }
 }
 
+#[derive(Debug)]
+struct TrayMenuCursorWatcherState {
+    generation: AtomicU64,
+    stopped: AtomicBool,
+}
+
+impl TrayMenuCursorWatcherState {
+    fn new() -> Self {
+        Self {
+            generation: AtomicU64::new(0),
+            stopped: AtomicBool::new(false),
+        }
+    }
+
+    fn begin(&self) -> u64 {
+        // 世代を進めることで、直前に起動した監視スレッドを次の周回で終了させる。
+        self.generation.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    fn is_current(&self, generation: u64) -> bool {
+        !self.stopped.load(Ordering::SeqCst) && self.generation.load(Ordering::SeqCst) == generation
+    }
+
+    fn stop(&self) {
+        // 終了時は以降の監視開始も含めて止め、スレッドを速やかに抜けさせる。
+        self.stopped.store(true, Ordering::SeqCst);
+        self.generation.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn hide_main_window_to_tray<R: tauri::Runtime + 'static>(
+    app: &AppHandle<R>,
+    tray_webview_destroy_state: &Arc<TrayWebviewDestroyState>,
+    close_webview: bool,
+) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.hide();
+    }
+    if close_webview {
+        tray_webview_destroy_state.schedule_destroy(app.clone());
+    } else {
+        tray_webview_destroy_state.cancel_pending();
+    }
+}
+
 fn resolve_ui_locale<R: tauri::Runtime>(app: &AppHandle<R>) -> String {
     crate::utils::settings::load_or_init_settings(app)
         .map(|settings| settings.ui_locale)
@@ -152,7 +198,11 @@ fn ensure_tray_menu_window<R: tauri::Runtime>(
     Some(window)
 }
 
-fn show_tray_menu_window<R: tauri::Runtime>(app: &AppHandle<R>, position: PhysicalPosition<f64>) {
+fn show_tray_menu_window<R: tauri::Runtime>(
+    app: &AppHandle<R>,
+    position: PhysicalPosition<f64>,
+    cursor_watcher_state: &Arc<TrayMenuCursorWatcherState>,
+) {
     let Some(window) = ensure_tray_menu_window(app) else {
         return;
     };
@@ -173,7 +223,16 @@ fn show_tray_menu_window<R: tauri::Runtime>(app: &AppHandle<R>, position: Physic
     let _ = window.set_position(Position::Physical(PhysicalPosition::new(x, y)));
     let _ = window.show();
     let _ = window.set_focus();
-    start_tray_menu_cursor_leave_watcher(app.clone(), position);
+    // 固定表示モードでは、項目の選択かトレイアイコンの再クリックまで閉じない。
+    let pinned = crate::utils::settings::load_or_init_settings(app)
+        .map(|settings| settings.tray_menu_pinned)
+        .unwrap_or(false);
+    if pinned {
+        // 直前の表示で起動した監視スレッドも止めるため、世代だけ進めておく。
+        cursor_watcher_state.begin();
+    } else {
+        start_tray_menu_cursor_leave_watcher(app.clone(), position, cursor_watcher_state.clone());
+    }
 }
 
 fn is_tray_menu_visible<R: tauri::Runtime>(app: &AppHandle<R>) -> bool {
@@ -215,12 +274,18 @@ fn is_cursor_inside_indicator_safe_zone(
 fn start_tray_menu_cursor_leave_watcher<R: tauri::Runtime + 'static>(
     app: AppHandle<R>,
     indicator_anchor: PhysicalPosition<f64>,
+    cursor_watcher_state: Arc<TrayMenuCursorWatcherState>,
 ) {
+    let generation = cursor_watcher_state.begin();
     std::thread::spawn(move || {
         let mut outside_since: Option<Instant> = None;
         loop {
             std::thread::sleep(Duration::from_millis(TRAY_MENU_CURSOR_POLL_MS));
 
+            if !cursor_watcher_state.is_current(generation) {
+                break;
+            }
+
             let Some(window) = app.get_webview_window(TRAY_MENU_WINDOW_LABEL) else {
                 break;
             };
@@ -335,6 +400,7 @@ fn show_main_window<R: tauri::Runtime>(
 fn setup_tray<R: tauri::Runtime>(
     app: &AppHandle<R>,
     tray_webview_destroy_state: Arc<TrayWebviewDestroyState>,
+    cursor_watcher_state: Arc<TrayMenuCursorWatcherState>,
 ) -> tauri::Result<()> {
     // トレイアイコンを初期化する。
     let mod_profile = mod_profile::get();
@@ -370,7 +436,7 @@ fn setup_tray<R: tauri::Runtime>(
                 if is_tray_menu_visible(tray.app_handle()) {
                     hide_tray_menu_window(tray.app_handle());
                 } else {
-                    show_tray_menu_window(tray.app_handle(), position);
+                    show_tray_menu_window(tray.app_handle(), position, &cursor_watcher_state);
                 }
             }
             _ => {}
@@ -394,6 +460,8 @@ pub fn run() {
         match commands::launch::parse_elevated_launch_payload_argument(std::env::args_os()) {
             Ok(path) => path,
             Err(error) => {
+                // ファイルロガーはアプリ生成後の app_data_dir を必要とするため、ここではまだ使えない。
+                // 引数が壊れている場合はアプリを生成せずに終了するので、標準エラーへだけ出す。
                 eprintln!("Failed to parse elevated launch arguments: {error}");
                 return;
             }
@@ -411,6 +479,8 @@ pub fn run() {
     let tray_webview_destroy_state_for_window = tray_webview_destroy_state.clone();
     let tray_webview_destroy_state_for_setup = tray_webview_destroy_state.clone();
     let tray_webview_destroy_state_for_autolaunch = tray_webview_destroy_state.clone();
+    let tray_menu_cursor_watcher_state = Arc::new(TrayMenuCursorWatcherState::new());
+    let tray_menu_cursor_watcher_state_for_setup = tray_menu_cursor_watcher_state.clone();
     let helper_mode_for_window_events = helper_mode;
     let helper_mode_for_run_loop = helper_mode;
     let elevated_launch_payload_path_for_setup = elevated_launch_payload_path.clone();
@@ -460,32 +530,64 @@ pub fn run() {
                     return;
                 }
 
-                let close_to_tray =
+                let (close_to_tray, close_webview, prompt) =
                     match crate::utils::settings::load_or_init_settings(window.app_handle()) {
                         // 設定読み込み失敗時は安全側としてトレイ遷移+webview解放を既定にする。
                         Ok(settings) => (
                             settings.close_to_tray_on_close,
                             settings.close_webview_on_tray_background,
+                            settings.close_action_prompt,
                         ),
-                        Err(_) => (true, true),
+                        Err(_) => (true, true, false),
                     };
 
-                if close_to_tray.0 {
+                if prompt {
+                    // 確認中は閉じずに待ち、選ばれた操作をダイアログの結果で実行する。
                     api.prevent_close();
-                    let _ = window.hide();
-                    if close_to_tray.1 {
-                        tray_webview_destroy_state_for_window
-                            .schedule_destroy(window.app_handle().clone());
-                    } else {
-                        tray_webview_destroy_state_for_window.cancel_pending();
-                    }
+                    let app_handle = window.app_handle().clone();
+                    let bypass_close_to_tray = bypass_close_to_tray_for_window.clone();
+                    let tray_webview_destroy_state = tray_webview_destroy_state_for_window.clone();
+                    crate::utils::close_prompt::prompt_close_action(
+                        window.app_handle(),
+                        move |action| match action {
+                            CloseAction::MinimizeToTray => hide_main_window_to_tray(
+                                &app_handle,
+                                &tray_webview_destroy_state,
+                                close_webview,
+                            ),
+                            CloseAction::Exit => {
+                                bypass_close_to_tray.store(true, Ordering::SeqCst);
+                                app_handle.exit(0);
+                            }
+                        },
+                    );
+                    return;
+                }
+
+                if close_to_tray {
+                    api.prevent_close();
+                    hide_main_window_to_tray(
+                        window.app_handle(),
+                        &tray_webview_destroy_state_for_window,
+                        close_webview,
+                    );
                 }
             }
         })
         .setup(move |app| {
-            crate::utils::mod_profile::validate().map_err(
+            let bootstrap_summary = crate::utils::bootstrap::app_bootstrap(app.handle()).map_err(
                 |error| -> Box<dyn std::error::Error> { Box::new(std::io::Error::other(error)) },
             )?;
+            // ロガー初期化に失敗しても起動自体は継続する。
+            if let Err(error) = crate::utils::logger::init(app.handle()) {
+                eprintln!("Failed to initialize file logger: {error}");
+            }
+            if !bootstrap_summary.created_directories.is_empty() {
+                log::info!(
+                    "Initialized app data directories: {}",
+                    bootstrap_summary.created_directories.join(", ")
+                );
+            }
             if let Some(payload_path) = elevated_launch_payload_path_for_setup.clone() {
                 if let Some(window) = app.get_webview_window("main") {
                     let _ = window.hide();
@@ -499,16 +601,28 @@ pub fn run() {
                     )
                     .await;
                     if let Err(error) = &result {
-                        eprintln!("Failed to execute elevated launch payload: {error}");
+                        log::error!("Failed to execute elevated launch payload: {error}");
                     }
                     app_handle.exit(if result.is_ok() { 0 } else { 1 });
                 });
                 return Ok(());
             }
 
-            setup_tray(app.handle(), tray_webview_destroy_state_for_setup.clone())?;
+            setup_tray(
+                app.handle(),
+                tray_webview_destroy_state_for_setup.clone(),
+                tray_menu_cursor_watcher_state_for_setup.clone(),
+            )?;
             crate::utils::background_notifications::start_worker(app.handle().clone());
 
+            // 前回クラッシュしたインストールの残骸は起動時に片付けておく。
+            let app_handle_for_cleanup = app.handle().clone();
+            std::thread::spawn(move || {
+                crate::services::snr_service::cleanup_orphaned_install_dirs_on_startup(
+                    &app_handle_for_cleanup,
+                );
+            });
+
             if auto_launch_modded {
                 if let Some(window) = app.get_webview_window("main") {
                     let _ = window.hide();
@@ -521,32 +635,109 @@ pub fn run() {
                     true,
                 );
             } else {
-                show_main_window(app.handle(), &tray_webview_destroy_state_for_setup);
+                let (start_minimized, close_webview_on_tray_background) =
+                    match crate::utils::settings::load_or_init_settings(app.handle()) {
+                        Ok(settings) => (
+                            settings.start_minimized,
+                            settings.close_webview_on_tray_background,
+                        ),
+                        // 設定を読めない場合は、起動したことが分かるようウィンドウを表示する。
+                        Err(_) => (false, false),
+                    };
+
+                if start_minimized {
+                    // ウィンドウは非表示のまま、トレイ常駐で静かに起動する。
+                    if close_webview_on_tray_background {
+                        tray_webview_destroy_state_for_setup.schedule_destroy(app.handle().clone());
+                    }
+                } else {
+                    show_main_window(app.handle(), &tray_webview_destroy_state_for_setup);
+                }
             }
 
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
+            commands::settings::app_bootstrap,
             commands::settings::settings_get,
             commands::settings::settings_update,
+            commands::settings::env_report,
+            commands::settings::locales_supported,
+            commands::settings::locale_set,
             commands::settings::settings_profile_ready,
             commands::settings::settings_open_folder,
+            commands::settings::open_game_folder,
+            commands::settings::open_profile_bepinex_folder,
+            commands::settings::platform_get,
+            commands::settings::platform_set,
+            commands::settings::settings_profile_overrides_get,
+            commands::settings::settings_profile_overrides_set,
             commands::migration::migration_export,
+            commands::migration::migration_estimate,
             commands::migration::migration_import,
+            commands::migration::locallow_writable_check,
+            commands::migration::migration_import_dialog,
             commands::migration::migration_validate_archive_password,
+            commands::migration::migration_archive_info,
+            commands::migration::migration_backups_list,
+            commands::migration::migration_recover_backup,
+            commands::migration::migration_backup_discard,
+            commands::modprofile::modprofile_validate_file,
+            commands::modprofile::modprofile_effective,
+            commands::modprofile::links_get,
+            commands::modprofile::features_get,
             commands::presets::presets_list_local,
             commands::presets::presets_export,
+            commands::presets::presets_export_dialog,
+            commands::presets::presets_export_all,
             commands::presets::presets_inspect_archive,
+            commands::presets::presets_verify_archive,
             commands::presets::presets_import_archive,
+            commands::presets::presets_import_archive_dialog,
+            commands::presets::options_backup,
+            commands::presets::options_restore,
+            commands::presets::options_repair,
+            commands::profiles::profiles_list,
+            commands::profiles::profile_health,
+            commands::profiles::savedata_paths,
+            commands::profiles::profile_rename,
+            commands::profiles::profile_legacy_detect,
+            commands::profiles::profile_legacy_migrate,
+            commands::profiles::file_hash,
             commands::finder::finder_detect_among_us,
             commands::finder::finder_detect_platform,
+            commands::finder::path_drift_check,
             commands::finder::finder_detect_platforms,
+            commands::finder::finder_scan,
+            commands::finder::finder_scan_cancel,
             commands::snr::mod_releases_list,
+            commands::snr::releases_refresh,
             commands::snr::mod_install,
+            commands::snr::mod_install_from_file,
+            commands::snr::mod_install_and_launch,
+            commands::snr::mod_update_diff,
+            commands::snr::mod_download_release,
+            commands::snr::mod_repatch_needed,
+            commands::snr::mod_patchers_resync,
+            commands::snr::mod_install_cancel,
+            commands::snr::mod_install_in_progress,
+            commands::snr::operation_status,
+            commands::snr::mod_install_retry,
+            commands::snr::install_cleanup_orphans,
+            commands::snr::managed_artifacts_list,
+            commands::snr::managed_artifacts_cleanup,
+            commands::snr::install_archive_validate,
+            commands::snr::github_rate_limit_status,
             commands::snr::mod_uninstall,
+            commands::snr::mod_uninstall_preview,
             commands::snr::mod_preserved_save_data_status,
+            commands::snr::preserved_savedata_details,
+            commands::snr::bepinex_config_preservation_get,
             commands::snr::mod_savedata_preview,
+            commands::snr::savedata_compat_check,
             commands::snr::mod_savedata_import,
+            commands::snr::mod_savedata_import_archive,
+            commands::snr::mod_savedata_import_cancel,
             commands::snr::mod_savedata_merge_presets,
             commands::snr::mod_preserved_savedata_merge_presets,
             commands::snr::snr_releases_list,
@@ -558,22 +749,47 @@ pub fn run() {
             commands::snr::snr_savedata_merge_presets,
             commands::snr::snr_preserved_savedata_merge_presets,
             commands::reporting::reporting_prepare,
+            commands::reporting::reporting_terms_get,
+            commands::reporting::reporting_healthcheck,
             commands::reporting::reporting_threads_list,
+            commands::reporting::reporting_status_summary,
+            commands::reporting::reporting_thread_resolve,
             commands::reporting::reporting_messages_list,
+            commands::reporting::reporting_thread_export,
             commands::reporting::reporting_message_send,
             commands::reporting::reporting_report_send,
             commands::reporting::reporting_notification_flag_get,
             commands::reporting::reporting_log_source_get,
+            #[cfg(debug_assertions)]
+            commands::reporting::reporting_log_decrypt,
             commands::notifications::notifications_take_open_target,
+            commands::notifications::announce_refresh,
+            commands::notifications::notifications_poll_now,
+            commands::notifications::notifications_worker_set_paused,
+            commands::notifications::unread_counts_get,
             commands::game_servers::game_servers_join_direct,
+            commands::game_servers::game_servers_room_check,
             commands::launch::launch_modded,
+            commands::launch::launch_wait_ready,
+            commands::launch::game_processes_list,
             commands::launch::launch_modded_elevated,
             commands::launch::launch_vanilla,
             commands::launch::launch_vanilla_elevated,
             commands::launch::launch_shortcut_create,
+            commands::launch::shortcuts_list,
+            commands::launch::shortcuts_cleanup,
+            commands::launch::startup_autolaunch_get,
+            commands::launch::startup_autolaunch_set,
             commands::launch::launch_modded_first_setup_pending,
+            commands::launch::interop_generation_status,
+            commands::launch::launch_doorstop_artifacts_get,
+            commands::launch::launch_doorstop_artifacts_disable,
             commands::launch::launch_autolaunch_error_take,
             commands::launch::launch_game_running_get,
+            commands::launch::game_state_get,
+            commands::launch::launch_preflight,
+            commands::launch::profile_validate,
+            commands::launch::av_status,
             commands::launch::launch_steam_running_get,
             commands::tray::tray_launch_modded,
             commands::tray::tray_show_main_window,
@@ -584,6 +800,7 @@ pub fn run() {
             commands::epic_commands::epic_session_restore,
             commands::epic_commands::epic_logged_in_get,
             commands::epic_commands::epic_status_get,
+            commands::epic_commands::epic_launch_precheck,
             commands::epic_commands::epic_logout,
         ])
         .build(tauri::generate_context!())
@@ -594,9 +811,15 @@ pub fn run() {
             return;
         }
 
+        if let RunEvent::Exit = event {
+            tray_menu_cursor_watcher_state.stop();
+            return;
+        }
+
         if let RunEvent::ExitRequested { api, code, .. } = event {
             // 明示終了(codeあり)か終了バイパス時は、通常終了フローをそのまま通す。
             if code.is_some() || bypass_close_to_tray_for_exit.load(Ordering::SeqCst) {
+                tray_menu_cursor_watcher_state.stop();
                 return;
             }
 
@@ -0,0 +1,127 @@
+//! サポート向けの環境レポート生成サービス。
+//! 既存の検出・状態取得処理を束ね、チャットへ貼れる程度の小さな要約にする。
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Runtime};
+
+use crate::services::snr_service;
+use crate::utils::{epic_api, finder, mod_profile, settings};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvReportPlatform {
+    pub path: String,
+    pub platform: String,
+}
+
+/// パスはユーザー名などを含まないよう、既知の基準ディレクトリからの相対表記にする。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvReport {
+    pub launcher_version: String,
+    pub os: String,
+    pub arch: String,
+    pub selected_platform: String,
+    pub among_us_path: String,
+    pub among_us_path_exists: bool,
+    pub detected_platforms: Vec<EnvReportPlatform>,
+    pub profile_path: String,
+    pub profile_exists: bool,
+    pub profile_ready: bool,
+    pub installed_tag: Option<String>,
+    pub interop_exists: bool,
+    // Epicログイン機能が無効な場合は None。
+    pub epic_logged_in: Option<bool>,
+    pub features: mod_profile::FeatureFlags,
+}
+
+fn user_home_dir() -> Option<PathBuf> {
+    #[cfg(windows)]
+    let home = std::env::var_os("USERPROFILE");
+    #[cfg(not(windows))]
+    let home = std::env::var_os("HOME");
+    home.map(PathBuf::from)
+}
+
+/// 既知の基準ディレクトリ配下なら `<label>/相対パス` に置き換える。
+fn redact_path(path: &str, bases: &[(&str, PathBuf)]) -> String {
+    let trimmed = path.trim();
+    if trimmed.is_empty() {
+        return String::new();
+    }
+    let target = Path::new(trimmed);
+    for (label, base) in bases {
+        if let Ok(relative) = target.strip_prefix(base) {
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            return if relative.is_empty() {
+                label.to_string()
+            } else {
+                format!("{label}/{relative}")
+            };
+        }
+    }
+    // 基準外の場所はフォルダ名だけを残す。
+    target
+        .file_name()
+        .map(|name| format!(".../{}", name.to_string_lossy()))
+        .unwrap_or_else(|| "...".to_string())
+}
+
+fn interop_exists(profile_path: &Path) -> bool {
+    std::fs::read_dir(profile_path.join("BepInEx").join("interop"))
+        .map(|mut entries| entries.next().is_some())
+        .unwrap_or(false)
+}
+
+/// 現在の設定と検出結果から、パスを伏せた環境レポートを組み立てる。
+pub fn env_report<R: Runtime>(app: &AppHandle<R>) -> Result<EnvReport, String> {
+    let launcher_settings = settings::load_or_init_settings(app)?;
+
+    // より具体的な基準を先に照合する。
+    let mut bases = Vec::new();
+    if let Ok(app_data) = settings::app_data_dir(app) {
+        bases.push(("<appdata>", app_data));
+    }
+    if let Some(home) = user_home_dir() {
+        bases.push(("~", home));
+    }
+
+    let among_us_path = launcher_settings.among_us_path.trim();
+    let profile_path = PathBuf::from(launcher_settings.profile_path.trim());
+    let profile_configured = !launcher_settings.profile_path.trim().is_empty();
+
+    let detected_platforms = finder::get_among_us_paths()
+        .into_iter()
+        .filter_map(|path| {
+            let path = path.to_string_lossy().to_string();
+            finder::detect_platform(&path)
+                .ok()
+                .map(|platform| EnvReportPlatform {
+                    path: redact_path(&path, &bases),
+                    platform,
+                })
+        })
+        .collect();
+
+    let epic_logged_in = mod_profile::feature_enabled(mod_profile::Feature::EpicLogin)
+        .then(|| epic_api::load_session().is_some());
+
+    Ok(EnvReport {
+        launcher_version: app.package_info().version.to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        selected_platform: launcher_settings.game_platform.as_str().to_string(),
+        among_us_path: redact_path(among_us_path, &bases),
+        among_us_path_exists: !among_us_path.is_empty() && Path::new(among_us_path).is_dir(),
+        detected_platforms,
+        profile_path: redact_path(&launcher_settings.profile_path, &bases),
+        profile_exists: profile_configured && profile_path.is_dir(),
+        profile_ready: profile_configured && settings::is_profile_ready(&profile_path),
+        installed_tag: snr_service::read_install_manifest(&profile_path)
+            .map(|manifest| manifest.tag),
+        interop_exists: profile_configured && interop_exists(&profile_path),
+        epic_logged_in,
+        features: mod_profile::get().features.clone(),
+    })
+}
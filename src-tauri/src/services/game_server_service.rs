@@ -4,12 +4,18 @@
 use std::time::Duration;
 
 use reqwest::Client;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::utils::mod_profile;
 
 const JOIN_LOCALHOST_UNREACHABLE_ERROR: &str = "JOIN_LOCALHOST_UNREACHABLE";
 const JOIN_LOCALHOST_ERROR: &str = "JOIN_LOCALHOST_ERROR";
+const ROOMS_API_PATH: &str = "api/games/all_for_web";
+const ROOMS_API_TIMEOUT: Duration = Duration::from_secs(10);
+const GAME_NAME_ALPHABET_V2: &[u8] = b"QWXRTYLPESDFGHUJKZOCVBINMA";
+// 部屋の状態が「開始前」を表す GameState の値。
+const GAME_STATE_NOT_STARTED: i64 = 0;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -19,6 +25,24 @@ pub struct GameServerJoinDirectResult {
     pub ok: bool,
 }
 
+/// 参加前のルームコード確認結果。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameServerRoomCheck {
+    pub server_id: String,
+    pub room_code: String,
+    pub exists: bool,
+    pub player_count: Option<i64>,
+    pub max_players: Option<i64>,
+    pub joinable: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct RoomsApiResponse {
+    #[serde(default)]
+    games: Vec<Value>,
+}
+
 fn normalize_query_suffix(query: &str) -> String {
     // query は先頭 ? あり/なしのどちらでも受け付ける。
     let trimmed = query.trim();
@@ -46,6 +70,7 @@ pub async fn join_direct(query: String) -> Result<GameServerJoinDirectResult, St
     let config = &mod_profile::get().apis.join_direct;
     let timeout = Duration::from_millis(config.timeout_ms);
     let client = Client::builder()
+        .user_agent(mod_profile::launcher_user_agent())
         .timeout(timeout)
         .build()
         .map_err(|_| JOIN_LOCALHOST_ERROR.to_string())?;
@@ -72,3 +97,131 @@ pub async fn join_direct(query: String) -> Result<GameServerJoinDirectResult, St
         ok,
     })
 }
+
+fn int_to_game_name_v2(value: i32) -> String {
+    let a = (value & 0x3ff) as usize;
+    let b = ((value >> 10) & 0xfffff) as usize;
+    [
+        a % 26,
+        (a / 26) % 26,
+        b % 26,
+        (b / 26) % 26,
+        (b / (26 * 26)) % 26,
+        (b / (26 * 26 * 26)) % 26,
+    ]
+    .iter()
+    .map(|index| GAME_NAME_ALPHABET_V2[*index] as char)
+    .collect()
+}
+
+/// rooms API の GameId をルームコードへ変換する(フロントの formatGameId と同じ規則)。
+fn game_id_to_code(value: i32) -> String {
+    if value < -1 {
+        return int_to_game_name_v2(value);
+    }
+    // 旧形式は4文字のコードをリトルエンディアンの整数として保持している。
+    let decoded = String::from_utf8_lossy(&value.to_le_bytes()).to_string();
+    let trimmed = decoded.trim_end_matches('\0');
+    if trimmed.is_empty() {
+        value.to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+fn normalize_room_code(room_code: &str) -> Result<String, String> {
+    let normalized = room_code.trim().to_ascii_uppercase();
+    let valid_length = normalized.len() == 4 || normalized.len() == 6;
+    if !valid_length || !normalized.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(format!(
+            "Room code must be 4 or 6 letters: {}",
+            room_code.trim()
+        ));
+    }
+    Ok(normalized)
+}
+
+// rooms API は数値を文字列で返すことがあるため、両方を受け付ける。
+fn room_field_as_i64(room: &Value, field: &str) -> Option<i64> {
+    match room.get(field)? {
+        Value::Number(number) => number.as_i64(),
+        Value::String(text) => text.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// 指定サーバーの rooms API からルームコードに一致する部屋を探し、参加可否を返す。
+pub async fn check_room(
+    server_id: String,
+    room_code: String,
+) -> Result<GameServerRoomCheck, String> {
+    let server_id = server_id.trim().to_string();
+    let server = mod_profile::get()
+        .apis
+        .game_servers
+        .iter()
+        .find(|server| server.id == server_id)
+        .ok_or_else(|| format!("Unknown game server id: {server_id}"))?;
+    let room_code = normalize_room_code(&room_code)?;
+
+    let client = Client::builder()
+        .user_agent(mod_profile::launcher_user_agent())
+        .timeout(ROOMS_API_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to create rooms API client: {e}"))?;
+    let url = format!(
+        "{}/{ROOMS_API_PATH}",
+        server.rooms_api_domain.trim().trim_end_matches('/')
+    );
+    let response = client
+        .get(&url)
+        .header("Accept", "application/json")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to request rooms API: {e}"))?;
+    if !response.status().is_success() {
+        return Err(format!(
+            "Rooms API request failed with status {}",
+            response.status().as_u16()
+        ));
+    }
+    let payload = response
+        .json::<RoomsApiResponse>()
+        .await
+        .map_err(|e| format!("Failed to parse rooms API response: {e}"))?;
+
+    let room = payload.games.iter().find(|room| {
+        room_field_as_i64(room, "GameId")
+            .and_then(|game_id| i32::try_from(game_id).ok())
+            .is_some_and(|game_id| game_id_to_code(game_id) == room_code)
+    });
+    let Some(room) = room else {
+        return Ok(GameServerRoomCheck {
+            server_id,
+            room_code,
+            exists: false,
+            player_count: None,
+            max_players: None,
+            joinable: false,
+        });
+    };
+
+    let player_count = room_field_as_i64(room, "PlayerCount");
+    let max_players = room_field_as_i64(room, "MaxPlayers");
+    let has_space = match (player_count, max_players) {
+        (Some(players), Some(max)) if max > 0 => players < max,
+        _ => true,
+    };
+    let not_started = room_field_as_i64(room, "GameState")
+        .map(|state| state == GAME_STATE_NOT_STARTED)
+        .unwrap_or(true);
+
+    Ok(GameServerRoomCheck {
+        server_id,
+        room_code,
+        exists: true,
+        player_count,
+        max_players,
+        joinable: has_space && not_started,
+    })
+}
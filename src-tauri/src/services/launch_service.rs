@@ -3,6 +3,7 @@
 // 起動前検証・プロセス追跡・補助ファイル管理を一箇所に集約する。
 
 use crate::utils::{
+    antivirus,
     epic_api::{self, EpicApi},
     mod_profile, settings,
 };
@@ -24,7 +25,30 @@ const STEAM_APP_ID_FILE_NAME: &str = "steam_appid.txt";
 const STEAM_APP_ID_VALUE: &str = "945360";
 #[cfg(windows)]
 const STEAM_CLIENT_EXECUTABLE_NAME: &str = "steam.exe";
-const ELEVATED_LAUNCH_DIR_NAME: &str = "elevated-launch";
+pub const ELEVATED_LAUNCH_DIR_NAME: &str = "elevated-launch";
+#[cfg(windows)]
+const STARTUP_RUN_KEY_PATH: &str = r"Software\Microsoft\Windows\CurrentVersion\Run";
+const INTEROP_STALL_THRESHOLD: Duration = Duration::from_secs(90);
+const BEPINEX_LOG_FILE_NAME: &str = "LogOutput.log";
+const BEPINEX_STARTUP_COMPLETE_MARKER: &str = "Chainloader startup complete";
+// 手動導入のDoorstopが残ると、Vanilla起動でもModが読み込まれる原因になる。
+const DOORSTOP_ARTIFACT_NAMES: [&str; 4] = [
+    "winhttp.dll",
+    "version.dll",
+    "doorstop_config.ini",
+    ".doorstop_version",
+];
+const DOORSTOP_DISABLED_SUFFIX: &str = ".disabled";
+const GAME_READY_DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+const GAME_READY_MAX_TIMEOUT: Duration = Duration::from_secs(600);
+const GAME_READY_POLL_INTERVAL: Duration = Duration::from_millis(500);
+// BepInEx(IL2CPP)はDoorstop経由で.NETランタイムを読み込むため、Modded判定の目印にする。
+#[cfg(windows)]
+const BEPINEX_RUNTIME_MODULE_NAME: &str = "coreclr.dll";
+#[cfg(windows)]
+const GAME_WINDOW_CLASS_NAME: &str = "UnityWndClass";
+#[cfg(windows)]
+const GAME_WINDOW_TITLE: &str = "Among Us";
 const ELEVATED_LAUNCH_FAILED_ERROR_PREFIX: &str = "ELEVATED_LAUNCH_FAILED:";
 #[cfg(windows)]
 const ELEVATION_REQUIRED_ERROR_PREFIX: &str = "ELEVATION_REQUIRED:";
@@ -111,9 +135,150 @@ fn resolve_available_shortcut_path(desktop_dir: &Path, file_name: &str) -> PathB
     unreachable!("infinite suffix iterator exhausted unexpectedly")
 }
 
+/// running-game.pid に保存する実行中ゲームの識別情報。
+/// PID再利用で別プロセスを誤検出しないよう、起動時刻も併せて記録する。
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RunningGameRecord {
+    pid: u32,
+    #[serde(default)]
+    started_at: Option<u64>,
+}
+
+/// 起動直後の黒画面から操作可能になるまでの段階。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GameLaunchPhase {
+    Launching,
+    Ready,
+}
+
 #[derive(Clone, serde::Serialize)]
 pub struct GameStatePayload {
     pub running: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<GameLaunchPhase>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShortcutCleanupResult {
+    pub kept: Option<String>,
+    pub removed: Vec<String>,
+}
+
+/// Webview 再生成時などに取り直すゲームの実行状態。
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameStateSnapshot {
+    pub running: bool,
+    pub pid: Option<u32>,
+    /// 追跡中プロセスの起動時刻（UNIXミリ秒）。取得できない場合は None。
+    pub started_at_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupAutolaunchStatus {
+    pub enabled: bool,
+    pub autolaunch_modded: bool,
+    /// 登録済みの実行ファイルが現在のランチャーと一致するか。
+    pub path_matches: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameProcessInfo {
+    pub pid: u32,
+    /// Modが読み込まれているか。プロセス情報を参照できない場合は None。
+    pub modded: Option<bool>,
+    /// ランチャーが起動・追跡しているプロセスか。
+    pub tracked: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameReadyResult {
+    pub ready: bool,
+    pub window_detected: bool,
+    pub elapsed_ms: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PreflightStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreflightCheck {
+    pub id: String,
+    pub status: PreflightStatus,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EpicLaunchPrecheckStatus {
+    Ok,
+    NeedsRelogin,
+    NetworkError,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpicLaunchPrecheckResult {
+    pub status: EpicLaunchPrecheckStatus,
+    pub message: Option<String>,
+}
+
+/// 起動前チェックの集計結果。Failが1件もなければ ready になる。
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchPreflightReport {
+    pub ready: bool,
+    pub checks: Vec<PreflightCheck>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InteropGenerationState {
+    NotStarted,
+    InProgress,
+    Stalled,
+    Complete,
+}
+
+/// 初回Modded起動時のinterop生成状況。UIのポーリング表示に使う。
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InteropGenerationStatus {
+    pub state: InteropGenerationState,
+    pub file_count: u64,
+    pub seconds_since_last_change: Option<u64>,
+}
+
+/// プロファイル検証で確認した構成要素1件分の結果。
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileComponentCheck {
+    pub id: String,
+    pub path: String,
+    pub present: bool,
+    /// false の要素は初回起動時に生成されるため、欠けていても valid を妨げない。
+    pub required: bool,
+}
+
+/// 任意フォルダをModdedプロファイルとして検証した結果。
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileValidationReport {
+    pub path: String,
+    pub valid: bool,
+    pub components: Vec<ProfileComponentCheck>,
 }
 
 pub fn clear_autolaunch_error() {
@@ -190,11 +355,15 @@ pub fn is_game_running<R: Runtime>(app: AppHandle<R>) -> Result<bool, String> {
         None => {
             drop(guard);
 
-            let Some(pid) = load_persisted_running_game_pid(&app)? else {
+            let Some(record) = load_persisted_running_game_pid(&app)? else {
                 return Ok(false);
             };
 
-            if is_pid_running(pid) {
+            if is_recorded_game_running(
+                &record,
+                is_pid_running(record.pid),
+                process_start_time(record.pid),
+            ) {
                 return Ok(true);
             }
 
@@ -204,6 +373,35 @@ pub fn is_game_running<R: Runtime>(app: AppHandle<R>) -> Result<bool, String> {
     }
 }
 
+// FILETIME(1601年起点の100ns単位)をUNIXミリ秒へ変換する。
+fn filetime_to_unix_ms(filetime: u64) -> Option<u64> {
+    const FILETIME_UNIX_EPOCH: u64 = 116_444_736_000_000_000;
+    filetime
+        .checked_sub(FILETIME_UNIX_EPOCH)
+        .map(|elapsed| elapsed / 10_000)
+}
+
+/// 実行状態と、追跡できていればPID・起動時刻を返す。
+pub fn game_state_snapshot<R: Runtime>(app: AppHandle<R>) -> Result<GameStateSnapshot, String> {
+    if !is_game_running(app.clone())? {
+        return Ok(GameStateSnapshot {
+            running: false,
+            pid: None,
+            started_at_ms: None,
+        });
+    }
+
+    // is_game_running が実行中と判定した時点で PID ファイルは最新化されている。
+    let record = load_persisted_running_game_pid(&app)?;
+    Ok(GameStateSnapshot {
+        running: true,
+        pid: record.as_ref().map(|record| record.pid),
+        started_at_ms: record
+            .and_then(|record| record.started_at)
+            .and_then(filetime_to_unix_ms),
+    })
+}
+
 fn running_game_pid_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
     // PIDファイルはアプリ専用データ配下へ保存する。
     Ok(settings::app_data_dir(app)?.join(RUNNING_GAME_PID_FILE_NAME))
@@ -213,7 +411,7 @@ fn persist_running_game_pid<R: Runtime>(app: &AppHandle<R>, pid: u32) {
     let path = match running_game_pid_path(app) {
         Ok(path) => path,
         Err(error) => {
-            eprintln!("Failed to resolve running game PID path: {error}");
+            log::warn!("Failed to resolve running game PID path: {error}");
             return;
         }
     };
@@ -221,13 +419,24 @@ fn persist_running_game_pid<R: Runtime>(app: &AppHandle<R>, pid: u32) {
     if let Some(parent) = path.parent() {
         // 初回起動時にも書き込めるよう親ディレクトリを準備する。
         if let Err(error) = fs::create_dir_all(parent) {
-            eprintln!("Failed to create running game PID directory: {error}");
+            log::warn!("Failed to create running game PID directory: {error}");
             return;
         }
     }
 
-    if let Err(error) = fs::write(&path, pid.to_string()) {
-        eprintln!("Failed to persist running game PID: {error}");
+    let record = RunningGameRecord {
+        pid,
+        started_at: process_start_time(pid),
+    };
+    let json = match serde_json::to_string(&record) {
+        Ok(json) => json,
+        Err(error) => {
+            log::warn!("Failed to serialize running game PID: {error}");
+            return;
+        }
+    };
+    if let Err(error) = fs::write(&path, json) {
+        log::warn!("Failed to persist running game PID: {error}");
     }
 }
 
@@ -235,19 +444,21 @@ fn clear_persisted_running_game_pid<R: Runtime>(app: &AppHandle<R>) {
     let path = match running_game_pid_path(app) {
         Ok(path) => path,
         Err(error) => {
-            eprintln!("Failed to resolve running game PID path: {error}");
+            log::warn!("Failed to resolve running game PID path: {error}");
             return;
         }
     };
 
     if let Err(error) = fs::remove_file(path) {
         if error.kind() != std::io::ErrorKind::NotFound {
-            eprintln!("Failed to clear running game PID: {error}");
+            log::warn!("Failed to clear running game PID: {error}");
         }
     }
 }
 
-fn load_persisted_running_game_pid<R: Runtime>(app: &AppHandle<R>) -> Result<Option<u32>, String> {
+fn load_persisted_running_game_pid<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<Option<RunningGameRecord>, String> {
     let path = running_game_pid_path(app)?;
     let content = match fs::read_to_string(&path) {
         Ok(content) => content,
@@ -260,9 +471,9 @@ fn load_persisted_running_game_pid<R: Runtime>(app: &AppHandle<R>) -> Result<Opt
         }
     };
 
-    match content.trim().parse::<u32>() {
-        Ok(pid) => Ok(Some(pid)),
-        Err(_) => {
+    match parse_running_game_record(&content) {
+        Some(record) => Ok(Some(record)),
+        None => {
             // 壊れたPIDファイルは削除して次回以降の誤判定を防ぐ。
             clear_persisted_running_game_pid(app);
             Ok(None)
@@ -270,6 +481,59 @@ fn load_persisted_running_game_pid<R: Runtime>(app: &AppHandle<R>) -> Result<Opt
     }
 }
 
+fn parse_running_game_record(content: &str) -> Option<RunningGameRecord> {
+    let trimmed = content.trim();
+    // 旧形式(PID数値のみ)は起動時刻なしのレコードとして読み込む。
+    if let Ok(pid) = trimmed.parse::<u32>() {
+        return Some(RunningGameRecord {
+            pid,
+            started_at: None,
+        });
+    }
+    serde_json::from_str::<RunningGameRecord>(trimmed).ok()
+}
+
+fn is_recorded_game_running(
+    record: &RunningGameRecord,
+    pid_running: bool,
+    current_started_at: Option<u64>,
+) -> bool {
+    if !pid_running {
+        return false;
+    }
+    // 双方の起動時刻が取れた場合のみ比較し、不一致ならPID再利用とみなす。
+    match (record.started_at, current_started_at) {
+        (Some(recorded), Some(current)) => recorded == current,
+        _ => true,
+    }
+}
+
+#[cfg(windows)]
+fn process_start_time(pid: u32) -> Option<u64> {
+    use windows::Win32::Foundation::{CloseHandle, FILETIME};
+    use windows::Win32::System::Threading::{
+        GetProcessTimes, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+
+    // 安全性: ハンドルは取得成功時のみ使用し、参照後に必ず閉じる。
+    let handle = unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) }.ok()?;
+    let mut creation = FILETIME::default();
+    let mut exit = FILETIME::default();
+    let mut kernel = FILETIME::default();
+    let mut user = FILETIME::default();
+    let result =
+        unsafe { GetProcessTimes(handle, &mut creation, &mut exit, &mut kernel, &mut user) };
+    let _ = unsafe { CloseHandle(handle) };
+    result.ok()?;
+
+    Some((u64::from(creation.dwHighDateTime) << 32) | u64::from(creation.dwLowDateTime))
+}
+
+#[cfg(not(windows))]
+fn process_start_time(_pid: u32) -> Option<u64> {
+    None
+}
+
 #[cfg(windows)]
 fn is_pid_running(pid: u32) -> bool {
     snapshot_contains_process(pid, among_us_exe_file_name())
@@ -292,6 +556,13 @@ pub fn is_steam_running() -> bool {
 
 #[cfg(windows)]
 fn snapshot_contains_process(pid: u32, executable_name: &str) -> bool {
+    snapshot_process_ids(executable_name)
+        .into_iter()
+        .any(|entry_pid| pid == 0 || entry_pid == pid)
+}
+
+#[cfg(windows)]
+fn snapshot_process_ids(executable_name: &str) -> Vec<u32> {
     use windows::Win32::Foundation::CloseHandle;
     use windows::Win32::System::Diagnostics::ToolHelp::{
         CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W,
@@ -300,7 +571,7 @@ fn snapshot_contains_process(pid: u32, executable_name: &str) -> bool {
 
     let snapshot = match unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0) } {
         Ok(handle) => handle,
-        Err(_) => return false,
+        Err(_) => return Vec::new(),
     };
 
     let mut entry = PROCESSENTRY32W {
@@ -308,24 +579,101 @@ fn snapshot_contains_process(pid: u32, executable_name: &str) -> bool {
         ..Default::default()
     };
     let target_name = executable_name.trim();
-    let found = unsafe {
+    let mut pids = Vec::new();
+    unsafe {
         let mut has_entry = Process32FirstW(snapshot, &mut entry).is_ok();
-        let mut matched = false;
-
         while has_entry {
-            let pid_matches = pid == 0 || entry.th32ProcessID == pid;
-            if pid_matches && process_entry_file_name(&entry).eq_ignore_ascii_case(target_name) {
-                matched = true;
-                break;
+            if process_entry_file_name(&entry).eq_ignore_ascii_case(target_name) {
+                pids.push(entry.th32ProcessID);
             }
             has_entry = Process32NextW(snapshot, &mut entry).is_ok();
         }
+    }
+
+    let _ = unsafe { CloseHandle(snapshot) };
+    pids
+}
 
-        matched
+/// 読み込み済みモジュールからModの有無を判定する。参照できない場合は None。
+#[cfg(windows)]
+fn process_has_bepinex_runtime(pid: u32) -> Option<bool> {
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Diagnostics::ToolHelp::{
+        CreateToolhelp32Snapshot, Module32FirstW, Module32NextW, MODULEENTRY32W, TH32CS_SNAPMODULE,
+        TH32CS_SNAPMODULE32,
+    };
+
+    let snapshot =
+        unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPMODULE | TH32CS_SNAPMODULE32, pid) }.ok()?;
+    let mut entry = MODULEENTRY32W {
+        dwSize: std::mem::size_of::<MODULEENTRY32W>() as u32,
+        ..Default::default()
+    };
+    let result = unsafe {
+        let mut has_entry = Module32FirstW(snapshot, &mut entry).is_ok();
+        // 先頭モジュールすら読めない場合は権限不足などで判定不能とする。
+        if !has_entry {
+            None
+        } else {
+            let mut found = false;
+            while has_entry {
+                let len = entry
+                    .szModule
+                    .iter()
+                    .position(|value| *value == 0)
+                    .unwrap_or(entry.szModule.len());
+                let module_name = String::from_utf16_lossy(&entry.szModule[..len]);
+                if module_name.eq_ignore_ascii_case(BEPINEX_RUNTIME_MODULE_NAME) {
+                    found = true;
+                    break;
+                }
+                has_entry = Module32NextW(snapshot, &mut entry).is_ok();
+            }
+            Some(found)
+        }
     };
 
     let _ = unsafe { CloseHandle(snapshot) };
-    found
+    result
+}
+
+#[cfg(windows)]
+fn list_among_us_processes() -> Vec<(u32, Option<bool>)> {
+    snapshot_process_ids(among_us_exe_file_name())
+        .into_iter()
+        .map(|pid| (pid, process_has_bepinex_runtime(pid)))
+        .collect()
+}
+
+#[cfg(not(windows))]
+fn list_among_us_processes() -> Vec<(u32, Option<bool>)> {
+    Vec::new()
+}
+
+fn tracked_game_pid<R: Runtime>(app: &AppHandle<R>) -> Option<u32> {
+    let tracked = GAME_PROCESS
+        .lock()
+        .ok()
+        .and_then(|guard| guard.as_ref().map(|process| process.id()));
+    tracked.or_else(|| {
+        load_persisted_running_game_pid(app)
+            .ok()
+            .flatten()
+            .map(|record| record.pid)
+    })
+}
+
+/// 実行中のAmong Usプロセスを列挙し、Modの有無とランチャー追跡対象かを返す。
+pub fn list_game_processes<R: Runtime>(app: &AppHandle<R>) -> Vec<GameProcessInfo> {
+    let tracked_pid = tracked_game_pid(app);
+    list_among_us_processes()
+        .into_iter()
+        .map(|(pid, modded)| GameProcessInfo {
+            pid,
+            modded,
+            tracked: tracked_pid == Some(pid),
+        })
+        .collect()
 }
 
 #[cfg(windows)]
@@ -340,6 +688,24 @@ fn process_entry_file_name(
     String::from_utf16_lossy(&entry.szExeFile[..len])
 }
 
+#[cfg(windows)]
+fn is_game_window_visible() -> bool {
+    use windows::core::PCWSTR;
+    use windows::Win32::UI::WindowsAndMessaging::FindWindowW;
+
+    let class_name = str_to_utf16(GAME_WINDOW_CLASS_NAME);
+    let title = str_to_utf16(GAME_WINDOW_TITLE);
+    // 安全性: 両文字列はNUL終端済みで、呼び出し中は所有バッファが生存している。
+    unsafe { FindWindowW(PCWSTR(class_name.as_ptr()), PCWSTR(title.as_ptr())) }
+        .map(|hwnd| !hwnd.is_invalid())
+        .unwrap_or(false)
+}
+
+#[cfg(not(windows))]
+fn is_game_window_visible() -> bool {
+    false
+}
+
 #[cfg(windows)]
 fn path_to_utf16(value: &Path) -> Vec<u16> {
     use std::os::windows::ffi::OsStrExt;
@@ -365,11 +731,7 @@ fn create_shortcut_with_shell_link(
     description: &str,
 ) -> Result<(), String> {
     use windows::core::{Interface, PCWSTR};
-    use windows::Win32::Foundation::RPC_E_CHANGED_MODE;
-    use windows::Win32::System::Com::{
-        CoCreateInstance, CoInitializeEx, CoUninitialize, IPersistFile, CLSCTX_INPROC_SERVER,
-        COINIT_MULTITHREADED,
-    };
+    use windows::Win32::System::Com::{CoCreateInstance, IPersistFile, CLSCTX_INPROC_SERVER};
     use windows::Win32::UI::Shell::{IShellLinkW, ShellLink};
 
     let target_utf16 = path_to_utf16(target_path);
@@ -379,18 +741,7 @@ fn create_shortcut_with_shell_link(
     let description_utf16 = str_to_utf16(description);
     let shortcut_utf16 = path_to_utf16(shortcut_path);
 
-    let com_init_result = unsafe { CoInitializeEx(None, COINIT_MULTITHREADED) };
-    let needs_uninitialize = if com_init_result.is_ok() {
-        true
-    } else if com_init_result == RPC_E_CHANGED_MODE {
-        // COM is already initialized on this thread in a different apartment model.
-        // In this case we can continue and must not call CoUninitialize here.
-        false
-    } else {
-        return Err(format!("Failed to initialize COM: {com_init_result}"));
-    };
-
-    let result = (|| -> Result<(), String> {
+    with_com_initialized(|| {
         let shell_link: IShellLinkW =
             unsafe { CoCreateInstance(&ShellLink, None, CLSCTX_INPROC_SERVER) }
                 .map_err(|e| format!("Failed to create shell link: {e}"))?;
@@ -422,7 +773,26 @@ fn create_shortcut_with_shell_link(
                 .map_err(|e| format!("Failed to save shortcut file: {e}"))?;
         }
         Ok(())
-    })();
+    })
+}
+
+#[cfg(windows)]
+fn with_com_initialized<T>(f: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    use windows::Win32::Foundation::RPC_E_CHANGED_MODE;
+    use windows::Win32::System::Com::{CoInitializeEx, CoUninitialize, COINIT_MULTITHREADED};
+
+    let com_init_result = unsafe { CoInitializeEx(None, COINIT_MULTITHREADED) };
+    let needs_uninitialize = if com_init_result.is_ok() {
+        true
+    } else if com_init_result == RPC_E_CHANGED_MODE {
+        // COM is already initialized on this thread in a different apartment model.
+        // In this case we can continue and must not call CoUninitialize here.
+        false
+    } else {
+        return Err(format!("Failed to initialize COM: {com_init_result}"));
+    };
+
+    let result = f();
 
     if needs_uninitialize {
         unsafe { CoUninitialize() };
@@ -431,6 +801,217 @@ fn create_shortcut_with_shell_link(
     result
 }
 
+#[cfg(windows)]
+fn utf16_buffer_to_string(buffer: &[u16]) -> String {
+    let len = buffer
+        .iter()
+        .position(|value| *value == 0)
+        .unwrap_or(buffer.len());
+    String::from_utf16_lossy(&buffer[..len])
+}
+
+/// .lnk ファイルからリンク先の実行ファイルと引数を読み取る。
+#[cfg(windows)]
+fn read_shortcut_target(shortcut_path: &Path) -> Result<(PathBuf, String), String> {
+    use windows::core::{Interface, PCWSTR};
+    use windows::Win32::System::Com::{
+        CoCreateInstance, IPersistFile, CLSCTX_INPROC_SERVER, STGM_READ,
+    };
+    use windows::Win32::UI::Shell::{IShellLinkW, ShellLink};
+
+    let shortcut_utf16 = path_to_utf16(shortcut_path);
+    with_com_initialized(|| {
+        let shell_link: IShellLinkW =
+            unsafe { CoCreateInstance(&ShellLink, None, CLSCTX_INPROC_SERVER) }
+                .map_err(|e| format!("Failed to create shell link: {e}"))?;
+        let persist_file: IPersistFile = shell_link
+            .cast()
+            .map_err(|e| format!("Failed to access persist file interface: {e}"))?;
+
+        let mut target = [0u16; 1024];
+        let mut arguments = [0u16; 1024];
+        // 安全性: 各バッファは呼び出し中に生存し、API へは長さ付きスライスで渡す。
+        unsafe {
+            persist_file
+                .Load(PCWSTR(shortcut_utf16.as_ptr()), STGM_READ)
+                .map_err(|e| format!("Failed to load shortcut file: {e}"))?;
+            shell_link
+                .GetPath(&mut target, std::ptr::null_mut(), 0)
+                .map_err(|e| format!("Failed to read shortcut target path: {e}"))?;
+            shell_link
+                .GetArguments(&mut arguments)
+                .map_err(|e| format!("Failed to read shortcut arguments: {e}"))?;
+        }
+
+        Ok((
+            PathBuf::from(utf16_buffer_to_string(&target)),
+            utf16_buffer_to_string(&arguments),
+        ))
+    })
+}
+
+/// 起動済みゲームのメインウィンドウが表示されるまで待機する。
+/// ウィンドウを検出できないままタイムアウトした場合は、プロセス生存で準備完了とみなす。
+pub async fn wait_for_game_ready<R: Runtime>(
+    app: AppHandle<R>,
+    timeout_ms: Option<u64>,
+) -> Result<GameReadyResult, String> {
+    let timeout = timeout_ms
+        .map(Duration::from_millis)
+        .unwrap_or(GAME_READY_DEFAULT_TIMEOUT)
+        .min(GAME_READY_MAX_TIMEOUT);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let started = std::time::Instant::now();
+        let _ = app.emit(
+            "game-state-changed",
+            GameStatePayload {
+                running: true,
+                phase: Some(GameLaunchPhase::Launching),
+            },
+        );
+
+        loop {
+            let elapsed_ms = started.elapsed().as_millis() as u64;
+            if !is_game_running(app.clone())? {
+                // 起動途中で終了した場合は準備完了を通知しない。
+                return Ok(GameReadyResult {
+                    ready: false,
+                    window_detected: false,
+                    elapsed_ms,
+                });
+            }
+
+            let window_detected = is_game_window_visible();
+            if window_detected || started.elapsed() >= timeout {
+                let _ = app.emit(
+                    "game-state-changed",
+                    GameStatePayload {
+                        running: true,
+                        phase: Some(GameLaunchPhase::Ready),
+                    },
+                );
+                return Ok(GameReadyResult {
+                    ready: true,
+                    window_detected,
+                    elapsed_ms,
+                });
+            }
+
+            std::thread::sleep(GAME_READY_POLL_INTERVAL);
+        }
+    })
+    .await
+    .map_err(|e| format!("Game ready wait task failed: {e}"))?
+}
+
+#[cfg_attr(not(windows), allow(dead_code))]
+fn build_startup_command(launcher_exe: &Path, autolaunch_modded: bool) -> String {
+    let mut command = format!("\"{}\"", launcher_exe.display());
+    if autolaunch_modded {
+        command.push(' ');
+        command.push_str(AUTOLAUNCH_MODDED_ARGUMENT);
+    }
+    command
+}
+
+/// Runキーの登録値を実行ファイルパスと Modded 自動起動指定の有無へ分解する。
+#[cfg_attr(not(windows), allow(dead_code))]
+fn parse_startup_command(raw: &str) -> (PathBuf, bool) {
+    let raw = raw.trim();
+    let (exe, rest) = match raw.strip_prefix('"') {
+        Some(quoted) => match quoted.split_once('"') {
+            Some((exe, rest)) => (exe, rest),
+            None => (quoted, ""),
+        },
+        None => raw.split_once(' ').unwrap_or((raw, "")),
+    };
+    let autolaunch_modded = rest
+        .split_whitespace()
+        .any(|arg| arg == AUTOLAUNCH_MODDED_ARGUMENT);
+    (PathBuf::from(exe), autolaunch_modded)
+}
+
+#[cfg(windows)]
+fn startup_value_name() -> &'static str {
+    mod_profile::get().branding.identifier.as_str()
+}
+
+/// Windowsログオン時の自動起動登録状態を返す。
+pub fn startup_autolaunch_status() -> Result<StartupAutolaunchStatus, String> {
+    #[cfg(windows)]
+    {
+        use winreg::{enums::HKEY_CURRENT_USER, RegKey};
+
+        let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+        let Ok(run_key) = hkcu.open_subkey(STARTUP_RUN_KEY_PATH) else {
+            return Ok(StartupAutolaunchStatus::default());
+        };
+        let Ok(raw) = run_key.get_value::<String, _>(startup_value_name()) else {
+            return Ok(StartupAutolaunchStatus::default());
+        };
+
+        let (registered_exe, autolaunch_modded) = parse_startup_command(&raw);
+        let current_exe = std::env::current_exe()
+            .map_err(|e| format!("Failed to resolve launcher executable path: {e}"))?;
+        let path_matches = registered_exe
+            .to_string_lossy()
+            .eq_ignore_ascii_case(&current_exe.to_string_lossy());
+        Ok(StartupAutolaunchStatus {
+            enabled: true,
+            autolaunch_modded,
+            path_matches,
+        })
+    }
+
+    #[cfg(not(windows))]
+    {
+        Ok(StartupAutolaunchStatus::default())
+    }
+}
+
+/// Windowsログオン時の自動起動を登録または解除する。
+pub fn set_startup_autolaunch(
+    enable: bool,
+    autolaunch_modded: bool,
+) -> Result<StartupAutolaunchStatus, String> {
+    #[cfg(windows)]
+    {
+        use winreg::{enums::HKEY_CURRENT_USER, RegKey};
+
+        let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+        let (run_key, _) = hkcu
+            .create_subkey(STARTUP_RUN_KEY_PATH)
+            .map_err(|e| format!("Failed to open startup registry key: {e}"))?;
+
+        if enable {
+            let launcher_exe = std::env::current_exe()
+                .map_err(|e| format!("Failed to resolve launcher executable path: {e}"))?;
+            // 移動・更新で古いパスが残らないよう、有効化のたびに現在のパスで書き直す。
+            run_key
+                .set_value(
+                    startup_value_name(),
+                    &build_startup_command(&launcher_exe, autolaunch_modded),
+                )
+                .map_err(|e| format!("Failed to register startup entry: {e}"))?;
+        } else {
+            match run_key.delete_value(startup_value_name()) {
+                Ok(()) => {}
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+                Err(error) => return Err(format!("Failed to remove startup entry: {error}")),
+            }
+        }
+
+        startup_autolaunch_status()
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = (enable, autolaunch_modded);
+        Err("Startup registration is only supported on Windows".to_string())
+    }
+}
+
 pub fn create_modded_launch_shortcut() -> Result<String, String> {
     #[cfg(windows)]
     {
@@ -440,12 +1021,7 @@ pub fn create_modded_launch_shortcut() -> Result<String, String> {
             .parent()
             .ok_or_else(|| "Launcher executable directory is invalid".to_string())?;
 
-        let desktop_dir = std::env::var_os("USERPROFILE")
-            .map(PathBuf::from)
-            .map(|path| path.join("Desktop"))
-            .ok_or_else(|| {
-                "Failed to resolve desktop directory: USERPROFILE is not set".to_string()
-            })?;
+        let desktop_dir = desktop_dir()?;
         fs::create_dir_all(&desktop_dir)
             .map_err(|e| format!("Failed to create desktop directory: {e}"))?;
 
@@ -469,11 +1045,99 @@ pub fn create_modded_launch_shortcut() -> Result<String, String> {
     }
 }
 
+#[cfg(windows)]
+fn desktop_dir() -> Result<PathBuf, String> {
+    std::env::var_os("USERPROFILE")
+        .map(PathBuf::from)
+        .map(|path| path.join("Desktop"))
+        .ok_or_else(|| "Failed to resolve desktop directory: USERPROFILE is not set".to_string())
+}
+
+// 連番の付かない元の名前を先頭にし、以降は作成順（連番順）に並べる。
+#[cfg_attr(not(windows), allow(dead_code))]
+fn sort_shortcuts_by_creation_order(paths: &mut [PathBuf]) {
+    paths.sort_by_key(|path| {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        (name.len(), name)
+    });
+}
+
+/// デスクトップ上の、このランチャーのMod起動ショートカットを作成順に列挙する。
+/// 名前ではなく .lnk のリンク先と引数で判定する。
+pub fn list_modded_launch_shortcuts() -> Result<Vec<String>, String> {
+    #[cfg(windows)]
+    {
+        let launcher_exe = std::env::current_exe()
+            .map_err(|e| format!("Failed to resolve launcher executable path: {e}"))?;
+        let desktop_dir = desktop_dir()?;
+        let Ok(entries) = fs::read_dir(&desktop_dir) else {
+            return Ok(Vec::new());
+        };
+
+        let mut shortcuts = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("lnk"))
+            })
+            .filter(|path| match read_shortcut_target(path) {
+                Ok((target, arguments)) => {
+                    target
+                        .to_string_lossy()
+                        .eq_ignore_ascii_case(&launcher_exe.to_string_lossy())
+                        && arguments.trim() == AUTOLAUNCH_MODDED_ARGUMENT
+                }
+                Err(error) => {
+                    log::debug!("Skipping unreadable shortcut '{}': {error}", path.display());
+                    false
+                }
+            })
+            .collect::<Vec<_>>();
+        sort_shortcuts_by_creation_order(&mut shortcuts);
+
+        Ok(shortcuts
+            .into_iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect())
+    }
+
+    #[cfg(not(windows))]
+    {
+        Err("Shortcut management is only supported on Windows".to_string())
+    }
+}
+
+/// 重複したMod起動ショートカットを削除し、最初に作成された1つだけを残す。
+pub fn cleanup_modded_launch_shortcuts() -> Result<ShortcutCleanupResult, String> {
+    let mut shortcuts = list_modded_launch_shortcuts()?.into_iter();
+    let kept = shortcuts.next();
+    let mut removed = Vec::new();
+    for path in shortcuts {
+        match fs::remove_file(&path) {
+            Ok(()) => removed.push(path),
+            Err(error) => log::warn!("Failed to remove duplicate shortcut '{path}': {error}"),
+        }
+    }
+
+    Ok(ShortcutCleanupResult { kept, removed })
+}
+
 fn monitor_game_process<R: Runtime>(app: AppHandle<R>) {
     std::thread::spawn(move || {
         // 起動直後に running=true を通知してUI表示を同期する。
-        let _ = app.emit("game-state-changed", GameStatePayload { running: true });
-
+        let _ = app.emit(
+            "game-state-changed",
+            GameStatePayload {
+                running: true,
+                phase: None,
+            },
+        );
+
+        let mut exited = false;
         loop {
             std::thread::sleep(Duration::from_millis(500));
 
@@ -481,18 +1145,35 @@ fn monitor_game_process<R: Runtime>(app: AppHandle<R>) {
                 break;
             };
 
-            match guard.as_mut().and_then(|process| process.try_wait().ok()) {
-                Some(Some(_)) | None => {
-                    // 終了検知または追跡不能時は監視対象を解除する。
+            match guard.as_mut().map(|process| process.try_wait()) {
+                Some(Ok(None)) => {}
+                Some(Ok(Some(_))) | None => {
+                    *guard = None;
+                    exited = true;
+                    break;
+                }
+                Some(Err(error)) => {
+                    // 子プロセスを追跡できなくなった場合は監視対象を解除し、PIDファイルでの判定へ切り替える。
+                    log::warn!("Failed to inspect game process state: {error}");
                     *guard = None;
                     break;
                 }
-                Some(None) => {}
             }
         }
 
+        // is_game_running と同じ PID ファイル判定で、実際に終了するまで running=false を通知しない。
+        while !exited && is_game_running(app.clone()).unwrap_or(false) {
+            std::thread::sleep(Duration::from_millis(500));
+        }
+
         clear_persisted_running_game_pid(&app);
-        let _ = app.emit("game-state-changed", GameStatePayload { running: false });
+        let _ = app.emit(
+            "game-state-changed",
+            GameStatePayload {
+                running: false,
+                phase: None,
+            },
+        );
     });
 }
 
@@ -621,7 +1302,7 @@ fn cleanup_elevated_launch_files(paths: &[&Path]) {
     for path in paths {
         if let Err(error) = fs::remove_file(path) {
             if error.kind() != std::io::ErrorKind::NotFound {
-                eprintln!("Failed to remove elevated launch temporary file: {error}");
+                log::warn!("Failed to remove elevated launch temporary file: {error}");
             }
         }
     }
@@ -842,6 +1523,145 @@ fn ensure_file_exists(path: &Path, label: &str) -> Result<(), String> {
     }
 }
 
+/// Doorstop に渡す起動対象アセンブリと .NET ランタイムの場所。
+struct DoorstopTargets {
+    target_assembly: PathBuf,
+    corlib_dir: PathBuf,
+    coreclr_path: PathBuf,
+}
+
+// 上書き値が相対パスならプロファイル基準で解決する。
+fn resolve_profile_relative_path(profile_path: &Path, value: &str) -> PathBuf {
+    let path = Path::new(value);
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        profile_path.join(path)
+    }
+}
+
+/// 既定の BepInEx IL2CPP 構成に、プロファイルごとの上書き設定を反映する。
+fn modded_doorstop_targets(profile_path: &Path) -> DoorstopTargets {
+    let overrides = settings::load_profile_settings_overrides(profile_path).unwrap_or_default();
+    let resolve = |value: Option<String>, default: PathBuf| {
+        value
+            .map(|value| resolve_profile_relative_path(profile_path, &value))
+            .unwrap_or(default)
+    };
+    let corlib_dir = resolve(overrides.doorstop_corlib_dir, profile_path.join("dotnet"));
+    DoorstopTargets {
+        target_assembly: resolve(
+            overrides.doorstop_target_assembly,
+            profile_path
+                .join("BepInEx")
+                .join("core")
+                .join("BepInEx.Unity.IL2CPP.dll"),
+        ),
+        coreclr_path: resolve(
+            overrides.doorstop_coreclr_path,
+            corlib_dir.join("coreclr.dll"),
+        ),
+        corlib_dir,
+    }
+}
+
+fn ensure_doorstop_targets(targets: &DoorstopTargets) -> Result<(), String> {
+    ensure_file_exists(&targets.target_assembly, "BepInEx IL2CPP DLL")?;
+    if !targets.corlib_dir.is_dir() {
+        return Err(format!(
+            "dotnet corlib directory not found: {}",
+            targets.corlib_dir.to_string_lossy()
+        ));
+    }
+    ensure_file_exists(&targets.coreclr_path, "dotnet coreclr")
+}
+
+/// Mod起動に必要なBepInEx/dotnetランタイムがプロファイルに揃っているか検証する。
+pub fn ensure_modded_launch_files(profile_path: &Path) -> Result<(), String> {
+    ensure_doorstop_targets(&modded_doorstop_targets(profile_path))
+}
+
+fn has_non_empty_interop(root: &Path) -> bool {
+    fs::read_dir(root.join("BepInEx").join("interop"))
+        .map(|mut entries| entries.any(|entry| entry.is_ok()))
+        .unwrap_or(false)
+}
+
+fn profile_component_check(
+    id: &str,
+    path: &Path,
+    present: bool,
+    required: bool,
+) -> ProfileComponentCheck {
+    ProfileComponentCheck {
+        id: id.to_string(),
+        path: path.to_string_lossy().to_string(),
+        present,
+        required,
+    }
+}
+
+/// 指定フォルダにModded起動に必要な構成要素が揃っているかを項目ごとに調べる。
+/// 起動時に個別に行っている存在確認を、UIから確認できる形でまとめて返す。
+pub fn validate_profile(path: &str) -> Result<ProfileValidationReport, String> {
+    let path = path.trim();
+    if path.is_empty() {
+        return Err("Profile path is required".to_string());
+    }
+    let profile_path = Path::new(path);
+    if !profile_path.is_dir() {
+        return Err(format!(
+            "Profile directory not found: {}",
+            profile_path.to_string_lossy()
+        ));
+    }
+
+    let targets = modded_doorstop_targets(profile_path);
+    let mut components = vec![
+        profile_component_check(
+            "bepinexCore",
+            &targets.target_assembly,
+            targets.target_assembly.is_file(),
+            true,
+        ),
+        profile_component_check(
+            "dotnetCorlib",
+            &targets.corlib_dir,
+            targets.corlib_dir.is_dir(),
+            true,
+        ),
+        profile_component_check(
+            "dotnetCoreclr",
+            &targets.coreclr_path,
+            targets.coreclr_path.is_file(),
+            true,
+        ),
+    ];
+    components.extend(
+        settings::profile_required_file_paths(profile_path)
+            .into_iter()
+            .map(|file_path| {
+                profile_component_check("requiredFile", &file_path, file_path.is_file(), true)
+            }),
+    );
+    let interop_dir = profile_path.join("BepInEx").join("interop");
+    components.push(profile_component_check(
+        "interop",
+        &interop_dir,
+        has_non_empty_interop(profile_path),
+        false,
+    ));
+
+    let valid = components
+        .iter()
+        .all(|component| component.present || !component.required);
+    Ok(ProfileValidationReport {
+        path: profile_path.to_string_lossy().to_string(),
+        valid,
+        components,
+    })
+}
+
 fn ensure_valid_among_us_launch_target(game_exe_path: &Path) -> Result<&Path, String> {
     let game_dir = game_exe_path
         .parent()
@@ -901,22 +1721,239 @@ fn ensure_steam_appid_file_if_needed(game_dir: &Path, platform: &str) -> Result<
     Ok(())
 }
 
-pub fn modded_first_setup_pending<R: Runtime>(
-    app: &AppHandle<R>,
-    game_exe: String,
-) -> Result<bool, String> {
-    let has_non_empty_interop = |root: &Path| -> bool {
-        let interop_dir = root.join("BepInEx").join("interop");
-        if !interop_dir.is_dir() {
-            return false;
+fn check_steam_appid_writable(game_dir: &Path) -> Result<(), String> {
+    let steam_appid_path = game_dir.join(STEAM_APP_ID_FILE_NAME);
+    if steam_appid_path.exists() {
+        if steam_appid_path.is_file() {
+            return Ok(());
         }
+        return Err(format!(
+            "steam_appid path is not a file: {}",
+            steam_appid_path.to_string_lossy()
+        ));
+    }
+
+    // 実ファイルは作らず、一時ファイルで書き込み可否だけを確認する。
+    let probe_path = game_dir.join(format!(".{STEAM_APP_ID_FILE_NAME}.probe"));
+    fs::write(&probe_path, STEAM_APP_ID_VALUE).map_err(|error| {
+        format!(
+            "Cannot create steam_appid.txt in {}: {error}",
+            game_dir.to_string_lossy()
+        )
+    })?;
+    let _ = fs::remove_file(&probe_path);
+    Ok(())
+}
+
+fn preflight_check(id: &str, result: Result<String, String>) -> PreflightCheck {
+    match result {
+        Ok(message) => PreflightCheck {
+            id: id.to_string(),
+            status: PreflightStatus::Pass,
+            message,
+        },
+        Err(message) => PreflightCheck {
+            id: id.to_string(),
+            status: PreflightStatus::Fail,
+            message,
+        },
+    }
+}
+
+/// ゲームを起動せずに、保存済みセッションでEpic起動用トークンを発行できるか確認する。
+pub async fn epic_launch_precheck() -> Result<EpicLaunchPrecheckResult, String> {
+    let Some(session) = epic_api::load_session() else {
+        return Ok(EpicLaunchPrecheckResult {
+            status: EpicLaunchPrecheckStatus::NeedsRelogin,
+            message: None,
+        });
+    };
+
+    // 発行したトークンは使い捨てのため、確認後はそのまま破棄する。
+    let result = match EpicApi::new()?.request_game_token(&session).await {
+        Ok(_) => EpicLaunchPrecheckResult {
+            status: EpicLaunchPrecheckStatus::Ok,
+            message: None,
+        },
+        Err(epic_api::GameTokenError::Rejected(message)) => EpicLaunchPrecheckResult {
+            status: EpicLaunchPrecheckStatus::NeedsRelogin,
+            message: Some(message),
+        },
+        Err(epic_api::GameTokenError::Network(message)) => EpicLaunchPrecheckResult {
+            status: EpicLaunchPrecheckStatus::NetworkError,
+            message: Some(message),
+        },
+    };
+    Ok(result)
+}
+
+async fn check_epic_session_for_preflight() -> PreflightCheck {
+    if !mod_profile::feature_enabled(mod_profile::Feature::EpicLogin) {
+        return preflight_check(
+            "epicSession",
+            Err("Epic launch is disabled by mod.config.json.".to_string()),
+        );
+    }
 
-        match fs::read_dir(&interop_dir) {
-            Ok(entries) => entries.filter_map(Result::ok).next().is_some(),
-            Err(_) => false,
+    let precheck = match epic_launch_precheck().await {
+        Ok(precheck) => precheck,
+        Err(error) => {
+            return preflight_check(
+                "epicSession",
+                Err(format!("Failed to initialize Epic authentication: {error}")),
+            )
+        }
+    };
+    match precheck.status {
+        EpicLaunchPrecheckStatus::Ok => {
+            preflight_check("epicSession", Ok("Epic session is valid.".to_string()))
         }
+        EpicLaunchPrecheckStatus::NeedsRelogin => preflight_check(
+            "epicSession",
+            Err(precheck
+                .message
+                .unwrap_or_else(|| "Epic launch requires Epic authentication.".to_string())),
+        ),
+        // 通信障害は再ログインで解決しないため、失敗ではなく警告として扱う。
+        EpicLaunchPrecheckStatus::NetworkError => PreflightCheck {
+            id: "epicSession".to_string(),
+            status: PreflightStatus::Warn,
+            message: precheck.message.unwrap_or_default(),
+        },
+    }
+}
+
+fn summarize_preflight(checks: Vec<PreflightCheck>) -> LaunchPreflightReport {
+    let ready = checks
+        .iter()
+        .all(|check| check.status != PreflightStatus::Fail);
+    LaunchPreflightReport { ready, checks }
+}
+
+/// 保存済み設定に対して起動前チェックをまとめて実行する。
+pub async fn launch_preflight<R: Runtime>(
+    app: AppHandle<R>,
+    modded: bool,
+) -> Result<LaunchPreflightReport, String> {
+    let launcher_settings = settings::load_or_init_settings(&app)?;
+    let platform = launcher_settings.game_platform.as_str();
+    let mut checks = Vec::new();
+
+    let among_us_path = launcher_settings.among_us_path.trim();
+    let game_exe_path = PathBuf::from(among_us_path).join(among_us_exe_file_name());
+    let game_dir = if among_us_path.is_empty() {
+        checks.push(preflight_check(
+            "gamePath",
+            Err("Among Us path is not configured".to_string()),
+        ));
+        None
+    } else {
+        let result = ensure_valid_among_us_launch_target(&game_exe_path);
+        let game_dir = result.as_ref().ok().map(|dir| dir.to_path_buf());
+        checks.push(preflight_check(
+            "gamePath",
+            result.map(|dir| format!("Among Us found: {}", dir.to_string_lossy())),
+        ));
+        game_dir
     };
 
+    if modded {
+        let profile_path = launcher_settings.profile_path.trim();
+        let result = if profile_path.is_empty() {
+            Err("Profile path is not configured".to_string())
+        } else {
+            ensure_modded_launch_files(Path::new(profile_path))
+                .map(|_| "Required profile files are present.".to_string())
+        };
+        checks.push(preflight_check("profileFiles", result));
+
+        // リアルタイム保護はDLLの隔離で起動失敗を招きやすいため、除外設定を促す警告として示す。
+        let av = tauri::async_runtime::spawn_blocking(antivirus::cached_av_status)
+            .await
+            .ok();
+        if let Some(av) = av.filter(|av| av.queryable) {
+            checks.push(match av.active_product {
+                Some(product) => PreflightCheck {
+                    id: "antivirus".to_string(),
+                    status: PreflightStatus::Warn,
+                    message: format!(
+                        "Real-time protection is active ({product}). Add the profile folder to its exclusions if mod files are blocked: {profile_path}"
+                    ),
+                },
+                None => preflight_check(
+                    "antivirus",
+                    Ok("No real-time antivirus protection was detected.".to_string()),
+                ),
+            });
+        }
+    }
+
+    if !modded {
+        if let Some(game_dir) = &game_dir {
+            let artifacts = find_doorstop_artifacts(game_dir);
+            checks.push(if artifacts.is_empty() {
+                preflight_check(
+                    "doorstopArtifacts",
+                    Ok("No leftover Doorstop files were found.".to_string()),
+                )
+            } else {
+                PreflightCheck {
+                    id: "doorstopArtifacts".to_string(),
+                    status: PreflightStatus::Warn,
+                    message: format!(
+                        "Leftover Doorstop files may load mods in vanilla: {}",
+                        artifacts
+                            .iter()
+                            .map(|path| path.to_string_lossy().to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                }
+            });
+        }
+    }
+
+    if platform == settings::GamePlatform::Steam.as_str() {
+        if let Some(game_dir) = &game_dir {
+            checks.push(preflight_check(
+                "steamAppId",
+                check_steam_appid_writable(game_dir)
+                    .map(|_| "steam_appid.txt is writable.".to_string()),
+            ));
+        }
+    } else {
+        checks.push(check_epic_session_for_preflight().await);
+    }
+
+    let untracked_processes = list_game_processes(&app)
+        .into_iter()
+        .filter(|process| !process.tracked)
+        .count();
+    checks.push(match is_game_running(app) {
+        Ok(true) => preflight_check("gameNotRunning", Err("Game is already running".to_string())),
+        // ランチャー外で起動したゲームは起動自体を妨げないが、二重起動になるため警告する。
+        Ok(false) if untracked_processes > 0 => PreflightCheck {
+            id: "gameNotRunning".to_string(),
+            status: PreflightStatus::Warn,
+            message: format!(
+                "Among Us is already running outside the launcher ({untracked_processes} process(es))."
+            ),
+        },
+        Ok(false) => preflight_check("gameNotRunning", Ok("Game is not running.".to_string())),
+        Err(message) => PreflightCheck {
+            id: "gameNotRunning".to_string(),
+            status: PreflightStatus::Warn,
+            message,
+        },
+    });
+
+    Ok(summarize_preflight(checks))
+}
+
+pub fn modded_first_setup_pending<R: Runtime>(
+    app: &AppHandle<R>,
+    game_exe: String,
+) -> Result<bool, String> {
     let game_exe_path = PathBuf::from(game_exe);
     let game_dir = ensure_valid_among_us_launch_target(&game_exe_path)?;
     // ゲーム側にinterop生成済みなら、BepInEx初回展開は完了済みとみなす。
@@ -933,6 +1970,90 @@ pub fn modded_first_setup_pending<R: Runtime>(
     Ok(!has_non_empty_interop(Path::new(profile_path)))
 }
 
+fn classify_interop_generation(
+    file_count: u64,
+    since_last_change: Option<Duration>,
+    startup_complete: bool,
+) -> InteropGenerationState {
+    if file_count == 0 {
+        return InteropGenerationState::NotStarted;
+    }
+    if startup_complete {
+        return InteropGenerationState::Complete;
+    }
+    match since_last_change {
+        Some(elapsed) if elapsed >= INTEROP_STALL_THRESHOLD => InteropGenerationState::Stalled,
+        _ => InteropGenerationState::InProgress,
+    }
+}
+
+/// interopディレクトリ直下のファイル数と最終更新時刻を集計する。
+fn collect_interop_activity(root: &Path) -> (u64, Option<SystemTime>) {
+    let Ok(entries) = fs::read_dir(root.join("BepInEx").join("interop")) else {
+        return (0, None);
+    };
+
+    let mut file_count = 0;
+    let mut newest_modified = None;
+    for entry in entries.filter_map(Result::ok) {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+        file_count += 1;
+        if let Ok(modified) = metadata.modified() {
+            newest_modified = newest_modified.max(Some(modified));
+        }
+    }
+    (file_count, newest_modified)
+}
+
+fn bepinex_startup_completed(root: &Path) -> bool {
+    fs::read_to_string(root.join("BepInEx").join(BEPINEX_LOG_FILE_NAME))
+        .map(|log| log.contains(BEPINEX_STARTUP_COMPLETE_MARKER))
+        .unwrap_or(false)
+}
+
+/// 初回Modded起動中のinterop生成が進行中か停滞しているかを判定する。
+pub fn interop_generation_status<R: Runtime>(
+    app: &AppHandle<R>,
+    game_exe: String,
+) -> Result<InteropGenerationStatus, String> {
+    let game_exe_path = PathBuf::from(game_exe);
+    let game_dir = ensure_valid_among_us_launch_target(&game_exe_path)?;
+    let launcher_settings = settings::load_or_init_settings(app)?;
+    let profile_path = launcher_settings.profile_path.trim();
+
+    // interopはゲーム側・プロファイル側のどちらにも生成され得るため両方を合算する。
+    let mut roots = vec![game_dir.to_path_buf()];
+    if !profile_path.is_empty() {
+        roots.push(PathBuf::from(profile_path));
+    }
+
+    let mut file_count = 0;
+    let mut newest_modified = None;
+    let mut startup_complete = false;
+    for root in &roots {
+        let (count, modified) = collect_interop_activity(root);
+        file_count += count;
+        newest_modified = newest_modified.max(modified);
+        startup_complete |= bepinex_startup_completed(root);
+    }
+
+    let since_last_change = newest_modified.map(|modified| {
+        SystemTime::now()
+            .duration_since(modified)
+            .unwrap_or_default()
+    });
+    Ok(InteropGenerationStatus {
+        state: classify_interop_generation(file_count, since_last_change, startup_complete),
+        file_count,
+        seconds_since_last_change: since_last_change.map(|elapsed| elapsed.as_secs()),
+    })
+}
+
 async fn add_epic_auth_argument_if_needed(
     command: &mut Command,
     platform: &str,
@@ -961,22 +2082,33 @@ async fn add_epic_auth_argument_if_needed(
     Ok(())
 }
 
+fn saved_game_exe_path(launcher_settings: &settings::LauncherSettings) -> Result<PathBuf, String> {
+    let among_us_path = launcher_settings.among_us_path.trim();
+    if among_us_path.is_empty() {
+        return Err("Among Us path is not configured".to_string());
+    }
+    Ok(PathBuf::from(among_us_path).join(among_us_exe_file_name()))
+}
+
+/// 保存済み設定のAmong Usで、次回Modded起動時にBepInExの初回セットアップが走るかを返す。
+pub fn saved_modded_first_setup_pending<R: Runtime>(app: &AppHandle<R>) -> Result<bool, String> {
+    let launcher_settings = settings::load_or_init_settings(app)?;
+    let game_exe_path = saved_game_exe_path(&launcher_settings)?;
+    modded_first_setup_pending(app, game_exe_path.to_string_lossy().to_string())
+}
+
 pub async fn launch_modded_from_saved_settings<R: Runtime>(
     app: AppHandle<R>,
 ) -> Result<(), String> {
     // 設定保存済みのパス情報を使って再入力なしで起動する。
     let launcher_settings = settings::load_or_init_settings(&app)?;
-    let among_us_path = launcher_settings.among_us_path.trim();
-    if among_us_path.is_empty() {
-        return Err("Among Us path is not configured".to_string());
-    }
+    let game_exe_path = saved_game_exe_path(&launcher_settings)?;
 
     let profile_path = launcher_settings.profile_path.trim();
     if profile_path.is_empty() {
         return Err("Profile path is not configured".to_string());
     }
 
-    let game_exe_path = PathBuf::from(among_us_path).join(among_us_exe_file_name());
     launch_modded(
         app,
         game_exe_path.to_string_lossy().to_string(),
@@ -996,24 +2128,17 @@ pub async fn launch_modded<R: Runtime>(
     let game_dir = ensure_valid_among_us_launch_target(&game_exe_path)?;
 
     let profile_path = PathBuf::from(&profile_path);
-    let bepinex_dll = profile_path
-        .join("BepInEx")
-        .join("core")
-        .join("BepInEx.Unity.IL2CPP.dll");
-    let dotnet_dir = profile_path.join("dotnet");
-    let coreclr_path = dotnet_dir.join("coreclr.dll");
-
-    ensure_file_exists(&bepinex_dll, "BepInEx IL2CPP DLL")?;
-    ensure_file_exists(&coreclr_path, "dotnet coreclr")?;
+    let targets = modded_doorstop_targets(&profile_path);
+    ensure_doorstop_targets(&targets)?;
 
     ensure_steam_appid_file_if_needed(game_dir, &platform)?;
 
     #[cfg(windows)]
     set_dll_directory(&profile_path.to_string_lossy())?;
 
-    let bepinex_dll_str = bepinex_dll.to_string_lossy().to_string();
-    let dotnet_dir_str = dotnet_dir.to_string_lossy().to_string();
-    let coreclr_path_str = coreclr_path.to_string_lossy().to_string();
+    let bepinex_dll_str = targets.target_assembly.to_string_lossy().to_string();
+    let dotnet_dir_str = targets.corlib_dir.to_string_lossy().to_string();
+    let coreclr_path_str = targets.coreclr_path.to_string_lossy().to_string();
 
     let mut command = Command::new(&game_exe_path);
     // Doorstop関連引数を付与してBepInEx経由で起動する。
@@ -1029,6 +2154,47 @@ pub async fn launch_modded<R: Runtime>(
     launch_process(app, command)
 }
 
+/// ゲームフォルダ直下に残っているDoorstop関連ファイルを列挙する。
+pub fn find_doorstop_artifacts(game_dir: &Path) -> Vec<PathBuf> {
+    DOORSTOP_ARTIFACT_NAMES
+        .iter()
+        .map(|name| game_dir.join(name))
+        .filter(|path| path.is_file())
+        .collect()
+}
+
+/// 指定ゲームのDoorstop関連ファイルを返す。
+pub fn doorstop_artifacts(game_exe: String) -> Result<Vec<String>, String> {
+    let game_exe_path = PathBuf::from(game_exe);
+    let game_dir = ensure_valid_among_us_launch_target(&game_exe_path)?;
+    Ok(find_doorstop_artifacts(game_dir)
+        .iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect())
+}
+
+/// Doorstop関連ファイルを `.disabled` 付きへ改名して無効化し、改名後のパスを返す。
+pub fn disable_doorstop_artifacts(game_exe: String) -> Result<Vec<String>, String> {
+    let game_exe_path = PathBuf::from(game_exe);
+    let game_dir = ensure_valid_among_us_launch_target(&game_exe_path)?;
+
+    let mut disabled = Vec::new();
+    for artifact in find_doorstop_artifacts(game_dir) {
+        let mut disabled_name = artifact.as_os_str().to_os_string();
+        disabled_name.push(DOORSTOP_DISABLED_SUFFIX);
+        let disabled_path = PathBuf::from(disabled_name);
+        // 削除せず改名に留め、手動導入を戻したい利用者が復元できるようにする。
+        fs::rename(&artifact, &disabled_path).map_err(|e| {
+            format!(
+                "Failed to disable Doorstop file '{}': {e}",
+                artifact.display()
+            )
+        })?;
+        disabled.push(disabled_path.to_string_lossy().to_string());
+    }
+    Ok(disabled)
+}
+
 pub async fn launch_vanilla<R: Runtime>(
     app: AppHandle<R>,
     game_exe: String,
@@ -1069,6 +2235,160 @@ mod tests {
         dir.join(file_name)
     }
 
+    #[test]
+    fn validate_profile_reports_missing_components_until_runtime_exists() {
+        let profile = temp_test_file_path("profile");
+        fs::create_dir_all(&profile).expect("failed to create profile directory");
+        let report = validate_profile(&profile.to_string_lossy()).expect("validation report");
+        assert!(!report.valid);
+        assert!(report
+            .components
+            .iter()
+            .any(|component| component.id == "bepinexCore" && !component.present));
+
+        let targets = modded_doorstop_targets(&profile);
+        let mut files = vec![
+            targets.target_assembly.clone(),
+            targets.coreclr_path.clone(),
+        ];
+        files.extend(settings::profile_required_file_paths(&profile));
+        for file in files {
+            fs::create_dir_all(file.parent().expect("file parent")).expect("create parent");
+            fs::write(&file, b"").expect("write component file");
+        }
+
+        let report = validate_profile(&profile.to_string_lossy()).expect("validation report");
+        assert!(report.valid);
+        let interop = report
+            .components
+            .iter()
+            .find(|component| component.id == "interop")
+            .expect("interop component");
+        assert!(!interop.present && !interop.required);
+
+        assert!(validate_profile(&profile.join("missing").to_string_lossy()).is_err());
+        let _ = fs::remove_dir_all(profile.parent().expect("temp dir"));
+    }
+
+    #[test]
+    fn sort_shortcuts_by_creation_order_keeps_unnumbered_first() {
+        let mut paths = vec![
+            PathBuf::from("Launch (10).lnk"),
+            PathBuf::from("Launch (2).lnk"),
+            PathBuf::from("Launch.lnk"),
+            PathBuf::from("Launch (3).lnk"),
+        ];
+        sort_shortcuts_by_creation_order(&mut paths);
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("Launch.lnk"),
+                PathBuf::from("Launch (2).lnk"),
+                PathBuf::from("Launch (3).lnk"),
+                PathBuf::from("Launch (10).lnk"),
+            ]
+        );
+    }
+
+    #[test]
+    fn filetime_to_unix_ms_converts_from_windows_epoch() {
+        assert_eq!(filetime_to_unix_ms(116_444_736_000_000_000), Some(0));
+        assert_eq!(
+            filetime_to_unix_ms(133_000_000_000_000_000),
+            Some(1_655_526_400_000)
+        );
+        assert_eq!(filetime_to_unix_ms(0), None);
+    }
+
+    #[test]
+    fn startup_command_round_trips_autolaunch_flag() {
+        let exe = Path::new(r"C:\Program Files\Launcher\launcher.exe");
+        let command = build_startup_command(exe, true);
+        assert_eq!(parse_startup_command(&command), (exe.to_path_buf(), true));
+
+        let command = build_startup_command(exe, false);
+        assert_eq!(parse_startup_command(&command), (exe.to_path_buf(), false));
+        assert_eq!(
+            parse_startup_command(r"C:\launcher.exe --autolaunch-modded"),
+            (PathBuf::from(r"C:\launcher.exe"), true)
+        );
+    }
+
+    #[test]
+    fn doorstop_artifacts_are_detected_in_game_dir() {
+        let game_dir = temp_test_file_path("Among Us.exe")
+            .parent()
+            .expect("temp file should have a parent")
+            .to_path_buf();
+        assert!(find_doorstop_artifacts(&game_dir).is_empty());
+
+        fs::write(game_dir.join("winhttp.dll"), b"dll").expect("failed to write winhttp.dll");
+        fs::write(game_dir.join("doorstop_config.ini"), b"[General]")
+            .expect("failed to write doorstop_config.ini");
+        let artifacts = find_doorstop_artifacts(&game_dir);
+        assert_eq!(artifacts.len(), 2);
+        assert!(artifacts.contains(&game_dir.join("winhttp.dll")));
+
+        let _ = fs::remove_dir_all(&game_dir);
+    }
+
+    #[test]
+    fn interop_generation_is_classified_by_recent_activity() {
+        assert_eq!(
+            classify_interop_generation(0, None, false),
+            InteropGenerationState::NotStarted
+        );
+        assert_eq!(
+            classify_interop_generation(12, Some(Duration::from_secs(5)), false),
+            InteropGenerationState::InProgress
+        );
+        assert_eq!(
+            classify_interop_generation(12, Some(INTEROP_STALL_THRESHOLD), false),
+            InteropGenerationState::Stalled
+        );
+        assert_eq!(
+            classify_interop_generation(12, Some(INTEROP_STALL_THRESHOLD), true),
+            InteropGenerationState::Complete
+        );
+    }
+
+    #[test]
+    fn preflight_is_ready_only_without_failures() {
+        let warn_only = summarize_preflight(vec![
+            preflight_check("gamePath", Ok("ok".to_string())),
+            PreflightCheck {
+                id: "epicSession".to_string(),
+                status: PreflightStatus::Warn,
+                message: "offline".to_string(),
+            },
+        ]);
+        assert!(warn_only.ready);
+
+        let with_failure = summarize_preflight(vec![
+            preflight_check("gamePath", Ok("ok".to_string())),
+            preflight_check("profileFiles", Err("missing".to_string())),
+        ]);
+        assert!(!with_failure.ready);
+        assert_eq!(with_failure.checks[1].status, PreflightStatus::Fail);
+    }
+
+    #[test]
+    fn steam_appid_probe_does_not_leave_files() {
+        let game_dir = temp_test_file_path("probe")
+            .parent()
+            .expect("temp dir")
+            .to_path_buf();
+
+        check_steam_appid_writable(&game_dir).expect("temp dir should be writable");
+        assert_eq!(
+            fs::read_dir(&game_dir).expect("read temp dir").count(),
+            0,
+            "probe file should be removed"
+        );
+
+        let _ = fs::remove_dir_all(&game_dir);
+    }
+
     #[test]
     fn parse_elevated_launch_payload_argument_returns_path() {
         let args = vec![
@@ -1101,6 +2421,39 @@ mod tests {
         assert!(message.starts_with(ELEVATION_REQUIRED_ERROR_PREFIX));
     }
 
+    #[test]
+    fn running_game_record_accepts_legacy_and_json_formats() {
+        assert_eq!(
+            parse_running_game_record("1234\n"),
+            Some(RunningGameRecord {
+                pid: 1234,
+                started_at: None,
+            })
+        );
+        assert_eq!(
+            parse_running_game_record(r#"{"pid":42,"startedAt":133000000000000000}"#),
+            Some(RunningGameRecord {
+                pid: 42,
+                started_at: Some(133_000_000_000_000_000),
+            })
+        );
+        assert_eq!(parse_running_game_record("not-a-pid"), None);
+    }
+
+    #[test]
+    fn stale_pid_with_different_start_time_is_not_running() {
+        let record = RunningGameRecord {
+            pid: 42,
+            started_at: Some(100),
+        };
+        assert!(is_recorded_game_running(&record, true, Some(100)));
+        // 再起動後に同じPIDが別プロセスへ再利用されたケース。
+        assert!(!is_recorded_game_running(&record, true, Some(200)));
+        assert!(!is_recorded_game_running(&record, false, Some(100)));
+        // 起動時刻を取得できない場合はPID一致のみで判定する。
+        assert!(is_recorded_game_running(&record, true, None));
+    }
+
     #[test]
     fn elevated_launch_payload_round_trip() {
         let payload_path = temp_test_file_path("payload.json");
@@ -3,6 +3,8 @@
 // 実処理の実装はこの配下へ集約する。
 // 外部公開するサービスはこのモジュールで明示的に管理する。
 
+pub mod env_report_service;
 pub mod game_server_service;
 pub mod launch_service;
+pub mod profile_service;
 pub mod snr_service;
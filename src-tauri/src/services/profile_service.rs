@@ -0,0 +1,634 @@
+//! 複数プロファイル管理向けのサービス層。
+//! 親ディレクトリ配下の導入済みプロファイル検出をここに集約する。
+
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter, Runtime};
+
+use crate::services::{launch_service, snr_service};
+use crate::utils::{bootstrap, download, presets, settings};
+
+const PROFILE_RENAME_RETRY_ATTEMPTS: u32 = 5;
+const PROFILE_RENAME_RETRY_DELAY: Duration = Duration::from_millis(300);
+// 過去の配布版が使っていたプロファイル置き場（アプリデータからの相対パス）。
+// これまでの配布版はすべて profiles/<名前> を使っており、ここへは実際に出荷した旧配置だけを追加する。
+const LEGACY_PROFILE_LAYOUTS: &[&str] = &[];
+/// 設定中のプロファイルが失われていることをUIへ知らせるイベント。
+pub const PROFILE_MISSING_EVENT: &str = "profile-missing";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstalledProfileSummary {
+    pub name: String,
+    pub path: String,
+    pub version: Option<String>,
+    pub platform: Option<String>,
+    pub last_modified: Option<u64>,
+}
+
+/// 設定中プロファイルの導入状態。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileHealth {
+    pub profile_path: String,
+    pub exists: bool,
+    pub ready: bool,
+    pub missing_files: Vec<String>,
+    pub installed_tag: Option<String>,
+}
+
+/// 設定中プロファイルで使うSaveData関連パスの解決結果。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveDataPaths {
+    pub profile_path: String,
+    pub save_data_dir: String,
+    pub save_data_dir_exists: bool,
+    pub options_data_path: String,
+    pub options_data_exists: bool,
+    pub preset_count: usize,
+    // プリセット機能側の設定で解決した場所。save_data_dir と異なれば設定の不整合を示す。
+    pub presets_save_data_dir: String,
+    pub consistent: bool,
+}
+
+/// 旧レイアウトのプロファイル候補1件分。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyProfileCandidate {
+    pub path: String,
+    pub ready: bool,
+    pub has_save_data: bool,
+    pub installed_tag: Option<String>,
+}
+
+/// 旧レイアウトのプロファイル検出結果。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyProfileDetection {
+    pub profile_path: String,
+    pub profile_exists: bool,
+    pub candidates: Vec<LegacyProfileCandidate>,
+}
+
+/// 旧レイアウトからの移行結果。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyProfileMigrationResult {
+    pub source_path: String,
+    pub profile_path: String,
+    /// プロファイル全体を移動したか。既存プロファイルがある場合は false。
+    pub moved_profile: bool,
+    /// 既存プロファイルへSaveDataだけを移したか。
+    pub moved_save_data: bool,
+}
+
+fn inspect_profile(profile_path: &Path) -> ProfileHealth {
+    let missing_files = settings::missing_profile_required_files(profile_path)
+        .into_iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+    ProfileHealth {
+        profile_path: profile_path.to_string_lossy().to_string(),
+        exists: profile_path.is_dir(),
+        ready: missing_files.is_empty(),
+        missing_files,
+        installed_tag: snr_service::read_install_manifest(profile_path)
+            .map(|manifest| manifest.tag),
+    }
+}
+
+/// 設定中のプロファイルが存在し、有効な導入内容を保っているかを返す。
+pub fn profile_health<R: Runtime>(app: &AppHandle<R>) -> Result<ProfileHealth, String> {
+    let launcher_settings = settings::load_or_init_settings(app)?;
+    Ok(inspect_profile(Path::new(
+        launcher_settings.profile_path.trim(),
+    )))
+}
+
+/// 設定中プロファイルのSaveData・Options.dataの場所と存在状況、プリセット数を返す。
+pub fn savedata_paths<R: Runtime>(app: &AppHandle<R>) -> Result<SaveDataPaths, String> {
+    let launcher_settings = settings::load_or_init_settings(app)?;
+    let profile_path = launcher_settings.profile_path.trim();
+    // 参照専用のため、未設定時も設定を書き換えず既定パスを使う。
+    let profile_path = if profile_path.is_empty() {
+        settings::default_profile_path(app)?
+    } else {
+        PathBuf::from(profile_path)
+    };
+
+    let save_data_dir = snr_service::save_data_path_in_profile(&profile_path);
+    let options_data_path = presets::options_data_path(&save_data_dir);
+    let presets_save_data_dir = presets::save_data_dir_in_profile(&profile_path);
+    // 読み取れない Options.data はプリセット無しとして扱い、パス情報の取得は妨げない。
+    let preset_count = presets::list_presets_from_save_data_dir(&save_data_dir)
+        .map(|presets| presets.len())
+        .unwrap_or(0);
+
+    Ok(SaveDataPaths {
+        profile_path: profile_path.to_string_lossy().to_string(),
+        save_data_dir: save_data_dir.to_string_lossy().to_string(),
+        save_data_dir_exists: save_data_dir.is_dir(),
+        options_data_path: options_data_path.to_string_lossy().to_string(),
+        options_data_exists: options_data_path.is_file(),
+        preset_count,
+        presets_save_data_dir: presets_save_data_dir.to_string_lossy().to_string(),
+        consistent: presets_save_data_dir == save_data_dir,
+    })
+}
+
+/// 操作の失敗後に呼び出し、導入済みだったプロファイルが失われていれば `profile-missing` を通知する。
+/// `profile_path` 未指定時は設定中のプロファイルを検査する。
+pub fn emit_if_profile_missing<R: Runtime>(app: &AppHandle<R>, profile_path: Option<&str>) {
+    let Ok(launcher_settings) = settings::load_or_init_settings(app) else {
+        return;
+    };
+    let profile_path = match profile_path {
+        Some(path) => path.trim().to_string(),
+        None => launcher_settings.profile_path.trim().to_string(),
+    };
+
+    let health = inspect_profile(Path::new(&profile_path));
+    if is_installed_profile_missing(&health, &launcher_settings.selected_release_tag) {
+        log::warn!("Profile is missing or incomplete: {}", health.profile_path);
+        let _ = app.emit(PROFILE_MISSING_EVENT, health);
+    }
+}
+
+// 一度も導入していない初期状態は「消えた」わけではないため、導入記録がある場合に限る。
+fn is_installed_profile_missing(health: &ProfileHealth, recorded_tag: &str) -> bool {
+    let installed_before = health.installed_tag.is_some() || !recorded_tag.trim().is_empty();
+    installed_before && !health.missing_files.is_empty()
+}
+
+fn last_modified_secs(path: &Path) -> Option<u64> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs())
+}
+
+fn summarize_profile(path: &Path) -> InstalledProfileSummary {
+    let manifest = snr_service::read_install_manifest(path);
+    InstalledProfileSummary {
+        name: path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        path: path.to_string_lossy().to_string(),
+        version: manifest.as_ref().map(|manifest| manifest.tag.clone()),
+        platform: manifest.map(|manifest| manifest.platform),
+        last_modified: last_modified_secs(path),
+    }
+}
+
+/// 親ディレクトリ直下から、Mod起動可能なプロファイルを列挙する。
+pub fn list_installed_profiles(parent_dir: &str) -> Result<Vec<InstalledProfileSummary>, String> {
+    let parent_dir = PathBuf::from(parent_dir.trim());
+    if parent_dir.as_os_str().is_empty() {
+        return Err("Profile parent directory is required".to_string());
+    }
+    if !parent_dir.is_dir() {
+        return Err(format!(
+            "Profile parent directory was not found: {}",
+            parent_dir.display()
+        ));
+    }
+
+    let entries = fs::read_dir(&parent_dir).map_err(|e| {
+        format!(
+            "Failed to read profile parent directory '{}': {e}",
+            parent_dir.display()
+        )
+    })?;
+
+    let mut profiles = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        // 起動時と同じ必須ファイル検査を通るものだけを導入済みとみなす。
+        if !path.is_dir() || launch_service::ensure_modded_launch_files(&path).is_err() {
+            continue;
+        }
+        profiles.push(summarize_profile(&path));
+    }
+
+    profiles.sort_by_key(|profile| profile.name.to_lowercase());
+    Ok(profiles)
+}
+
+fn managed_roots<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<PathBuf>, String> {
+    let launcher_settings = settings::load_or_init_settings(app)?;
+    Ok([
+        launcher_settings.profile_path,
+        launcher_settings.among_us_path,
+    ]
+    .iter()
+    .map(|path| path.trim())
+    .filter(|path| !path.is_empty())
+    .filter_map(|path| fs::canonicalize(path).ok())
+    .collect())
+}
+
+/// プロファイルまたはゲームフォルダ配下のファイルのSHA256を返す。
+pub fn hash_managed_file<R: Runtime>(app: &AppHandle<R>, path: &str) -> Result<String, String> {
+    let trimmed = path.trim();
+    if trimmed.is_empty() {
+        return Err("Path is empty".to_string());
+    }
+
+    // シンボリックリンクや `..` で管理外へ抜けられないよう、正規化後のパスで判定する。
+    let target = fs::canonicalize(trimmed)
+        .map_err(|e| format!("Failed to resolve path '{trimmed}': {e}"))?;
+    if !managed_roots(app)?
+        .iter()
+        .any(|root| target.starts_with(root))
+    {
+        return Err(format!(
+            "Path is outside the profile and game directories: {}",
+            target.display()
+        ));
+    }
+    if !target.is_file() {
+        return Err(format!("Path is not a file: {}", target.display()));
+    }
+
+    download::sha256_file(&target)
+}
+
+fn configured_or_default_profile_path<R: Runtime>(
+    app: &AppHandle<R>,
+    launcher_settings: &settings::LauncherSettings,
+) -> Result<PathBuf, String> {
+    let profile_path = launcher_settings.profile_path.trim();
+    if profile_path.is_empty() {
+        return settings::default_profile_path(app);
+    }
+    Ok(PathBuf::from(profile_path))
+}
+
+fn legacy_profile_candidate_paths<R: Runtime>(
+    app: &AppHandle<R>,
+    profile_path: &Path,
+) -> Result<Vec<PathBuf>, String> {
+    let app_data_dir = settings::app_data_dir(app)?;
+    Ok(legacy_profile_candidates_in(
+        &app_data_dir,
+        profile_path,
+        LEGACY_PROFILE_LAYOUTS,
+    ))
+}
+
+fn legacy_profile_candidates_in(
+    app_data_dir: &Path,
+    profile_path: &Path,
+    layouts: &[&str],
+) -> Vec<PathBuf> {
+    // profiles/ 配下は現行の複数プロファイル置き場なので、旧配置として扱わない。
+    let profiles_dir = app_data_dir.join(bootstrap::PROFILES_DIR_NAME);
+    let mut candidates = Vec::new();
+    for layout in layouts {
+        let candidate = app_data_dir.join(layout);
+        if candidate.starts_with(&profiles_dir)
+            || candidate.starts_with(profile_path)
+            || profile_path.starts_with(&candidate)
+            || candidates.contains(&candidate)
+        {
+            continue;
+        }
+        // BepInEx か SaveData を持つディレクトリだけを旧プロファイルとみなす。
+        if candidate.join("BepInEx").is_dir()
+            || snr_service::save_data_path_in_profile(&candidate).is_dir()
+        {
+            candidates.push(candidate);
+        }
+    }
+    candidates
+}
+
+/// 設定中プロファイルの周辺から、旧レイアウトのプロファイルを探す。
+pub fn detect_legacy_profiles<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<LegacyProfileDetection, String> {
+    let launcher_settings = settings::load_or_init_global_settings(app)?;
+    let profile_path = configured_or_default_profile_path(app, &launcher_settings)?;
+    let candidates = legacy_profile_candidate_paths(app, &profile_path)?
+        .into_iter()
+        .map(|path| LegacyProfileCandidate {
+            ready: settings::is_profile_ready(&path),
+            has_save_data: snr_service::save_data_path_in_profile(&path).is_dir(),
+            installed_tag: snr_service::read_install_manifest(&path).map(|manifest| manifest.tag),
+            path: path.to_string_lossy().to_string(),
+        })
+        .collect();
+
+    Ok(LegacyProfileDetection {
+        profile_exists: profile_path.is_dir(),
+        profile_path: profile_path.to_string_lossy().to_string(),
+        candidates,
+    })
+}
+
+fn is_empty_dir(path: &Path) -> bool {
+    fs::read_dir(path)
+        .map(|mut entries| entries.next().is_none())
+        .unwrap_or(false)
+}
+
+/// 旧レイアウトのプロファイルを現在の保存先へ移す。
+/// 保存先にプロファイルが既にある場合は、SaveDataだけを移して既存の導入内容は変更しない。
+pub fn migrate_legacy_profile<R: Runtime>(
+    app: &AppHandle<R>,
+    source_path: &str,
+) -> Result<LegacyProfileMigrationResult, String> {
+    // 移動と設定保存の間にインストールが始まらないよう、完了までガードを保持する。
+    let _install_guard = snr_service::InstallGuard::acquire()
+        .map_err(|_| "Cannot migrate a profile while an install is in progress".to_string())?;
+    if launch_service::is_game_running(app.clone())? {
+        return Err("Cannot migrate a profile while the game is running".to_string());
+    }
+
+    let mut launcher_settings = settings::load_or_init_global_settings(app)?;
+    let profile_path = configured_or_default_profile_path(app, &launcher_settings)?;
+    // 任意パスの移動を防ぐため、検出した候補に限って受け付ける。
+    let source = PathBuf::from(source_path.trim());
+    if !legacy_profile_candidate_paths(app, &profile_path)?.contains(&source) {
+        return Err(format!(
+            "Not a detected legacy profile: {}",
+            source.display()
+        ));
+    }
+
+    let mut result = LegacyProfileMigrationResult {
+        source_path: source.to_string_lossy().to_string(),
+        profile_path: profile_path.to_string_lossy().to_string(),
+        moved_profile: false,
+        moved_save_data: false,
+    };
+
+    if !profile_path.exists() || is_empty_dir(&profile_path) {
+        if let Some(parent) = profile_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create profile parent directory: {e}"))?;
+        }
+        if profile_path.exists() {
+            fs::remove_dir(&profile_path).map_err(|e| {
+                format!(
+                    "Failed to remove empty profile directory '{}': {e}",
+                    profile_path.display()
+                )
+            })?;
+        }
+        rename_with_retry(&source, &profile_path).map_err(|e| {
+            format!(
+                "Failed to move legacy profile ('{}' -> '{}'): {e}",
+                source.display(),
+                profile_path.display()
+            )
+        })?;
+        result.moved_profile = true;
+    } else {
+        let source_save_data = snr_service::save_data_path_in_profile(&source);
+        let target_save_data = snr_service::save_data_path_in_profile(&profile_path);
+        if source_save_data.is_dir() {
+            // 既存のSaveDataは上書きせず、移行先が空の場合だけ移す。
+            if target_save_data.exists() && !is_empty_dir(&target_save_data) {
+                return Err(format!(
+                    "The current profile already has save data: {}",
+                    target_save_data.display()
+                ));
+            }
+            if let Some(parent) = target_save_data.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create SaveData parent directory: {e}"))?;
+            }
+            if target_save_data.exists() {
+                fs::remove_dir(&target_save_data).map_err(|e| {
+                    format!(
+                        "Failed to remove empty SaveData directory '{}': {e}",
+                        target_save_data.display()
+                    )
+                })?;
+            }
+            rename_with_retry(&source_save_data, &target_save_data).map_err(|e| {
+                format!(
+                    "Failed to move legacy SaveData ('{}' -> '{}'): {e}",
+                    source_save_data.display(),
+                    target_save_data.display()
+                )
+            })?;
+            result.moved_save_data = true;
+        }
+    }
+
+    if launcher_settings.profile_path.trim().is_empty() {
+        launcher_settings.profile_path = result.profile_path.clone();
+        settings::save_settings(app, &launcher_settings)?;
+    }
+    Ok(result)
+}
+
+fn validate_profile_dir_name(new_dir_name: &str) -> Result<&str, String> {
+    let trimmed = new_dir_name.trim();
+    if trimmed.is_empty() || trimmed == "." || trimmed == ".." {
+        return Err("Profile directory name is required".to_string());
+    }
+    // 同じ親ディレクトリ内での改名に限定するため、区切り文字やOS非対応文字を拒否する。
+    if trimmed.chars().any(|c| {
+        matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|') || c.is_control()
+    }) {
+        return Err(format!("Invalid profile directory name: {trimmed}"));
+    }
+    Ok(trimmed)
+}
+
+/// 改名先のパスを求める。同じ名前なら None、既に存在する場合はエラーを返す。
+fn profile_rename_target(
+    profile_path: &Path,
+    new_dir_name: &str,
+) -> Result<Option<PathBuf>, String> {
+    let parent = profile_path.parent().ok_or_else(|| {
+        format!(
+            "Profile directory has no parent: {}",
+            profile_path.display()
+        )
+    })?;
+    let target_path = parent.join(new_dir_name);
+    if target_path == profile_path {
+        return Ok(None);
+    }
+    if target_path.exists() {
+        return Err(format!(
+            "A file or directory already exists at: {}",
+            target_path.display()
+        ));
+    }
+    Ok(Some(target_path))
+}
+
+fn is_retryable_rename_error(error: &io::Error) -> bool {
+    // Windowsではウイルス対策ソフトなどが一時的にファイルを掴むため、共有違反は再試行する。
+    cfg!(windows) && matches!(error.raw_os_error(), Some(5) | Some(32) | Some(33))
+}
+
+fn rename_with_retry(from: &Path, to: &Path) -> io::Result<()> {
+    let mut attempt = 1;
+    loop {
+        match fs::rename(from, to) {
+            Ok(()) => return Ok(()),
+            Err(error)
+                if attempt < PROFILE_RENAME_RETRY_ATTEMPTS && is_retryable_rename_error(&error) =>
+            {
+                attempt += 1;
+                thread::sleep(PROFILE_RENAME_RETRY_DELAY);
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+/// 選択中プロファイルのディレクトリを同じ親ディレクトリ内で改名し、設定の保存先も更新する。
+pub fn rename_active_profile<R: Runtime>(
+    app: &AppHandle<R>,
+    new_dir_name: &str,
+) -> Result<String, String> {
+    let new_dir_name = validate_profile_dir_name(new_dir_name)?;
+    // 改名と設定保存の間にインストールが旧パスへ反映しないよう、完了までガードを保持する。
+    let _install_guard = snr_service::InstallGuard::acquire()
+        .map_err(|_| "Cannot rename the profile while an install is in progress".to_string())?;
+    if launch_service::is_game_running(app.clone())? {
+        return Err("Cannot rename the profile while the game is running".to_string());
+    }
+
+    let mut launcher_settings = settings::load_or_init_global_settings(app)?;
+    let profile_path = PathBuf::from(launcher_settings.profile_path.trim());
+    if !profile_path.is_dir() {
+        return Err(format!(
+            "Profile directory was not found: {}",
+            profile_path.display()
+        ));
+    }
+    let Some(target_path) = profile_rename_target(&profile_path, new_dir_name)? else {
+        return Ok(profile_path.to_string_lossy().to_string());
+    };
+
+    rename_with_retry(&profile_path, &target_path).map_err(|e| {
+        format!(
+            "Failed to rename profile ('{}' -> '{}'): {e}",
+            profile_path.display(),
+            target_path.display()
+        )
+    })?;
+
+    launcher_settings.profile_path = target_path.to_string_lossy().to_string();
+    if let Err(error) = settings::save_settings(app, &launcher_settings) {
+        // 設定と実ディレクトリがずれないよう、保存失敗時は元の名前へ戻す。
+        let _ = rename_with_retry(&target_path, &profile_path);
+        return Err(error);
+    }
+    Ok(launcher_settings.profile_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn make_temp_dir(label: &str) -> PathBuf {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        std::env::temp_dir().join(format!(
+            "snr-profile-{label}-{}-{millis}",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn validate_profile_dir_name_rejects_paths_and_reserved_names() {
+        assert_eq!(validate_profile_dir_name("  Main  "), Ok("Main"));
+        for invalid in ["", " ", ".", "..", "a/b", "a\\b", "C:", "a*b", "a\u{7}b"] {
+            assert!(
+                validate_profile_dir_name(invalid).is_err(),
+                "{invalid:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn installed_profile_missing_requires_install_record() {
+        let root = make_temp_dir("profile-missing");
+        let _ = fs::remove_dir_all(&root);
+        let profile_path = root.join("default");
+
+        // 未導入のまま既定パスが存在しない初回状態では通知しない。
+        let never_installed = inspect_profile(&profile_path);
+        assert!(!never_installed.ready);
+        assert!(!is_installed_profile_missing(&never_installed, ""));
+        assert!(!is_installed_profile_missing(&never_installed, "  "));
+
+        // 導入記録があるのに必須ファイルが無い場合だけ通知する。
+        assert!(is_installed_profile_missing(&never_installed, "v1.0.0"));
+
+        for path in settings::profile_required_file_paths(&profile_path) {
+            fs::create_dir_all(path.parent().expect("required file parent"))
+                .expect("create required file parent");
+            fs::write(&path, b"ok").expect("write required file");
+        }
+        let ready = inspect_profile(&profile_path);
+        assert!(ready.ready);
+        assert!(!is_installed_profile_missing(&ready, "v1.0.0"));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn legacy_profile_candidates_ignore_sibling_profiles() {
+        let root = make_temp_dir("legacy-siblings");
+        let _ = fs::remove_dir_all(&root);
+        let profiles_dir = root.join(bootstrap::PROFILES_DIR_NAME);
+        let profile_path = profiles_dir.join("default");
+        let sibling = profiles_dir.join("profile");
+        fs::create_dir_all(profile_path.join("BepInEx")).expect("create profile");
+        fs::create_dir_all(sibling.join("BepInEx")).expect("create sibling profile");
+        fs::create_dir_all(root.join("old").join("BepInEx")).expect("create legacy profile");
+
+        assert!(
+            legacy_profile_candidates_in(&root, &profile_path, LEGACY_PROFILE_LAYOUTS).is_empty()
+        );
+        assert_eq!(
+            legacy_profile_candidates_in(
+                &root,
+                &profile_path,
+                &["profiles/profile", "profiles", "old"]
+            ),
+            vec![root.join("old")]
+        );
+        assert!(sibling.join("BepInEx").is_dir());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn profile_rename_target_handles_same_name_and_existing_target() {
+        let root = make_temp_dir("rename-target");
+        let _ = fs::remove_dir_all(&root);
+        let profile_path = root.join("profile");
+        fs::create_dir_all(&profile_path).expect("create profile");
+        fs::create_dir_all(root.join("taken")).expect("create existing target");
+
+        assert_eq!(profile_rename_target(&profile_path, "profile"), Ok(None));
+        assert!(profile_rename_target(&profile_path, "taken").is_err());
+        assert_eq!(
+            profile_rename_target(&profile_path, "renamed"),
+            Ok(Some(root.join("renamed")))
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -2,19 +2,26 @@
 //! commands層から呼び出される実処理をここに集約する。
 // インストール工程ごとの失敗時ロールバックもこの層で担保する。
 
-use crate::utils::{download, migration, mod_profile, presets, settings, zip};
+use crate::services::launch_service;
+use crate::utils::{
+    background_notifications, bootstrap, download, finder, migration, mod_profile, presets,
+    settings, zip,
+};
+use futures_util::stream::{self, StreamExt};
 use regex::Regex;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::io;
 use std::path::{Component, Path, PathBuf};
-use std::time::{Duration, Instant};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Emitter, Runtime};
 
-const PRESERVED_SAVE_DATA_DIR: &str = "preserved_save_data";
+const PRESERVED_SAVE_DATA_DIR: &str = bootstrap::PRESERVED_SAVE_DATA_DIR_NAME;
 const SAVE_DATA_STAGING_DIR_NAME: &str = "SaveData._import_staging";
 const SAVE_DATA_BACKUP_DIR_NAME: &str = "SaveData._import_backup";
 const OPTIONS_DATA_FILE_NAME: &str = "Options.data";
@@ -24,12 +31,206 @@ const NO_IMPORTABLE_PRESETS_ERROR: &str =
 // インストール全体の進捗(0-100)へ統合するための配分。
 // downloading/extracting は各ステージの 0-100 をこの範囲へ線形変換する。
 const INSTALL_DOWNLOAD_END: f64 = 80.0;
-const INSTALL_EXTRACT_END: f64 = 98.0;
-const INSTALL_PATCHERS_END: f64 = 99.0;
-const INSTALL_RESTORE_END: f64 = 100.0;
+const INSTALL_EXTRACT_END: f64 = 97.0;
+const INSTALL_PATCHERS_END: f64 = 98.0;
+const INSTALL_RESTORE_END: f64 = 99.0;
+const INSTALL_VERIFY_END: f64 = 100.0;
 const PATCHER_SYNC_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
 const PATCHER_SYNC_REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
 const PATCHER_SYNC_MAX_DURATION: Duration = Duration::from_secs(45);
+const PATCHER_DOWNLOAD_CONCURRENCY: usize = 4;
+// 展開先と差し替え後プロファイルが同時に存在するため、プロファイル側はアセットの2倍を見込む。
+// ダウンロード分（アセット1つ分）はキャッシュのドライブで別に確認する。
+const INSTALL_PROFILE_DISK_SPACE_FACTOR: u64 = 2;
+const INSTALL_ALREADY_IN_PROGRESS_ERROR: &str = "An install is already in progress.";
+// ローカルzipのファイル名からバージョンを推定できない場合に記録するタグ。
+const LOCAL_ARCHIVE_TAG: &str = "local";
+const INSTALL_CANCELLED_ERROR: &str = "Installation was cancelled.";
+const INSTALL_MANIFEST_FILE_NAME: &str = "launcher-install.json";
+const SAVE_DATA_IMPORT_CANCELLED_ERROR: &str = "SaveData import was cancelled.";
+const DOWNLOAD_CORRUPTED_ERROR_PREFIX: &str = "DOWNLOAD_CORRUPTED:";
+const SAVE_DATA_ARCHIVE_EXTRACT_DIR_PREFIX: &str = "savedata-archive";
+// 起動時の片付けで、削除前に残骸を退避させる名前の接尾辞。
+const ORPHAN_TRASH_SUFFIX: &str = "._trash";
+const RELEASE_LIST_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+// _staging/_backup を共有するため、インストールは同時に1件のみ許可する。
+static INSTALL_IN_PROGRESS: AtomicBool = AtomicBool::new(false);
+static SAVE_DATA_IMPORT_CANCEL_REQUESTED: AtomicBool = AtomicBool::new(false);
+// 中断要求はタグ単位で受け付け、対象インストールの各段階で確認する。
+static INSTALL_CANCEL_TOKENS: Mutex<Vec<(String, Arc<AtomicBool>)>> = Mutex::new(Vec::new());
+// 再生成されたWebviewが進行中の処理へ再同期できるよう、実行中の長時間処理を記録する。
+static ACTIVE_OPERATIONS: Mutex<Vec<ActiveOperation>> = Mutex::new(Vec::new());
+// 画面遷移のたびに GitHub API を叩かないよう、リリース一覧を一定時間保持する。
+static RELEASE_LIST_CACHE: Mutex<Option<(Instant, Vec<SnrReleaseSummary>)>> = Mutex::new(None);
+
+/// インストールと、プロファイルのディレクトリを動かす処理を排他するガード。
+pub(crate) struct InstallGuard;
+
+impl InstallGuard {
+    pub(crate) fn acquire() -> Result<Self, String> {
+        INSTALL_IN_PROGRESS
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .map(|_| Self)
+            .map_err(|_| INSTALL_ALREADY_IN_PROGRESS_ERROR.to_string())
+    }
+}
+
+impl Drop for InstallGuard {
+    fn drop(&mut self) {
+        // 失敗・中断(future破棄)時も確実に解放する。
+        INSTALL_IN_PROGRESS.store(false, Ordering::SeqCst);
+    }
+}
+
+pub fn is_install_in_progress() -> bool {
+    INSTALL_IN_PROGRESS.load(Ordering::SeqCst)
+}
+
+/// 実行中インストールの中断トークン。破棄時に登録を外す。
+struct InstallCancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl InstallCancelToken {
+    fn register(tag: &str) -> Self {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        if let Ok(mut tokens) = INSTALL_CANCEL_TOKENS.lock() {
+            tokens.push((tag.to_string(), Arc::clone(&cancelled)));
+        }
+        Self { cancelled }
+    }
+
+    fn flag(&self) -> &AtomicBool {
+        &self.cancelled
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    fn ensure_not_cancelled(&self) -> Result<(), String> {
+        if self.is_cancelled() {
+            return Err(INSTALL_CANCELLED_ERROR.to_string());
+        }
+        Ok(())
+    }
+
+    /// プロファイル差し替えの直前に呼び、以降の中断要求を受け付けなくする。
+    /// 差し替えは完了するか元へ戻すかのどちらかで終わらせるため、途中では止めない。
+    fn commit(&self) -> Result<(), String> {
+        let mut tokens = INSTALL_CANCEL_TOKENS
+            .lock()
+            .map_err(|_| "Install cancel registry is unavailable".to_string())?;
+        // 中断要求と同じロック内で確認し、確認後に要求が割り込まないようにする。
+        self.ensure_not_cancelled()?;
+        tokens.retain(|(_, flag)| !Arc::ptr_eq(flag, &self.cancelled));
+        Ok(())
+    }
+}
+
+impl Drop for InstallCancelToken {
+    fn drop(&mut self) {
+        if let Ok(mut tokens) = INSTALL_CANCEL_TOKENS.lock() {
+            tokens.retain(|(_, flag)| !Arc::ptr_eq(flag, &self.cancelled));
+        }
+    }
+}
+
+/// 実行中インストールへ中断を要求する。タグ未指定時は全件が対象。
+/// 中断を受け付けたインストールがあれば true を返す。
+pub fn request_install_cancel(tag: Option<&str>) -> bool {
+    let tag = tag.map(str::trim).filter(|tag| !tag.is_empty());
+    let Ok(tokens) = INSTALL_CANCEL_TOKENS.lock() else {
+        return false;
+    };
+    let mut requested = false;
+    for (token_tag, flag) in tokens.iter() {
+        if tag.is_none_or(|tag| tag == token_tag) {
+            flag.store(true, Ordering::SeqCst);
+            requested = true;
+        }
+    }
+    requested
+}
+
+/// 状態照会の対象となる長時間処理の種類。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OperationKind {
+    Install,
+    MigrationExport,
+    MigrationImport,
+    SaveDataImport,
+    ReportSend,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveOperation {
+    pub kind: OperationKind,
+    pub started_at: u64,
+    /// 直近に通知した進捗イベントのペイロード。進捗を通知しない処理では None。
+    pub progress: Option<Value>,
+}
+
+/// 生存中は処理を実行中として記録するガード。
+pub struct OperationGuard {
+    kind: OperationKind,
+}
+
+impl Drop for OperationGuard {
+    fn drop(&mut self) {
+        if let Ok(mut operations) = ACTIVE_OPERATIONS.lock() {
+            if let Some(index) = operations
+                .iter()
+                .rposition(|operation| operation.kind == self.kind)
+            {
+                operations.remove(index);
+            }
+        }
+    }
+}
+
+/// 長時間処理の開始を記録する。返したガードの破棄で記録を外す。
+pub fn begin_operation(kind: OperationKind) -> OperationGuard {
+    let started_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+    if let Ok(mut operations) = ACTIVE_OPERATIONS.lock() {
+        operations.push(ActiveOperation {
+            kind,
+            started_at,
+            progress: None,
+        });
+    }
+    OperationGuard { kind }
+}
+
+fn record_operation_progress(kind: OperationKind, payload: &impl Serialize) {
+    set_operation_progress(kind, serde_json::to_value(payload).ok());
+}
+
+fn set_operation_progress(kind: OperationKind, value: Option<Value>) {
+    if let Ok(mut operations) = ACTIVE_OPERATIONS.lock() {
+        if let Some(operation) = operations
+            .iter_mut()
+            .rev()
+            .find(|operation| operation.kind == kind)
+        {
+            operation.progress = value;
+        }
+    }
+}
+
+/// 実行中の長時間処理を開始順に返す。
+pub fn active_operations() -> Vec<ActiveOperation> {
+    ACTIVE_OPERATIONS
+        .lock()
+        .map(|operations| operations.clone())
+        .unwrap_or_default()
+}
 
 fn among_us_exe_name() -> &'static str {
     // 実行ファイル名の定義はmodプロファイルへ一本化する。
@@ -98,8 +299,9 @@ fn map_install_progress(stage: &str, stage_percent: f64) -> f64 {
         "extracting" => scale_progress(clamped, INSTALL_DOWNLOAD_END, INSTALL_EXTRACT_END),
         "patchers" => scale_progress(clamped, INSTALL_EXTRACT_END, INSTALL_PATCHERS_END),
         "restoring" => scale_progress(clamped, INSTALL_PATCHERS_END, INSTALL_RESTORE_END),
+        "verifying" => scale_progress(clamped, INSTALL_RESTORE_END, INSTALL_VERIFY_END),
         "complete" => 100.0,
-        "failed" => 0.0,
+        "failed" | "cancelled" => 0.0,
         _ => clamped,
     }
 }
@@ -108,6 +310,11 @@ fn map_install_progress(stage: &str, stage_percent: f64) -> f64 {
 struct GitHubAsset {
     name: String,
     browser_download_url: String,
+    /// GitHubが公開するアセットのダイジェスト（例: "sha256:..."）。古いリリースでは無い。
+    #[serde(default)]
+    digest: Option<String>,
+    #[serde(default)]
+    size: u64,
 }
 
 #[derive(Debug, Deserialize)]
@@ -119,6 +326,18 @@ struct GitHubRelease {
     assets: Vec<GitHubAsset>,
 }
 
+#[derive(Debug, Deserialize)]
+struct GitHubRateLimitResource {
+    limit: u64,
+    remaining: u64,
+    reset: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRateLimitPayload {
+    rate: GitHubRateLimitResource,
+}
+
 #[derive(Debug, Deserialize)]
 struct PatchersManifestPayload {
     #[serde(default)]
@@ -141,6 +360,15 @@ pub struct SnrReleaseSummary {
     pub published_at: String,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitHubRateLimitStatus {
+    pub limit: u64,
+    pub remaining: u64,
+    pub reset_at: u64,
+    pub resets_in_seconds: u64,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InstallResult {
@@ -151,12 +379,168 @@ pub struct InstallResult {
     pub restored_save_files: usize,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepatchCheck {
+    /// ゲーム更新によりパッチャーの再同期が推奨される場合は true。
+    pub needed: bool,
+    pub detected_game_version: Option<String>,
+    pub recorded_game_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatcherResyncResult {
+    /// 取得・検証に失敗して更新できなかったパッチャー名。
+    pub skipped: Vec<String>,
+    pub game_version: Option<String>,
+}
+
+/// インストール時にプロファイル直下へ記録する導入情報。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallManifest {
+    pub tag: String,
+    pub platform: String,
+    pub asset_name: String,
+    pub installed_at: u64,
+    /// 配布zipから展開したファイルの一覧。記録前の導入情報では空になる。
+    #[serde(default)]
+    pub files: Vec<InstallManifestFile>,
+    /// 導入・パッチャー同期時に検出したゲームのバージョン。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub game_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallManifestFile {
+    pub path: String,
+    pub size: u64,
+    pub crc32: u32,
+}
+
+impl From<zip::ZipFileEntry> for InstallManifestFile {
+    fn from(entry: zip::ZipFileEntry) -> Self {
+        Self {
+            path: entry.path,
+            size: entry.size,
+            crc32: entry.crc32,
+        }
+    }
+}
+
+/// インストールせずに保存したリリースzipの情報。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseDownloadResult {
+    pub output_path: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+/// 現在の導入内容から指定リリースへ更新した場合のファイル差分。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModUpdateDiff {
+    pub installed_tag: String,
+    pub target_tag: String,
+    pub platform: String,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+    pub unchanged_count: usize,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UninstallResult {
     pub profile_path: String,
     pub removed_profile: bool,
     pub preserved_files: usize,
+    // 同じバージョンの再インストールでダウンロードを省略できるキャッシュが残っているか。
+    pub reusable_cache: bool,
+}
+
+/// アンインストール実行前の確認用に、削除・保持される内容を集計した結果。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UninstallPreview {
+    pub profile_path: String,
+    pub profile_exists: bool,
+    pub preserved_files: usize,
+    pub removed_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OrphanCleanupResult {
+    pub removed_paths: Vec<String>,
+    pub reclaimed_bytes: u64,
+    pub restored_profile_from_backup: bool,
+}
+
+/// ランチャーが作成する一時ファイル・作業用ディレクトリの種類。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ManagedArtifactKind {
+    ProfileStaging,
+    ProfileBackup,
+    SaveDataImportStaging,
+    SaveDataImportBackup,
+    ElevatedLaunch,
+    MigrationImportBackup,
+    ReleaseCache,
+}
+
+impl ManagedArtifactKind {
+    /// この種類を作業領域として使う長時間処理。
+    fn owner_operation(self) -> Option<OperationKind> {
+        match self {
+            Self::ProfileStaging | Self::ProfileBackup | Self::ReleaseCache => {
+                Some(OperationKind::Install)
+            }
+            Self::SaveDataImportStaging | Self::SaveDataImportBackup => {
+                Some(OperationKind::SaveDataImport)
+            }
+            Self::MigrationImportBackup => Some(OperationKind::MigrationImport),
+            Self::ElevatedLaunch => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedArtifact {
+    pub kind: ManagedArtifactKind,
+    pub path: String,
+    pub size_bytes: u64,
+    /// 最終更新時刻（UNIX 秒）。取得できない場合は None。
+    pub modified_at: Option<u64>,
+    pub age_seconds: Option<u64>,
+    /// 実行中の処理が使用している場合は true。
+    pub in_use: bool,
+    /// `managed_artifacts_cleanup` の削除対象なら true。
+    pub safe_to_delete: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedArtifactsCleanupResult {
+    pub removed_paths: Vec<String>,
+    pub reclaimed_bytes: u64,
+    /// 使用中・削除対象外・削除失敗のため残したパス。
+    pub skipped_paths: Vec<String>,
+    pub restored_profile_from_backup: bool,
+}
+
+/// BepInEx/config の引き継ぎ設定と、現在のプロファイル・退避先にある設定ファイル。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BepInExConfigPreservationStatus {
+    pub enabled: bool,
+    pub profile_files: Vec<String>,
+    pub preserved_files: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -166,6 +550,23 @@ pub struct PreservedSaveDataStatus {
     pub files: usize,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreservedSaveDataFile {
+    /// 保持ディレクトリからの "/" 区切り相対パス。
+    pub path: String,
+    pub size: u64,
+    /// 最終更新日時（UNIX秒）。取得できない場合は None。
+    pub modified_at: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreservedSaveDataDetails {
+    pub files: Vec<PreservedSaveDataFile>,
+    pub total_size: u64,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SaveDataPresetSummary {
@@ -183,6 +584,17 @@ pub struct SaveDataPreviewResult {
     pub file_count: usize,
 }
 
+/// 取り込み元と取り込み先の Options.data 形式バージョンの比較結果。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveDataCompatReport {
+    pub source_save_data_path: String,
+    pub target_save_data_path: String,
+    pub source_version: Option<u8>,
+    pub target_version: Option<u8>,
+    pub compatible: bool,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SaveDataImportResult {
@@ -232,17 +644,32 @@ fn emit_progress<R: Runtime>(
         current,
         entries_total,
     };
+    // 完了・失敗は結果として別途返るため、再同期用の進捗からは外す。
+    if matches!(payload.stage.as_str(), "complete" | "failed" | "cancelled") {
+        set_operation_progress(OperationKind::Install, None);
+    } else {
+        record_operation_progress(OperationKind::Install, &payload);
+    }
     let _ = app.emit(install_progress_event(), payload.clone());
     let _ = app.emit(install_progress_legacy_event(), payload);
 }
 
+fn write_install_manifest(profile_path: &Path, manifest: &InstallManifest) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(manifest)
+        .map_err(|e| format!("Failed to serialize install manifest: {e}"))?;
+    fs::write(profile_path.join(INSTALL_MANIFEST_FILE_NAME), json)
+        .map_err(|e| format!("Failed to write install manifest: {e}"))
+}
+
+/// プロファイルの導入情報を読み込む。未記録・破損時は None を返す。
+pub fn read_install_manifest(profile_path: &Path) -> Option<InstallManifest> {
+    let content = fs::read_to_string(profile_path.join(INSTALL_MANIFEST_FILE_NAME)).ok()?;
+    serde_json::from_str::<InstallManifest>(&content).ok()
+}
+
 fn patcher_sync_client() -> Result<Client, String> {
     Client::builder()
-        .user_agent(format!(
-            "{}/{}",
-            mod_profile::get().branding.launcher_name,
-            env!("CARGO_PKG_VERSION")
-        ))
+        .user_agent(mod_profile::launcher_user_agent())
         .connect_timeout(PATCHER_SYNC_CONNECT_TIMEOUT)
         .timeout(PATCHER_SYNC_REQUEST_TIMEOUT)
         .build()
@@ -370,10 +797,32 @@ fn verify_md5(path: &Path, expected: &str) -> Result<(), String> {
     }
 }
 
+// GitHubのダイジェストは "sha256:<hex>" 形式のため、sha256 以外は検証対象外とする。
+fn sha256_from_asset_digest(digest: &str) -> Option<&str> {
+    let (algorithm, value) = digest.trim().split_once(':')?;
+    let value = value.trim();
+    (algorithm.eq_ignore_ascii_case("sha256") && !value.is_empty()).then_some(value)
+}
+
+fn verify_sha256(path: &Path, expected: &str) -> Result<(), String> {
+    let actual = download::sha256_file(path)?;
+    let expected = expected.trim().to_ascii_lowercase();
+
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "SHA256 mismatch for '{}': expected '{expected}', got '{actual}'",
+            path.display()
+        ))
+    }
+}
+
 async fn download_patchers_into_staging<R: Runtime>(
     app: &AppHandle<R>,
     client: &Client,
     staging_path: &Path,
+    cancel_requested: Option<&AtomicBool>,
 ) -> Result<Vec<String>, String> {
     let started_at = Instant::now();
     let patchers = fetch_patcher_manifest(client).await?;
@@ -411,99 +860,126 @@ async fn download_patchers_into_staging<R: Runtime>(
         Some(total_patchers),
     );
 
-    let mut skipped: Vec<String> = Vec::new();
-
-    for (index, patcher) in patchers.iter().enumerate() {
-        ensure_patcher_sync_within_time(started_at)?;
-        let index = index + 1;
-        let name = patcher.name.trim();
-        let base_progress = ((index - 1) as f64 / total_patchers as f64) * 100.0;
-
-        if !safe_patcher_name(name) {
-            skipped.push(name.to_string());
-            emit_progress(
-                app,
-                "patchers",
-                (index as f64 / total_patchers as f64) * 100.0,
-                "Skipping unsafe patcher",
-                None,
-                None,
-                Some(index),
-                Some(total_patchers),
-            );
-            continue;
-        }
-
-        let destination = patchers_dir.join(name);
-        let encoded_name = urlencoding::encode(name);
-        let url = format!("{}{encoded_name}", patcher_base_url());
-
-        let download_result =
-            download::download_file(client, &url, &destination, |downloaded, total| {
-                let file_percent = total
-                    .map(|size| (downloaded as f64 / size as f64) * 100.0)
-                    .unwrap_or(0.0);
-                let stage_percent = base_progress + file_percent / total_patchers as f64;
-                emit_progress(
-                    app,
-                    "patchers",
-                    stage_percent.clamp(0.0, 100.0),
-                    format!("Downloading patcher {index}/{total_patchers}"),
-                    Some(downloaded),
-                    total,
-                    Some(index),
-                    Some(total_patchers),
-                );
-            })
-            .await;
-
-        if let Err(error) = download_result {
-            skipped.push(name.to_string());
-            emit_progress(
-                app,
-                "patchers",
-                (index as f64 / total_patchers as f64) * 100.0,
-                format!("Download failed ({index}/{total_patchers}): {error}"),
-                None,
-                None,
-                Some(index),
-                Some(total_patchers),
-            );
-            eprintln!("Failed to download patcher '{name}': {error}");
-            let _ = fs::remove_file(&destination);
-            continue;
-        }
-
-        if let Some(expected_md5) = patcher.expected_md5.as_deref() {
-            if let Err(error) = verify_md5(&destination, expected_md5) {
-                skipped.push(name.to_string());
-                emit_progress(
-                    app,
-                    "patchers",
-                    (index as f64 / total_patchers as f64) * 100.0,
-                    format!("MD5 mismatch ({index}/{total_patchers}): {error}"),
-                    None,
-                    None,
-                    Some(index),
-                    Some(total_patchers),
-                );
-                eprintln!("Failed to verify patcher '{name}': {error}");
-                let _ = fs::remove_file(&destination);
-                continue;
-            }
+    // ファイルごとの進捗率と完了件数を共有し、並列取得中も1本の進捗として通知する。
+    let file_percents = Mutex::new(vec![0.0_f64; total_patchers]);
+    let completed = AtomicUsize::new(0);
+    let stage_percent = || {
+        file_percents
+            .lock()
+            .map(|percents| percents.iter().sum::<f64>() / total_patchers as f64)
+            .unwrap_or(0.0)
+    };
+    let set_file_percent = |slot: usize, percent: f64| {
+        if let Ok(mut percents) = file_percents.lock() {
+            percents[slot] = percent.clamp(0.0, 100.0);
         }
-
+    };
+    let finish_patcher = |slot: usize, message: &str| {
+        set_file_percent(slot, 100.0);
+        let done = completed.fetch_add(1, Ordering::SeqCst) + 1;
         emit_progress(
             app,
             "patchers",
-            (index as f64 / total_patchers as f64) * 100.0,
-            format!("Patchers ok ({index}/{total_patchers})"),
+            stage_percent(),
+            format!("{message} ({done}/{total_patchers})"),
             None,
             None,
-            Some(index),
+            Some(done),
             Some(total_patchers),
         );
+    };
+
+    // 1件の失敗は他の取得を止めず、スキップとして集計する。中断と時間超過だけは全体を打ち切る。
+    let downloads = patchers
+        .iter()
+        .enumerate()
+        .map(|(slot, patcher)| {
+            let set_file_percent = &set_file_percent;
+            let finish_patcher = &finish_patcher;
+            let stage_percent = &stage_percent;
+            let completed = &completed;
+            let patchers_dir = &patchers_dir;
+            async move {
+                if cancel_requested.is_some_and(|flag| flag.load(Ordering::SeqCst)) {
+                    return Err(INSTALL_CANCELLED_ERROR.to_string());
+                }
+                ensure_patcher_sync_within_time(started_at)?;
+                let name = patcher.name.trim();
+
+                if !safe_patcher_name(name) {
+                    finish_patcher(slot, "Skipping unsafe patcher");
+                    return Ok(Some((slot, name.to_string())));
+                }
+
+                let destination = patchers_dir.join(name);
+                let encoded_name = urlencoding::encode(name);
+                let url = format!("{}{encoded_name}", patcher_base_url());
+
+                let download_result = download::download_file_cancellable(
+                    client,
+                    &url,
+                    &destination,
+                    cancel_requested,
+                    |downloaded, total| {
+                        let file_percent = total
+                            .map(|size| (downloaded as f64 / size as f64) * 100.0)
+                            .unwrap_or(0.0);
+                        set_file_percent(slot, file_percent);
+                        let done = completed.load(Ordering::SeqCst);
+                        emit_progress(
+                            app,
+                            "patchers",
+                            stage_percent(),
+                            format!("Downloading patchers ({done}/{total_patchers})"),
+                            None,
+                            None,
+                            Some(done),
+                            Some(total_patchers),
+                        );
+                    },
+                )
+                .await;
+
+                if let Err(error) = download_result {
+                    log::warn!("Failed to download patcher '{name}': {error}");
+                    // プロファイル内に途中ファイルを残さないよう、再開用の .part も消す。
+                    let _ = fs::remove_file(download::partial_download_path(&destination));
+                    let _ = fs::remove_file(&destination);
+                    finish_patcher(slot, &format!("Download failed: {error}"));
+                    return Ok(Some((slot, name.to_string())));
+                }
+
+                if let Some(expected_md5) = patcher.expected_md5.as_deref() {
+                    if let Err(error) = verify_md5(&destination, expected_md5) {
+                        log::warn!("Failed to verify patcher '{name}': {error}");
+                        let _ = fs::remove_file(&destination);
+                        finish_patcher(slot, &format!("MD5 mismatch: {error}"));
+                        return Ok(Some((slot, name.to_string())));
+                    }
+                }
+
+                finish_patcher(slot, "Patchers ok");
+                Ok(None)
+            }
+        })
+        .collect::<Vec<_>>();
+    let results = stream::iter(downloads)
+        .buffer_unordered(PATCHER_DOWNLOAD_CONCURRENCY)
+        .collect::<Vec<Result<Option<(usize, String)>, String>>>()
+        .await;
+
+    // 完了順ではなくマニフェスト順でスキップ一覧を返す。
+    let mut skipped_with_slot = Vec::new();
+    for result in results {
+        if let Some(entry) = result? {
+            skipped_with_slot.push(entry);
+        }
     }
+    skipped_with_slot.sort_by_key(|(slot, _)| *slot);
+    let skipped = skipped_with_slot
+        .into_iter()
+        .map(|(_, name)| name)
+        .collect::<Vec<_>>();
 
     if skipped.is_empty() {
         emit_progress(
@@ -551,6 +1027,104 @@ fn resolve_asset<'a>(
         })
 }
 
+fn format_size_mb(bytes: u64) -> String {
+    format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+fn check_disk_space(required_bytes: u64, available_bytes: u64) -> Result<(), String> {
+    if available_bytes < required_bytes {
+        return Err(format!(
+            "Not enough disk space: need ~{}, have {}",
+            format_size_mb(required_bytes),
+            format_size_mb(available_bytes)
+        ));
+    }
+    Ok(())
+}
+
+/// 指定パスを含むドライブの空き容量。未作成のパスは存在する親ディレクトリで調べる。
+#[cfg(windows)]
+fn available_disk_space(path: &Path) -> Option<u64> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows::core::PCWSTR;
+    use windows::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+    let existing = path.ancestors().find(|ancestor| ancestor.is_dir())?;
+    let wide = existing
+        .as_os_str()
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect::<Vec<u16>>();
+    let mut available = 0_u64;
+    // 安全性: wide はNUL終端済みで、呼び出し中は所有バッファが生存している。
+    unsafe {
+        GetDiskFreeSpaceExW(
+            PCWSTR(wide.as_ptr()),
+            Some(&mut available as *mut u64),
+            None,
+            None,
+        )
+    }
+    .ok()
+    .map(|_| available)
+}
+
+#[cfg(not(windows))]
+fn available_disk_space(_path: &Path) -> Option<u64> {
+    None
+}
+
+/// 2つのパスが同じドライブ上にあるかを、先頭のパス要素（Windows ではドライブ文字など）で判定する。
+fn same_volume(a: &Path, b: &Path) -> bool {
+    match (a.components().next(), b.components().next()) {
+        (Some(Component::Prefix(a)), Some(Component::Prefix(b))) => {
+            a.as_os_str().eq_ignore_ascii_case(b.as_os_str())
+        }
+        (a, b) => a == b,
+    }
+}
+
+fn ensure_free_space(path: &Path, required_bytes: u64) -> Result<(), String> {
+    if required_bytes == 0 {
+        return Ok(());
+    }
+    let Some(available) = available_disk_space(path) else {
+        log::info!(
+            "Could not determine free disk space for '{}'; skipping the check",
+            path.display()
+        );
+        return Ok(());
+    };
+    check_disk_space(required_bytes, available)
+}
+
+/// 展開途中で容量不足になって作業用ディレクトリが壊れないよう、事前に空き容量を確認する。
+/// ダウンロード先のキャッシュとプロファイルは別ドライブの場合があるため、それぞれで確認する。
+/// 検証済みキャッシュを再利用する場合はダウンロード分を見込まない。
+/// アセットサイズや空き容量が取得できない場合は確認を省略する。
+/// 失敗の通知は `finish_install` に任せ、ここでは failed を送らない。
+fn ensure_sufficient_disk_space(
+    profile_path: &Path,
+    cache_zip: &Path,
+    asset_size: u64,
+    reuse_cached_download: bool,
+) -> Result<(), String> {
+    if asset_size == 0 {
+        return Ok(());
+    }
+    let profile_required = asset_size.saturating_mul(INSTALL_PROFILE_DISK_SPACE_FACTOR);
+    let download_required = if reuse_cached_download { 0 } else { asset_size };
+    if same_volume(profile_path, cache_zip) {
+        ensure_free_space(
+            profile_path,
+            profile_required.saturating_add(download_required),
+        )
+    } else {
+        ensure_free_space(cache_zip, download_required)
+            .and_then(|_| ensure_free_space(profile_path, profile_required))
+    }
+}
+
 fn make_profile_paths(profile_path: &Path) -> Result<(PathBuf, PathBuf), String> {
     let parent = profile_path
         .parent()
@@ -662,6 +1236,11 @@ fn source_save_data_path_from_among_us(among_us_path: &Path) -> PathBuf {
     among_us_path.join(save_data_root())
 }
 
+/// mod設定(paths.saveDataRoot)で解決したプロファイル内SaveDataの場所。
+pub fn save_data_path_in_profile(profile_path: &Path) -> PathBuf {
+    profile_path.join(save_data_root())
+}
+
 fn resolve_source_save_data_path(source_among_us_path: &str) -> Result<(PathBuf, PathBuf), String> {
     let among_us_path = validate_source_among_us_path(source_among_us_path)?;
     let source_save_data_path = source_save_data_path_from_among_us(&among_us_path);
@@ -680,7 +1259,7 @@ fn source_bepinex_config_path(source_among_us_path: &Path) -> PathBuf {
 }
 
 fn profile_save_data_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
-    let mut launcher_settings = settings::load_or_init_settings(app)?;
+    let mut launcher_settings = settings::load_or_init_global_settings(app)?;
     if launcher_settings.profile_path.trim().is_empty() {
         launcher_settings.profile_path = settings::default_profile_path(app)?
             .to_string_lossy()
@@ -688,10 +1267,16 @@ fn profile_save_data_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, Str
         settings::save_settings(app, &launcher_settings)?;
     }
 
-    Ok(PathBuf::from(launcher_settings.profile_path.trim()).join(save_data_root()))
+    Ok(save_data_path_in_profile(Path::new(
+        launcher_settings.profile_path.trim(),
+    )))
 }
 
-fn copy_directory_recursive(source: &Path, destination: &Path) -> Result<(), String> {
+fn copy_directory_recursive(
+    source: &Path,
+    destination: &Path,
+    cancel_requested: Option<&AtomicBool>,
+) -> Result<(), String> {
     if !source.is_dir() {
         return Err(format!(
             "Source directory does not exist for SaveData import: {}",
@@ -709,6 +1294,10 @@ fn copy_directory_recursive(source: &Path, destination: &Path) -> Result<(), Str
     let mut files = Vec::new();
     collect_files_recursive(source, &mut files)?;
     for source_file in files {
+        if cancel_requested.is_some_and(|flag| flag.load(Ordering::SeqCst)) {
+            return Err(SAVE_DATA_IMPORT_CANCELLED_ERROR.to_string());
+        }
+
         let relative = source_file.strip_prefix(source).map_err(|_| {
             format!(
                 "Failed to compute relative path during SaveData import copy: '{}' (source root '{}')",
@@ -744,11 +1333,69 @@ fn clear_preserved_save_data<R: Runtime>(app: &AppHandle<R>) -> Result<(), Strin
     clean_path(&preserved_path)
 }
 
+fn profile_bepinex_config_path(profile_path: &Path) -> PathBuf {
+    profile_path.join("BepInEx").join("config")
+}
+
+/// プロファイルの BepInEx/config 配下のファイルを、プロファイル相対パス付きで列挙する。
+fn collect_bepinex_config_files(profile_path: &Path) -> Result<Vec<(PathBuf, String)>, String> {
+    let config_path = profile_bepinex_config_path(profile_path);
+    if !config_path.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut files = Vec::new();
+    collect_files_recursive(&config_path, &mut files)?;
+    files
+        .into_iter()
+        .map(|path| {
+            let relative = path
+                .strip_prefix(profile_path)
+                .map_err(|_| {
+                    format!(
+                        "Failed to make profile-relative path from '{}'",
+                        path.display()
+                    )
+                })?
+                .to_string_lossy()
+                .replace('\\', "/");
+            Ok((path, relative))
+        })
+        .collect()
+}
+
+/// 退避対象のファイル一覧。設定で有効な場合は BepInEx/config も含める。
+fn collect_preserved_profile_files(
+    profile_path: &Path,
+    include_bepinex_config: bool,
+) -> Result<Vec<(PathBuf, String)>, String> {
+    let mut files = migration::collect_supported_profile_save_files(profile_path)?;
+    if include_bepinex_config {
+        files.extend(collect_bepinex_config_files(profile_path)?);
+    }
+    Ok(files)
+}
+
+/// 更新前プロファイルの BepInEx/config をステージングへ上書きコピーし、利用者の調整を引き継ぐ。
+fn carry_over_bepinex_config(profile_path: &Path, staging_path: &Path) -> Result<usize, String> {
+    let files = collect_bepinex_config_files(profile_path)?;
+    if !files.is_empty() {
+        copy_directory_recursive(
+            &profile_bepinex_config_path(profile_path),
+            &profile_bepinex_config_path(staging_path),
+            None,
+        )?;
+    }
+    Ok(files.len())
+}
+
 fn preserve_profile_save_data<R: Runtime>(
     app: &AppHandle<R>,
     profile_path: &Path,
 ) -> Result<usize, String> {
-    let files = migration::collect_supported_profile_save_files(profile_path)?;
+    let include_bepinex_config =
+        settings::load_or_init_global_settings(app)?.preserve_bepinex_config;
+    let files = collect_preserved_profile_files(profile_path, include_bepinex_config)?;
     let file_count = files.len();
     let preserved_path = preserved_save_data_path(app)?;
 
@@ -792,11 +1439,26 @@ fn preserve_profile_save_data<R: Runtime>(
     Ok(file_count)
 }
 
+fn normalize_restore_relative_path(path: &str) -> String {
+    path.trim().replace('\\', "/").trim_matches('/').to_string()
+}
+
 fn restore_preserved_save_data_into_profile<R: Runtime>(
     app: &AppHandle<R>,
     profile_path: &Path,
+    restore_paths: Option<&[String]>,
 ) -> Result<usize, String> {
     let preserved_path = preserved_save_data_path(app)?;
+    restore_preserved_files(&preserved_path, profile_path, restore_paths)
+}
+
+/// 保持済みセーブデータをプロファイルへ戻す。
+/// `restore_paths` 指定時は、その相対パスに一致するファイルだけを復元する。
+fn restore_preserved_files(
+    preserved_path: &Path,
+    profile_path: &Path,
+    restore_paths: Option<&[String]>,
+) -> Result<usize, String> {
     if !preserved_path.exists() {
         return Ok(0);
     }
@@ -809,10 +1471,18 @@ fn restore_preserved_save_data_into_profile<R: Runtime>(
     }
 
     let mut files = Vec::new();
-    collect_files_recursive(&preserved_path, &mut files)?;
+    collect_files_recursive(preserved_path, &mut files)?;
+
+    let restore_filter = restore_paths.map(|paths| {
+        paths
+            .iter()
+            .map(|path| normalize_restore_relative_path(path))
+            .collect::<Vec<_>>()
+    });
+    let mut restored = 0;
 
     for source_path in &files {
-        let relative = source_path.strip_prefix(&preserved_path).map_err(|_| {
+        let relative = source_path.strip_prefix(preserved_path).map_err(|_| {
             format!(
                 "Internal path error while restoring preserved save data: '{}' is not under '{}'.",
                 source_path.display(),
@@ -820,6 +1490,12 @@ fn restore_preserved_save_data_into_profile<R: Runtime>(
             )
         })?;
         validate_relative_path(relative)?;
+        if let Some(filter) = &restore_filter {
+            let normalized = normalize_restore_relative_path(&relative.to_string_lossy());
+            if !filter.contains(&normalized) {
+                continue;
+            }
+        }
 
         let destination = profile_path.join(relative);
         if let Some(parent) = destination.parent() {
@@ -838,9 +1514,390 @@ fn restore_preserved_save_data_into_profile<R: Runtime>(
                 destination.display()
             )
         })?;
+        restored += 1;
     }
 
-    Ok(files.len())
+    Ok(restored)
+}
+
+fn path_size_bytes(path: &Path) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+
+    let mut files = Vec::new();
+    if collect_files_recursive(path, &mut files).is_err() {
+        return 0;
+    }
+    files
+        .iter()
+        .filter_map(|file| fs::metadata(file).ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+fn latest_modified_time(path: &Path) -> SystemTime {
+    let mut files = Vec::new();
+    let _ = collect_files_recursive(path, &mut files);
+    files
+        .iter()
+        .filter_map(|file| fs::metadata(file).and_then(|m| m.modified()).ok())
+        .max()
+        .unwrap_or(UNIX_EPOCH)
+}
+
+/// タグ単位のキャッシュを最終更新の古い順に削除し、合計サイズを上限以下に収める。
+/// 直前に使った `keep` のキャッシュは上限を超えていても残す。
+fn evict_cache_to_cap(cache_root: &Path, max_bytes: u64, keep: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(cache_root) else {
+        return Vec::new();
+    };
+    let mut tag_dirs: Vec<(PathBuf, u64, SystemTime)> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .map(|path| {
+            let size = path_size_bytes(&path);
+            let modified = latest_modified_time(&path);
+            (path, size, modified)
+        })
+        .collect();
+
+    let mut total: u64 = tag_dirs.iter().map(|(_, size, _)| size).sum();
+    tag_dirs.sort_by_key(|(_, _, modified)| *modified);
+
+    let mut evicted = Vec::new();
+    for (path, size, _) in tag_dirs {
+        if total <= max_bytes {
+            break;
+        }
+        if path == keep {
+            continue;
+        }
+        match fs::remove_dir_all(&path) {
+            Ok(()) => {
+                total = total.saturating_sub(size);
+                evicted.push(path);
+            }
+            Err(error) => log::warn!(
+                "Failed to evict cached release '{}': {error}",
+                path.display()
+            ),
+        }
+    }
+    evicted
+}
+
+fn cleanup_orphaned_profile_dirs(profile_path: &Path) -> Result<OrphanCleanupResult, String> {
+    let (staging_path, backup_path) = make_profile_paths(profile_path)?;
+    let mut result = OrphanCleanupResult::default();
+
+    // 昇格途中で中断した場合はバックアップにしか旧プロファイルが残らないため、削除せず戻す。
+    if !profile_path.exists() && backup_path.is_dir() {
+        fs::rename(&backup_path, profile_path).map_err(|e| {
+            format!(
+                "Failed to restore profile from orphaned backup ('{}' -> '{}'): {e}",
+                backup_path.display(),
+                profile_path.display()
+            )
+        })?;
+        result.restored_profile_from_backup = true;
+        log::warn!(
+            "Restored profile '{}' from the backup left by an interrupted install",
+            profile_path.display()
+        );
+    }
+
+    for orphan in [&staging_path, &backup_path] {
+        if !orphan.exists() {
+            continue;
+        }
+        let size = path_size_bytes(orphan);
+        clean_path(orphan)?;
+        result.reclaimed_bytes += size;
+        result
+            .removed_paths
+            .push(orphan.to_string_lossy().to_string());
+    }
+
+    Ok(result)
+}
+
+/// 中断したインストールが残した _staging/_backup ディレクトリを検出して削除する。
+pub fn cleanup_orphaned_install_dirs<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<OrphanCleanupResult, String> {
+    // 実行中インストールの作業ディレクトリを消さないよう、同じガードで排他する。
+    let _install_guard = InstallGuard::acquire()?;
+    let launcher_settings = settings::load_or_init_global_settings(app)?;
+    let profile_path = launcher_settings.profile_path.trim();
+    if profile_path.is_empty() {
+        return Ok(OrphanCleanupResult::default());
+    }
+    cleanup_orphaned_profile_dirs(Path::new(profile_path))
+}
+
+fn child_paths(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+        .unwrap_or_default()
+}
+
+fn artifact_modified_time(path: &Path) -> Option<SystemTime> {
+    // ディレクトリは中身の最終更新を優先し、空なら自身の更新時刻を使う。
+    let latest = if path.is_dir() {
+        Some(latest_modified_time(path)).filter(|modified| *modified > UNIX_EPOCH)
+    } else {
+        None
+    };
+    latest.or_else(|| fs::metadata(path).and_then(|m| m.modified()).ok())
+}
+
+/// 各サービスが作成する一時ファイル・作業用ディレクトリを列挙する。
+/// `kept_cache_tags` のキャッシュは再インストールや再試行で再利用するため削除対象にしない。
+fn collect_managed_artifacts(
+    app_data_dir: &Path,
+    profile_path: &Path,
+    active: &[OperationKind],
+    kept_cache_tags: &[String],
+) -> Result<Vec<ManagedArtifact>, String> {
+    let mut candidates = Vec::new();
+    let (staging_path, backup_path) = make_profile_paths(profile_path)?;
+    candidates.push((ManagedArtifactKind::ProfileStaging, staging_path));
+    candidates.push((ManagedArtifactKind::ProfileBackup, backup_path));
+    if let Some(save_data_parent) = save_data_path_in_profile(profile_path).parent() {
+        candidates.push((
+            ManagedArtifactKind::SaveDataImportStaging,
+            save_data_parent.join(SAVE_DATA_STAGING_DIR_NAME),
+        ));
+        candidates.push((
+            ManagedArtifactKind::SaveDataImportBackup,
+            save_data_parent.join(SAVE_DATA_BACKUP_DIR_NAME),
+        ));
+    }
+    for path in child_paths(&app_data_dir.join(launch_service::ELEVATED_LAUNCH_DIR_NAME)) {
+        candidates.push((ManagedArtifactKind::ElevatedLaunch, path));
+    }
+    for path in child_paths(&app_data_dir.join(bootstrap::MIGRATION_BACKUPS_DIR_NAME)) {
+        candidates.push((ManagedArtifactKind::MigrationImportBackup, path));
+    }
+    let cache_root = app_data_dir
+        .join(bootstrap::CACHE_DIR_NAME)
+        .join(mod_profile::get().mod_info.id.as_str());
+    for path in child_paths(&cache_root) {
+        candidates.push((ManagedArtifactKind::ReleaseCache, path));
+    }
+
+    // 昇格起動のファイル名は作成したプロセスのPIDで始まるため、自プロセス分は使用中とみなす。
+    let own_elevated_prefix = format!("{}-", std::process::id());
+    let now = SystemTime::now();
+    Ok(candidates
+        .into_iter()
+        .filter(|(_, path)| path.exists())
+        .map(|(kind, path)| {
+            let in_use = match kind.owner_operation() {
+                Some(owner) => active.contains(&owner),
+                None => path
+                    .file_name()
+                    .is_some_and(|name| name.to_string_lossy().starts_with(&own_elevated_prefix)),
+            };
+            // 巻き戻しに失敗した取り込みバックアップは利用者の旧データなので自動削除しない。
+            let kept = match kind {
+                ManagedArtifactKind::MigrationImportBackup => true,
+                ManagedArtifactKind::ReleaseCache => path.file_name().is_some_and(|name| {
+                    kept_cache_tags
+                        .iter()
+                        .any(|tag| name.to_string_lossy() == tag.as_str())
+                }),
+                _ => false,
+            };
+            let safe_to_delete = !in_use && !kept;
+            let modified = artifact_modified_time(&path);
+            ManagedArtifact {
+                kind,
+                size_bytes: path_size_bytes(&path),
+                modified_at: modified
+                    .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+                    .map(|duration| duration.as_secs()),
+                age_seconds: modified
+                    .and_then(|modified| now.duration_since(modified).ok())
+                    .map(|duration| duration.as_secs()),
+                path: path.to_string_lossy().to_string(),
+                in_use,
+                safe_to_delete,
+            }
+        })
+        .collect())
+}
+
+fn managed_artifacts_for_app<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<(PathBuf, Vec<ManagedArtifact>), String> {
+    let launcher_settings = settings::load_or_init_global_settings(app)?;
+    let profile_path = install_profile_path(app, &launcher_settings)?;
+    let active = active_operations()
+        .into_iter()
+        .map(|operation| operation.kind)
+        .collect::<Vec<_>>();
+    // 導入済みのタグと選択中のタグのキャッシュは残す。
+    let mut kept_cache_tags = vec![launcher_settings.selected_release_tag.clone()];
+    if let Some(manifest) = read_install_manifest(&profile_path) {
+        kept_cache_tags.push(manifest.tag);
+    }
+    kept_cache_tags.retain(|tag| !tag.trim().is_empty());
+    let artifacts = collect_managed_artifacts(
+        &settings::app_data_dir(app)?,
+        &profile_path,
+        &active,
+        &kept_cache_tags,
+    )?;
+    Ok((profile_path, artifacts))
+}
+
+/// ランチャーが管理する一時ファイル・作業用ディレクトリをサイズと経過時間付きで返す。
+pub fn list_managed_artifacts<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<Vec<ManagedArtifact>, String> {
+    managed_artifacts_for_app(app).map(|(_, artifacts)| artifacts)
+}
+
+/// 削除して問題ない一時ファイル・作業用ディレクトリをまとめて削除する。
+/// 実行中の処理が使うものは残し、プロファイルが失われている場合はバックアップから復元する。
+pub fn cleanup_managed_artifacts<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<ManagedArtifactsCleanupResult, String> {
+    // インストールの作業領域は、ガードを取得できた場合のみ触る。
+    let install_guard = InstallGuard::acquire().ok();
+    let (profile_path, artifacts) = managed_artifacts_for_app(app)?;
+    let mut result = ManagedArtifactsCleanupResult::default();
+    let mut has_profile_dirs = false;
+
+    for artifact in artifacts {
+        let needs_install_guard = artifact.kind.owner_operation() == Some(OperationKind::Install);
+        if !artifact.safe_to_delete || (needs_install_guard && install_guard.is_none()) {
+            result.skipped_paths.push(artifact.path);
+            continue;
+        }
+        if matches!(
+            artifact.kind,
+            ManagedArtifactKind::ProfileStaging | ManagedArtifactKind::ProfileBackup
+        ) {
+            has_profile_dirs = true;
+            continue;
+        }
+        match clean_path(Path::new(&artifact.path)) {
+            Ok(()) => {
+                result.reclaimed_bytes += artifact.size_bytes;
+                result.removed_paths.push(artifact.path);
+            }
+            Err(error) => {
+                log::warn!("Failed to remove managed artifact: {error}");
+                result.skipped_paths.push(artifact.path);
+            }
+        }
+    }
+
+    // バックアップしか残っていない場合の復元は、孤立ディレクトリの片付けと同じ処理に任せる。
+    if has_profile_dirs {
+        let orphans = cleanup_orphaned_profile_dirs(&profile_path)?;
+        result.reclaimed_bytes += orphans.reclaimed_bytes;
+        result.removed_paths.extend(orphans.removed_paths);
+        result.restored_profile_from_backup = orphans.restored_profile_from_backup;
+    }
+
+    Ok(result)
+}
+
+fn orphan_trash_prefix(profile_path: &Path) -> Option<String> {
+    let base_name = profile_path.file_name()?.to_string_lossy().to_string();
+    Some(format!("{base_name}{ORPHAN_TRASH_SUFFIX}"))
+}
+
+/// 残骸を削除待ちの名前へ移し、削除対象のパスを返す。前回消し切れなかった削除待ちも含める。
+fn detach_orphaned_profile_dirs(profile_path: &Path) -> Result<Vec<PathBuf>, String> {
+    let (staging_path, backup_path) = make_profile_paths(profile_path)?;
+    if !profile_path.exists() && backup_path.is_dir() {
+        fs::rename(&backup_path, profile_path).map_err(|e| {
+            format!(
+                "Failed to restore profile from orphaned backup ('{}' -> '{}'): {e}",
+                backup_path.display(),
+                profile_path.display()
+            )
+        })?;
+        log::warn!(
+            "Restored profile '{}' from the backup left by an interrupted install",
+            profile_path.display()
+        );
+    }
+
+    let (Some(parent), Some(trash_prefix)) =
+        (profile_path.parent(), orphan_trash_prefix(profile_path))
+    else {
+        return Ok(Vec::new());
+    };
+    let mut trash_paths = child_paths(parent)
+        .into_iter()
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with(&trash_prefix))
+        })
+        .collect::<Vec<_>>();
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    for (index, orphan) in [&staging_path, &backup_path].into_iter().enumerate() {
+        if !orphan.exists() {
+            continue;
+        }
+        let trash_path = parent.join(format!("{trash_prefix}-{millis}-{index}"));
+        fs::rename(orphan, &trash_path).map_err(|e| {
+            format!(
+                "Failed to move orphaned directory '{}' aside: {e}",
+                orphan.display()
+            )
+        })?;
+        trash_paths.push(trash_path);
+    }
+    Ok(trash_paths)
+}
+
+/// 起動時に、前回中断したインストールの残骸を片付ける。
+/// 起動直後のインストールを妨げないよう、ガードは残骸を改名する間だけ保持して削除はガード外で行う。
+/// インストールが既に始まっている場合は何もしない（インストール側で作業用ディレクトリを作り直す）。
+pub fn cleanup_orphaned_install_dirs_on_startup<R: Runtime>(app: &AppHandle<R>) {
+    let profile_path = match settings::load_or_init_global_settings(app) {
+        Ok(launcher_settings) if !launcher_settings.profile_path.trim().is_empty() => {
+            PathBuf::from(launcher_settings.profile_path.trim())
+        }
+        Ok(_) => return,
+        Err(error) => {
+            log::warn!("Failed to load settings for orphan cleanup: {error}");
+            return;
+        }
+    };
+
+    let trash_paths = {
+        let Ok(_install_guard) = InstallGuard::acquire() else {
+            log::info!("Skipping orphaned install cleanup because an install is in progress");
+            return;
+        };
+        match detach_orphaned_profile_dirs(&profile_path) {
+            Ok(trash_paths) => trash_paths,
+            Err(error) => {
+                log::warn!("Failed to clean orphaned install directories: {error}");
+                return;
+            }
+        }
+    };
+    for path in trash_paths {
+        if let Err(error) = clean_path(&path) {
+            log::warn!("Failed to clean orphaned install directories: {error}");
+        }
+    }
 }
 
 fn promote_staging_to_profile(staging: &Path, profile: &Path, backup: &Path) -> Result<(), String> {
@@ -875,7 +1932,64 @@ fn promote_staging_to_profile(staging: &Path, profile: &Path, backup: &Path) ->
     }
 }
 
+/// GitHub APIの残りリクエスト数とリセット時刻を取得する。
+/// rate_limit エンドポイント自体は消費枠にカウントされない。
+pub async fn github_rate_limit_status() -> Result<GitHubRateLimitStatus, String> {
+    let response = download::github_client()?
+        .get(mod_profile::github_rate_limit_api_url())
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch GitHub rate limit: {e}"))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Failed to fetch GitHub rate limit: status {}",
+            response.status()
+        ));
+    }
+
+    let payload = response
+        .json::<GitHubRateLimitPayload>()
+        .await
+        .map_err(|e| format!("Failed to parse GitHub rate limit: {e}"))?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    Ok(GitHubRateLimitStatus {
+        limit: payload.rate.limit,
+        remaining: payload.rate.remaining,
+        reset_at: payload.rate.reset,
+        resets_in_seconds: payload.rate.reset.saturating_sub(now),
+    })
+}
+
+/// 保持しているリリース一覧を破棄し、次回の取得で GitHub から取り直させる。
+pub fn invalidate_release_list_cache() {
+    if let Ok(mut guard) = RELEASE_LIST_CACHE.lock() {
+        *guard = None;
+    }
+}
+
+/// 利用可能なリリース一覧を返す。取得結果は一定時間メモリへキャッシュする。
 pub async fn list_snr_releases() -> Result<Vec<SnrReleaseSummary>, String> {
+    if let Ok(guard) = RELEASE_LIST_CACHE.lock() {
+        if let Some((fetched_at, releases)) = guard.as_ref() {
+            if fetched_at.elapsed() < RELEASE_LIST_CACHE_TTL {
+                return Ok(releases.clone());
+            }
+        }
+    }
+
+    let releases = fetch_snr_releases().await?;
+    if let Ok(mut guard) = RELEASE_LIST_CACHE.lock() {
+        *guard = Some((Instant::now(), releases.clone()));
+    }
+    Ok(releases)
+}
+
+async fn fetch_snr_releases() -> Result<Vec<SnrReleaseSummary>, String> {
     let client = download::github_client()?;
     let steam_regex =
         Regex::new(&mod_profile::get().distribution.asset_regex.steam).map_err(|e| {
@@ -968,6 +2082,50 @@ pub fn get_preserved_save_data_status<R: Runtime>(
     })
 }
 
+/// 保持済みセーブデータの各ファイルをサイズ・更新日時付きで一覧する。
+pub fn get_preserved_save_data_details<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<PreservedSaveDataDetails, String> {
+    let preserved_path = preserved_save_data_path(&app)?;
+    if !preserved_path.is_dir() {
+        return Ok(PreservedSaveDataDetails {
+            files: Vec::new(),
+            total_size: 0,
+        });
+    }
+
+    let mut paths = Vec::new();
+    collect_files_recursive(&preserved_path, &mut paths)?;
+
+    let mut files = paths
+        .iter()
+        .map(|path| {
+            let metadata = fs::metadata(path)
+                .map_err(|e| format!("Failed to read metadata '{}': {e}", path.display()))?;
+            let relative = path
+                .strip_prefix(&preserved_path)
+                .unwrap_or(path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            Ok(PreservedSaveDataFile {
+                path: relative,
+                size: metadata.len(),
+                modified_at: metadata
+                    .modified()
+                    .ok()
+                    .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+                    .map(|elapsed| elapsed.as_secs()),
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    files.sort_by(|left, right| left.path.cmp(&right.path));
+
+    Ok(PreservedSaveDataDetails {
+        total_size: files.iter().map(|file| file.size).sum(),
+        files,
+    })
+}
+
 pub fn preview_savedata_from_among_us(
     source_among_us_path: String,
 ) -> Result<SaveDataPreviewResult, String> {
@@ -998,14 +2156,160 @@ pub fn preview_savedata_from_among_us(
     })
 }
 
+// どちらかに Options.data が無ければ形式が混在しないため互換とみなす。
+fn options_versions_compatible(source: Option<u8>, target: Option<u8>) -> bool {
+    match (source, target) {
+        (Some(source), Some(target)) => source == target,
+        _ => true,
+    }
+}
+
+/// 取り込み前に、取り込み元とプロファイルの Options.data 形式が一致するか検査する。
+pub fn check_savedata_compat<R: Runtime>(
+    app: &AppHandle<R>,
+    source_among_us_path: String,
+) -> Result<SaveDataCompatReport, String> {
+    let (_, source_save_data_path) = resolve_source_save_data_path(&source_among_us_path)?;
+    let target_save_data_path = profile_save_data_path(app)?;
+    let source_version = presets::options_data_version(&source_save_data_path)?;
+    // 取り込み先の Options.data が壊れていても上書きで復旧できるため、読めなければ不明として扱う。
+    let target_version = presets::options_data_version(&target_save_data_path)
+        .ok()
+        .flatten();
+
+    Ok(SaveDataCompatReport {
+        source_save_data_path: source_save_data_path.to_string_lossy().to_string(),
+        target_save_data_path: target_save_data_path.to_string_lossy().to_string(),
+        source_version,
+        target_version,
+        compatible: options_versions_compatible(source_version, target_version),
+    })
+}
+
+/// SaveData取り込みの開始を記録する。準備中に届いた中断要求も反映するよう、ここで要求をリセットする。
+fn begin_save_data_import() -> OperationGuard {
+    SAVE_DATA_IMPORT_CANCEL_REQUESTED.store(false, Ordering::SeqCst);
+    begin_operation(OperationKind::SaveDataImport)
+}
+
 pub fn import_savedata_from_among_us_into_profile<R: Runtime>(
     app: &AppHandle<R>,
     source_among_us_path: String,
 ) -> Result<SaveDataImportResult, String> {
+    let _operation = begin_save_data_import();
     let preview = preview_savedata_from_among_us(source_among_us_path)?;
     let source_save_data_path = PathBuf::from(&preview.source_save_data_path);
     let source_among_us_path = PathBuf::from(&preview.source_among_us_path);
     let source_bepinex_config_path = source_bepinex_config_path(&source_among_us_path);
+    import_save_data_tree_into_profile(
+        app,
+        &source_save_data_path,
+        &source_bepinex_config_path,
+        preview.file_count,
+        preview.presets.len(),
+    )
+}
+
+/// SaveDataを含むzipバックアップを展開し、Among Usフォルダからの取り込みと同じ手順で取り込む。
+pub fn import_savedata_from_archive_into_profile<R: Runtime>(
+    app: &AppHandle<R>,
+    archive_path: String,
+) -> Result<SaveDataImportResult, String> {
+    let _operation = begin_save_data_import();
+    let trimmed = archive_path.trim();
+    if trimmed.is_empty() {
+        return Err("SaveData archive path is required".to_string());
+    }
+    let archive_path = PathBuf::from(trimmed);
+    if !archive_path.is_file() {
+        return Err(format!(
+            "SaveData archive was not found: {}",
+            archive_path.display()
+        ));
+    }
+    zip::validate_zip(&archive_path)?;
+
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    let extract_root = std::env::temp_dir().join(format!(
+        "{}-{SAVE_DATA_ARCHIVE_EXTRACT_DIR_PREFIX}-{}-{millis}",
+        mod_profile::get().branding.identifier,
+        std::process::id()
+    ));
+    clean_path(&extract_root)?;
+
+    // 一時展開先は成功・失敗にかかわらず必ず片付ける。
+    let result = zip::extract_zip(&archive_path, &extract_root, |_, _| {}).and_then(|_| {
+        let (source_save_data_path, source_bepinex_config_path) =
+            locate_archive_save_data(&extract_root)?;
+        let mut files = Vec::new();
+        collect_files_recursive(&source_save_data_path, &mut files)?;
+        if source_bepinex_config_path.is_dir() {
+            collect_files_recursive(&source_bepinex_config_path, &mut files)?;
+        }
+        let preset_count = presets::list_presets_from_save_data_dir(&source_save_data_path)?.len();
+
+        import_save_data_tree_into_profile(
+            app,
+            &source_save_data_path,
+            &source_bepinex_config_path,
+            files.len(),
+            preset_count,
+        )
+    });
+    let _ = clean_path(&extract_root);
+
+    result.map(|mut imported| {
+        // 一時展開先ではなく、利用者が選んだアーカイブを取り込み元として返す。
+        imported.source_save_data_path = archive_path.to_string_lossy().to_string();
+        imported
+    })
+}
+
+/// 展開済みアーカイブからSaveDataフォルダとBepInEx設定フォルダの位置を特定する。
+/// プロファイルと同じ階層、SaveDataフォルダ単体、SaveDataの中身のみの3形式を受け付ける。
+fn locate_archive_save_data(extract_root: &Path) -> Result<(PathBuf, PathBuf), String> {
+    let mut base = extract_root.to_path_buf();
+
+    // フォルダごと圧縮されたアーカイブは、単一の最上位フォルダを1段だけ掘り下げる。
+    let entries = fs::read_dir(&base)
+        .map_err(|e| format!("Failed to read extracted SaveData archive: {e}"))?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .collect::<Vec<_>>();
+    if let [only] = entries.as_slice() {
+        if only.is_dir() {
+            base = only.clone();
+        }
+    }
+
+    let mut candidates = vec![base.join(save_data_root())];
+    if let Some(leaf) = save_data_root().file_name() {
+        candidates.push(base.join(leaf));
+    }
+    candidates.push(base.clone());
+
+    let save_data_path = candidates
+        .into_iter()
+        .find(|candidate| candidate.join(OPTIONS_DATA_FILE_NAME).is_file())
+        .ok_or_else(|| {
+            format!(
+                "The archive does not contain a SaveData folder ({OPTIONS_DATA_FILE_NAME} was not found)."
+            )
+        })?;
+
+    Ok((save_data_path, source_bepinex_config_path(&base)))
+}
+
+fn import_save_data_tree_into_profile<R: Runtime>(
+    app: &AppHandle<R>,
+    source_save_data_path: &Path,
+    source_bepinex_config_path: &Path,
+    file_count: usize,
+    preset_count: usize,
+) -> Result<SaveDataImportResult, String> {
     let target_save_data_path = profile_save_data_path(app)?;
 
     let target_parent = target_save_data_path.parent().ok_or_else(|| {
@@ -1026,8 +2330,14 @@ pub fn import_savedata_from_among_us_into_profile<R: Runtime>(
     clean_path(&staging_path)?;
     clean_path(&backup_path)?;
 
-    if let Err(error) = copy_directory_recursive(&source_save_data_path, &staging_path) {
+    // 中断はステージングへのコピー中のみ反映し、既存のSaveDataには触れない。
+    if let Err(error) = copy_directory_recursive(
+        source_save_data_path,
+        &staging_path,
+        Some(&SAVE_DATA_IMPORT_CANCEL_REQUESTED),
+    ) {
         let _ = clean_path(&staging_path);
+        let _ = clean_path(&backup_path);
         return Err(error);
     }
 
@@ -1040,17 +2350,26 @@ pub fn import_savedata_from_among_us_into_profile<R: Runtime>(
     }
     if source_bepinex_config_path.is_dir() {
         let target_bepinex_config_path = target_parent.join("BepInEx").join("config");
-        copy_directory_recursive(&source_bepinex_config_path, &target_bepinex_config_path)?;
+        copy_directory_recursive(
+            source_bepinex_config_path,
+            &target_bepinex_config_path,
+            None,
+        )?;
     }
 
     Ok(SaveDataImportResult {
         source_save_data_path: source_save_data_path.to_string_lossy().to_string(),
         target_save_data_path: target_save_data_path.to_string_lossy().to_string(),
-        imported_files: preview.file_count,
-        imported_presets: preview.presets.len(),
+        imported_files: file_count,
+        imported_presets: preset_count,
     })
 }
 
+/// 実行中のSaveData取り込みへ中断を要求する。
+pub fn request_savedata_import_cancel() {
+    SAVE_DATA_IMPORT_CANCEL_REQUESTED.store(true, Ordering::SeqCst);
+}
+
 pub fn merge_savedata_presets_from_among_us_into_profile<R: Runtime>(
     app: &AppHandle<R>,
     source_among_us_path: String,
@@ -1105,8 +2424,9 @@ pub fn merge_preserved_savedata_presets_into_profile<R: Runtime>(
 pub fn uninstall_snr_profile<R: Runtime>(
     app: AppHandle<R>,
     preserve_save_data: bool,
+    keep_cache: bool,
 ) -> Result<UninstallResult, String> {
-    let mut launcher_settings = settings::load_or_init_settings(&app)?;
+    let mut launcher_settings = settings::load_or_init_global_settings(&app)?;
     if launcher_settings.profile_path.trim().is_empty() {
         launcher_settings.profile_path = settings::default_profile_path(&app)?
             .to_string_lossy()
@@ -1133,33 +2453,292 @@ pub fn uninstall_snr_profile<R: Runtime>(
     fs::create_dir_all(&profile_path)
         .map_err(|e| format!("Failed to recreate profile directory after uninstall: {e}"))?;
 
+    // selected_release_tag は残すため、キャッシュを保持すれば同じタグの再インストールで再利用できる。
+    let selected_tag = launcher_settings.selected_release_tag.trim();
+    let reusable_cache = if selected_tag.is_empty() {
+        false
+    } else {
+        let cache_zip =
+            release_cache_zip_path(&app, selected_tag, &launcher_settings.game_platform)?;
+        if !keep_cache {
+            discard_cached_download(&cache_zip);
+        }
+        keep_cache && is_cached_download_valid(&cache_zip)
+    };
+
     Ok(UninstallResult {
         profile_path: profile_path.to_string_lossy().to_string(),
         removed_profile,
         preserved_files,
+        reusable_cache,
     })
 }
 
-pub async fn install_snr_release<R: Runtime>(
-    app: AppHandle<R>,
-    tag: String,
-    platform: String,
-    restore_preserved_save_data: Option<bool>,
-) -> Result<InstallResult, String> {
-    let platform = settings::GamePlatform::from_user_value(&platform)?;
-    let tag = tag.trim().to_string();
-    if tag.is_empty() {
-        return Err("Release tag is required".to_string());
-    }
-
+/// 選択中のタグと一致し、検証済みのダウンロードキャッシュが残っていれば true を返す。
+pub fn has_reusable_cached_download<R: Runtime>(
+    app: &AppHandle<R>,
+    tag: &str,
+    platform: &str,
+) -> bool {
+    let Ok(platform) = settings::GamePlatform::from_user_value(platform) else {
+        return false;
+    };
+    let Ok(launcher_settings) = settings::load_or_init_global_settings(app) else {
+        return false;
+    };
+    let tag = tag.trim();
+    if tag.is_empty() || launcher_settings.selected_release_tag.trim() != tag {
+        return false;
+    }
+    release_cache_zip_path(app, tag, &platform)
+        .map(|cache_zip| is_cached_download_valid(&cache_zip))
+        .unwrap_or(false)
+}
+
+fn preview_profile_uninstall(
+    profile_path: &Path,
+    preserve_save_data: bool,
+    include_bepinex_config: bool,
+) -> Result<UninstallPreview, String> {
+    let profile_exists = profile_path.exists();
+    let preserved_files = if preserve_save_data && profile_exists {
+        collect_preserved_profile_files(profile_path, include_bepinex_config)?.len()
+    } else {
+        0
+    };
+
+    Ok(UninstallPreview {
+        profile_path: profile_path.to_string_lossy().to_string(),
+        profile_exists,
+        preserved_files,
+        removed_bytes: path_size_bytes(profile_path),
+    })
+}
+
+/// 何も削除せず、アンインストール時に削除・保持される内容を返す。
+pub fn uninstall_preview<R: Runtime>(
+    app: &AppHandle<R>,
+    preserve_save_data: bool,
+) -> Result<UninstallPreview, String> {
+    let launcher_settings = settings::load_or_init_global_settings(app)?;
+    // プレビューでは設定を書き換えず、未設定時の既定パスを参照するだけに留める。
+    let profile_path = install_profile_path(app, &launcher_settings)?;
+    preview_profile_uninstall(
+        &profile_path,
+        preserve_save_data,
+        launcher_settings.preserve_bepinex_config,
+    )
+}
+
+/// BepInEx/config が更新・再インストールを越えて引き継がれるかを確認する。
+pub fn bepinex_config_preservation_status<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<BepInExConfigPreservationStatus, String> {
+    let launcher_settings = settings::load_or_init_global_settings(app)?;
+    let relative_paths = |files: Vec<(PathBuf, String)>| {
+        let mut paths = files
+            .into_iter()
+            .map(|(_, relative)| relative)
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths
+    };
+
+    let profile_path = PathBuf::from(launcher_settings.profile_path.trim());
+    let profile_files = if launcher_settings.profile_path.trim().is_empty() {
+        Vec::new()
+    } else {
+        relative_paths(collect_bepinex_config_files(&profile_path)?)
+    };
+    let preserved_files = relative_paths(collect_bepinex_config_files(&preserved_save_data_path(
+        app,
+    )?)?);
+
+    Ok(BepInExConfigPreservationStatus {
+        enabled: launcher_settings.preserve_bepinex_config,
+        profile_files,
+        preserved_files,
+    })
+}
+
+fn detect_game_version<R: Runtime>(app: &AppHandle<R>) -> Option<String> {
+    let launcher_settings = settings::load_or_init_settings(app).ok()?;
+    let among_us_path = launcher_settings.among_us_path.trim();
+    if among_us_path.is_empty() {
+        return None;
+    }
+    finder::read_among_us_version(Path::new(among_us_path))
+}
+
+// 記録の無い旧導入情報や検出失敗時は判断できないため、再同期を促さない。
+fn repatch_needed(detected: Option<&str>, recorded: Option<&str>) -> bool {
+    matches!((detected, recorded), (Some(detected), Some(recorded)) if detected != recorded)
+}
+
+/// 導入時と現在のゲームバージョンを比べ、パッチャーの再同期が必要かを返す。
+pub fn check_repatch_needed<R: Runtime>(app: &AppHandle<R>) -> Result<RepatchCheck, String> {
+    let launcher_settings = settings::load_or_init_settings(app)?;
+    let profile_path = PathBuf::from(launcher_settings.profile_path.trim());
+    let recorded_game_version = if launcher_settings.profile_path.trim().is_empty() {
+        None
+    } else {
+        read_install_manifest(&profile_path).and_then(|manifest| manifest.game_version)
+    };
+    let detected_game_version = detect_game_version(app);
+
+    Ok(RepatchCheck {
+        needed: mod_profile::get().distribution.patchers.enabled
+            && repatch_needed(
+                detected_game_version.as_deref(),
+                recorded_game_version.as_deref(),
+            ),
+        detected_game_version,
+        recorded_game_version,
+    })
+}
+
+/// 導入済みプロファイルのパッチャーを取り直し、現在のゲームバージョンを記録する。
+pub async fn resync_profile_patchers<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<PatcherResyncResult, String> {
+    if !mod_profile::get().distribution.patchers.enabled {
+        return Err("Patchers synchronization is disabled by mod.config.json".to_string());
+    }
+
+    let _install_guard = InstallGuard::acquire()?;
+    let _operation = begin_operation(OperationKind::Install);
+    let launcher_settings = settings::load_or_init_settings(&app)?;
+    let profile_path = PathBuf::from(launcher_settings.profile_path.trim());
+    let mut manifest = if launcher_settings.profile_path.trim().is_empty() {
+        None
+    } else {
+        read_install_manifest(&profile_path)
+    }
+    .ok_or_else(|| "Mod is not installed in the current profile".to_string())?;
+
+    // 取得失敗時に動作中のパッチャーを消さないよう、作業ディレクトリへ取得してから差し替える。
+    let (staging_path, _) = make_profile_paths(&profile_path)?;
+    clean_path(&staging_path)?;
+    let client = patcher_sync_client()?;
+    let download_result = download_patchers_into_staging(&app, &client, &staging_path, None).await;
+    let swap_result = download_result.and_then(|skipped| {
+        swap_in_resynced_patchers(&staging_path, &profile_path).map(|_| skipped)
+    });
+    if let Err(error) = clean_path(&staging_path) {
+        log::warn!("Failed to remove patcher resync staging: {error}");
+    }
+    let skipped = swap_result?;
+
+    // 一部でも更新できなかった場合は、同期済みと誤認しないよう記録済みの版を維持する。
+    if skipped.is_empty() {
+        manifest.game_version = detect_game_version(&app);
+        write_install_manifest(&profile_path, &manifest)?;
+    }
+
+    Ok(PatcherResyncResult {
+        skipped,
+        game_version: manifest.game_version,
+    })
+}
+
+/// 作業ディレクトリで取得・検証済みのパッチャーだけをプロファイルへ移す。
+fn swap_in_resynced_patchers(staging_path: &Path, profile_path: &Path) -> Result<usize, String> {
+    let staged_dir = staging_path.join("BepInEx").join("patchers");
+    if !staged_dir.is_dir() {
+        return Ok(0);
+    }
+    let patchers_dir = profile_path.join("BepInEx").join("patchers");
+    fs::create_dir_all(&patchers_dir).map_err(|e| {
+        format!(
+            "Failed to create patchers directory '{}': {e}",
+            patchers_dir.display()
+        )
+    })?;
+
+    let mut swapped = 0;
+    for source in child_paths(&staged_dir) {
+        if !source.is_file() {
+            continue;
+        }
+        let Some(name) = source.file_name() else {
+            continue;
+        };
+        let destination = patchers_dir.join(name);
+        fs::rename(&source, &destination)
+            .map_err(|e| format!("Failed to replace patcher '{}': {e}", destination.display()))?;
+        swapped += 1;
+    }
+    Ok(swapped)
+}
+
+/// 指定したzipアーカイブを展開せずに検証し、エントリ数を返す。
+pub fn validate_install_archive(archive_path: String) -> Result<usize, String> {
+    let archive_path = archive_path.trim();
+    if archive_path.is_empty() {
+        return Err("Archive path is required".to_string());
+    }
+    zip::validate_zip(Path::new(archive_path))
+}
+
+pub async fn install_snr_release<R: Runtime>(
+    app: AppHandle<R>,
+    tag: String,
+    platform: String,
+    restore_preserved_save_data: Option<bool>,
+    restore_paths: Option<Vec<String>>,
+    reuse_cached_download: bool,
+) -> Result<InstallResult, String> {
+    let platform = settings::GamePlatform::from_user_value(&platform)?;
+    let tag = tag.trim().to_string();
+    if tag.is_empty() {
+        return Err("Release tag is required".to_string());
+    }
+
     // 既存UI互換のため、未指定時は保持セーブデータを復元しない挙動を維持する。
     let restore_preserved_save_data = restore_preserved_save_data.unwrap_or(false);
 
-    let result =
-        install_snr_release_inner(&app, &tag, &platform, restore_preserved_save_data).await;
+    // 実行中のインストールの進捗表示を壊さないよう、拒否時は failed を通知しない。
+    let _install_guard = InstallGuard::acquire()?;
+    let _operation = begin_operation(OperationKind::Install);
+    let _polling_pause = background_notifications::pause_polling();
+    let cancel = InstallCancelToken::register(&tag);
+    let result = install_snr_release_inner(
+        &app,
+        &tag,
+        &platform,
+        restore_preserved_save_data,
+        restore_paths.as_deref(),
+        reuse_cached_download,
+        &cancel,
+    )
+    .await;
+    finish_install(&app, &cancel, result)
+}
+
+/// インストール結果に応じて cancelled / failed を通知する。
+fn finish_install<R: Runtime>(
+    app: &AppHandle<R>,
+    cancel: &InstallCancelToken,
+    result: Result<InstallResult, String>,
+) -> Result<InstallResult, String> {
+    // 中断時は各段階のエラー内容に関わらず、作業用ディレクトリを片付けて cancelled を通知する。
+    if result.is_err() && cancel.is_cancelled() {
+        cleanup_cancelled_install(app);
+        emit_progress(
+            app,
+            "cancelled",
+            0.0,
+            "Installation cancelled",
+            None,
+            None,
+            None,
+            None,
+        );
+        return Err(INSTALL_CANCELLED_ERROR.to_string());
+    }
     if let Err(ref error) = result {
         emit_progress(
-            &app,
+            app,
             "failed",
             0.0,
             format!("Installation failed: {error}"),
@@ -1172,25 +2751,188 @@ pub async fn install_snr_release<R: Runtime>(
     result
 }
 
-async fn install_snr_release_inner<R: Runtime>(
+/// ユーザーが選択したローカルのzipからインストールする。リリース取得とダウンロードは行わない。
+/// タグはファイル名から推定し、推定できなければ `local` として記録する。
+pub async fn install_snr_release_from_local_zip<R: Runtime>(
+    app: AppHandle<R>,
+    archive_path: String,
+    platform: String,
+    restore_preserved_save_data: Option<bool>,
+    skip_patchers: Option<bool>,
+) -> Result<InstallResult, String> {
+    let platform = settings::GamePlatform::from_user_value(&platform)?;
+    let archive_path = PathBuf::from(archive_path.trim());
+    if !archive_path.is_file() {
+        return Err(format!(
+            "Archive file was not found: {}",
+            archive_path.display()
+        ));
+    }
+    let tag = infer_local_archive_tag(&archive_path);
+    let restore_preserved_save_data = restore_preserved_save_data.unwrap_or(false);
+    let sync_patchers = !skip_patchers.unwrap_or(false);
+
+    let _install_guard = InstallGuard::acquire()?;
+    let _operation = begin_operation(OperationKind::Install);
+    let _polling_pause = background_notifications::pause_polling();
+    let cancel = InstallCancelToken::register(&tag);
+    let result = install_local_zip_inner(
+        &app,
+        &archive_path,
+        &tag,
+        &platform,
+        restore_preserved_save_data,
+        sync_patchers,
+        &cancel,
+    )
+    .await;
+    finish_install(&app, &cancel, result)
+}
+
+/// ファイル名に含まれるバージョン表記（例: `v1.2.3`）をタグとして取り出す。
+fn infer_local_archive_tag(archive_path: &Path) -> String {
+    let stem = archive_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    Regex::new(r"v?\d+(?:\.\d+)+")
+        .ok()
+        .and_then(|pattern| pattern.find(&stem).map(|found| found.as_str().to_string()))
+        .unwrap_or_else(|| LOCAL_ARCHIVE_TAG.to_string())
+}
+
+/// zip内に存在しない必須ファイルの相対パスを返す。
+fn missing_archive_required_files(entries: &[zip::ZipFileEntry]) -> Vec<String> {
+    let entry_paths = entries
+        .iter()
+        .map(|entry| entry.path.to_ascii_lowercase())
+        .collect::<HashSet<_>>();
+    mod_profile::get()
+        .paths
+        .profile_required_files
+        .iter()
+        .filter(|required| {
+            let normalized = required.replace('\\', "/").to_ascii_lowercase();
+            !entry_paths.contains(normalized.trim_start_matches("./"))
+        })
+        .cloned()
+        .collect()
+}
+
+async fn install_local_zip_inner<R: Runtime>(
     app: &AppHandle<R>,
+    archive_path: &Path,
     tag: &str,
     platform: &settings::GamePlatform,
     restore_preserved_save_data: bool,
+    sync_patchers: bool,
+    cancel: &InstallCancelToken,
 ) -> Result<InstallResult, String> {
     emit_progress(
         app,
         "resolving",
         0.0,
-        "Resolving release metadata...",
+        "Validating local archive...",
         None,
         None,
         None,
         None,
     );
+    zip::validate_zip(archive_path)
+        .map_err(|error| format!("The selected archive is not a valid zip file: {error}"))?;
+    let missing = missing_archive_required_files(&zip::list_zip_files(archive_path)?);
+    if !missing.is_empty() {
+        return Err(format!(
+            "The selected archive is missing required file(s): {}",
+            missing.join(", ")
+        ));
+    }
+    cancel.ensure_not_cancelled()?;
+
+    let mut launcher_settings = settings::load_or_init_global_settings(app)?;
+    let profile_path = install_profile_path(app, &launcher_settings)?;
+    let archive_size = fs::metadata(archive_path)
+        .map(|metadata| metadata.len())
+        .unwrap_or(0);
+    // 選択されたzipをそのまま展開するため、ダウンロード分は見込まない。
+    ensure_sufficient_disk_space(&profile_path, archive_path, archive_size, true)?;
+    if let Some(parent) = profile_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create profile parent directory: {e}"))?;
+    }
 
-    let client = download::github_client()?;
-    let release = client
+    let asset_name = archive_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let restored_save_files = install_from_archive(
+        app,
+        archive_path,
+        tag,
+        platform,
+        &asset_name,
+        &profile_path,
+        &mut launcher_settings,
+        restore_preserved_save_data,
+        None,
+        sync_patchers,
+        cancel,
+    )
+    .await?;
+
+    emit_progress(
+        app,
+        "complete",
+        100.0,
+        "Installation complete",
+        None,
+        None,
+        None,
+        None,
+    );
+
+    Ok(InstallResult {
+        tag: tag.to_string(),
+        platform: platform.as_str().to_string(),
+        asset_name,
+        profile_path: profile_path.to_string_lossy().to_string(),
+        restored_save_files,
+    })
+}
+
+fn cleanup_cancelled_install<R: Runtime>(app: &AppHandle<R>) {
+    let profile_path = match settings::load_or_init_global_settings(app)
+        .and_then(|launcher_settings| install_profile_path(app, &launcher_settings))
+    {
+        Ok(profile_path) => profile_path,
+        Err(error) => {
+            log::warn!("Failed to resolve profile path for cancelled install cleanup: {error}");
+            return;
+        }
+    };
+    // 差し替え開始後は中断を受け付けないため、ここで消すのは未使用の作業用ディレクトリのみ。
+    if let Ok((staging_path, backup_path)) = make_profile_paths(&profile_path) {
+        for path in [staging_path, backup_path] {
+            if let Err(error) = clean_path(&path) {
+                log::warn!("Failed to clean up after cancelled install: {error}");
+            }
+        }
+    }
+}
+
+/// インストール先のプロファイルパス。未設定時は既定のパスを使う。
+fn install_profile_path<R: Runtime>(
+    app: &AppHandle<R>,
+    launcher_settings: &settings::LauncherSettings,
+) -> Result<PathBuf, String> {
+    if launcher_settings.profile_path.trim().is_empty() {
+        return settings::default_profile_path(app);
+    }
+    Ok(PathBuf::from(&launcher_settings.profile_path))
+}
+
+async fn fetch_release_by_tag(client: &Client, tag: &str) -> Result<GitHubRelease, String> {
+    let response = client
         .get(format!(
             "{}/{}",
             mod_profile::github_release_by_tag_api_base_url(),
@@ -1200,39 +2942,61 @@ async fn install_snr_release_inner<R: Runtime>(
         .await
         .map_err(|e| format!("Failed to fetch release '{tag}': {e}"))?;
 
-    if !release.status().is_success() {
+    if !response.status().is_success() {
         return Err(format!(
             "Release '{}' was not found (status {})",
             tag,
-            release.status()
+            response.status()
         ));
     }
 
-    let release = release
+    response
         .json::<GitHubRelease>()
         .await
-        .map_err(|e| format!("Failed to parse release payload: {e}"))?;
-    let asset = resolve_asset(&release, platform)?;
-
-    let mut launcher_settings = settings::load_or_init_settings(app)?;
-    if launcher_settings.profile_path.trim().is_empty() {
-        launcher_settings.profile_path = settings::default_profile_path(app)?
-            .to_string_lossy()
-            .to_string();
-    }
-    let profile_path = PathBuf::from(&launcher_settings.profile_path);
-
-    if let Some(parent) = profile_path.parent() {
-        fs::create_dir_all(parent)
-            .map_err(|e| format!("Failed to create profile parent directory: {e}"))?;
-    }
+        .map_err(|e| format!("Failed to parse release payload: {e}"))
+}
 
-    let cache_zip = settings::app_data_dir(app)?
-        .join("cache")
+fn release_cache_zip_path<R: Runtime>(
+    app: &AppHandle<R>,
+    tag: &str,
+    platform: &settings::GamePlatform,
+) -> Result<PathBuf, String> {
+    Ok(settings::app_data_dir(app)?
+        .join(bootstrap::CACHE_DIR_NAME)
         .join(mod_profile::get().mod_info.id.as_str())
         .join(tag)
-        .join(format!("{}.zip", platform.as_str()));
+        .join(format!("{}.zip", platform.as_str())))
+}
 
+fn cache_checksum_path(cache_zip: &Path) -> PathBuf {
+    let mut file_name = cache_zip.as_os_str().to_os_string();
+    file_name.push(".sha256");
+    PathBuf::from(file_name)
+}
+
+fn discard_cached_download(cache_zip: &Path) {
+    let _ = fs::remove_file(cache_zip);
+    let _ = fs::remove_file(cache_checksum_path(cache_zip));
+}
+
+/// キャッシュzipがサイドカーに記録したSHA-256と一致するかを返す。
+/// サイドカーは取得直後のバイト列から計算するため、検出できるのは保存後の破損だけで、
+/// 取得内容そのものの正しさは `verify_release_asset_digest` で公開ダイジェストと照合する。
+fn is_cached_download_valid(cache_zip: &Path) -> bool {
+    let Ok(expected) = fs::read_to_string(cache_checksum_path(cache_zip)) else {
+        return false;
+    };
+    download::sha256_file(cache_zip)
+        .is_ok_and(|actual| actual.eq_ignore_ascii_case(expected.trim()))
+}
+
+async fn download_release_asset<R: Runtime>(
+    app: &AppHandle<R>,
+    client: &Client,
+    asset: &GitHubAsset,
+    cache_zip: &Path,
+    cancel_requested: Option<&AtomicBool>,
+) -> Result<(), String> {
     emit_progress(
         app,
         "downloading",
@@ -1244,10 +3008,11 @@ async fn install_snr_release_inner<R: Runtime>(
         None,
     );
 
-    download::download_file(
-        &client,
-        &asset.browser_download_url,
-        &cache_zip,
+    download_release_asset_to_cache(
+        client,
+        asset,
+        cache_zip,
+        cancel_requested,
         |downloaded, total| {
             let progress = total
                 .map(|total| (downloaded as f64 / total as f64) * 100.0)
@@ -1267,9 +3032,275 @@ async fn install_snr_release_inner<R: Runtime>(
             );
         },
     )
+    .await
+}
+
+/// リリースアセットをキャッシュへ取得する。インストールの進捗イベントは通知しない。
+async fn download_release_asset_to_cache<F>(
+    client: &Client,
+    asset: &GitHubAsset,
+    cache_zip: &Path,
+    cancel_requested: Option<&AtomicBool>,
+    on_progress: F,
+) -> Result<(), String>
+where
+    F: FnMut(u64, Option<u64>),
+{
+    download::download_file_cancellable(
+        client,
+        &asset.browser_download_url,
+        cache_zip,
+        cancel_requested,
+        on_progress,
+    )
     .await?;
 
-    let (staging_path, backup_path) = make_profile_paths(&profile_path)?;
+    // 後段で失敗した場合の再試行に備え、キャッシュの検証用チェックサムを保存する。
+    write_cache_checksum(cache_zip)
+}
+
+/// 公開されている sha256 ダイジェストと照合する。不一致ならキャッシュを破棄する。
+/// ダイジェストが公開されていないアセットは照合を省略する。
+fn verify_release_asset_digest(asset: &GitHubAsset, cache_zip: &Path) -> Result<(), String> {
+    match asset.digest.as_deref().and_then(sha256_from_asset_digest) {
+        Some(expected) => {
+            if let Err(error) = verify_sha256(cache_zip, expected) {
+                discard_cached_download(cache_zip);
+                return Err(format!(
+                    "{DOWNLOAD_CORRUPTED_ERROR_PREFIX} The downloaded archive does not match the published checksum. Please retry. ({error})"
+                ));
+            }
+        }
+        None => log::info!(
+            "Release asset '{}' has no published sha256 digest; skipping checksum verification",
+            asset.name
+        ),
+    }
+    Ok(())
+}
+
+/// 取得直後のキャッシュzipのSHA-256をサイドカーへ記録する。
+/// 取得元の検証ではなく、再試行で再利用するまでのローカルでの破損検出に使う。
+fn write_cache_checksum(cache_zip: &Path) -> Result<(), String> {
+    let checksum = download::sha256_file(cache_zip)?;
+    fs::write(cache_checksum_path(cache_zip), checksum)
+        .map_err(|e| format!("Failed to write download checksum: {e}"))
+}
+
+/// 導入済みファイルと更新先zipのファイル一覧を比較し、追加・削除・変更に振り分ける。
+fn diff_install_files(
+    installed: &[InstallManifestFile],
+    target: &[InstallManifestFile],
+) -> (Vec<String>, Vec<String>, Vec<String>, usize) {
+    let installed_by_path = installed
+        .iter()
+        .map(|file| (file.path.as_str(), file))
+        .collect::<HashMap<_, _>>();
+    let target_paths = target
+        .iter()
+        .map(|file| file.path.as_str())
+        .collect::<HashSet<_>>();
+
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+    let mut unchanged = 0;
+    for file in target {
+        match installed_by_path.get(file.path.as_str()) {
+            None => added.push(file.path.clone()),
+            Some(current) if current.crc32 != file.crc32 || current.size != file.size => {
+                changed.push(file.path.clone());
+            }
+            Some(_) => unchanged += 1,
+        }
+    }
+    let mut removed = installed
+        .iter()
+        .filter(|file| !target_paths.contains(file.path.as_str()))
+        .map(|file| file.path.clone())
+        .collect::<Vec<_>>();
+
+    added.sort();
+    removed.sort();
+    changed.sort();
+    (added, removed, changed, unchanged)
+}
+
+/// 指定リリースのzipをインストールせずに `output_path` へ保存する。
+/// 取得はダウンロードキャッシュを経由し、保存後にキャッシュのチェックサムと照合する。
+pub async fn download_release_to_path<R: Runtime>(
+    app: &AppHandle<R>,
+    tag: String,
+    platform: String,
+    output_path: String,
+) -> Result<ReleaseDownloadResult, String> {
+    let platform = settings::GamePlatform::from_user_value(&platform)?;
+    let tag = tag.trim().to_string();
+    if tag.is_empty() {
+        return Err("Release tag is required".to_string());
+    }
+    let output_path = PathBuf::from(output_path.trim());
+    if output_path.as_os_str().is_empty() {
+        return Err("Output path is required".to_string());
+    }
+    if output_path.is_dir() {
+        return Err(format!(
+            "Output path is a directory: {}",
+            output_path.display()
+        ));
+    }
+
+    // 同じキャッシュzipをインストールと同時に書き換えないよう、インストールと排他にする。
+    let _install_guard = InstallGuard::acquire()?;
+    // キャッシュを使う場合も公開ダイジェストと照合するため、アセット情報は常に取得する。
+    let client = download::github_client()?;
+    let release = fetch_release_by_tag(&client, &tag).await?;
+    let asset = resolve_asset(&release, &platform)?;
+    let cache_zip = release_cache_zip_path(app, &tag, &platform)?;
+    if is_cached_download_valid(&cache_zip) {
+        emit_progress(
+            app,
+            "downloading",
+            100.0,
+            format!("Using cached '{}'", asset.name),
+            None,
+            None,
+            None,
+            None,
+        );
+    } else {
+        download_release_asset(app, &client, asset, &cache_zip, None).await?;
+    }
+    verify_release_asset_digest(asset, &cache_zip)?;
+
+    if let Err(error) = zip::validate_zip(&cache_zip) {
+        discard_cached_download(&cache_zip);
+        return Err(format!(
+            "{DOWNLOAD_CORRUPTED_ERROR_PREFIX} The downloaded archive appears corrupted. Please retry. ({error})"
+        ));
+    }
+
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            format!(
+                "Failed to create output directory '{}': {e}",
+                parent.display()
+            )
+        })?;
+    }
+    let size = fs::copy(&cache_zip, &output_path).map_err(|e| {
+        format!(
+            "Failed to save release archive to '{}': {e}",
+            output_path.display()
+        )
+    })?;
+
+    let sha256 = download::sha256_file(&output_path)?;
+    if let Ok(expected) = fs::read_to_string(cache_checksum_path(&cache_zip)) {
+        if !sha256.eq_ignore_ascii_case(expected.trim()) {
+            let _ = fs::remove_file(&output_path);
+            return Err(format!(
+                "Checksum mismatch for saved release archive '{}'",
+                output_path.display()
+            ));
+        }
+    }
+
+    Ok(ReleaseDownloadResult {
+        output_path: output_path.to_string_lossy().to_string(),
+        size,
+        sha256,
+    })
+}
+
+/// 現在の導入内容から `target_tag` へ更新した場合に追加・削除・変更されるファイルを求める。
+/// 更新先のzipはキャッシュを優先し、無ければ進捗通知なしでキャッシュへ取得する。
+pub async fn compute_mod_update_diff<R: Runtime>(
+    app: &AppHandle<R>,
+    target_tag: String,
+    platform: String,
+) -> Result<ModUpdateDiff, String> {
+    let platform = settings::GamePlatform::from_user_value(&platform)?;
+    let target_tag = target_tag.trim().to_string();
+    if target_tag.is_empty() {
+        return Err("Release tag is required".to_string());
+    }
+
+    let launcher_settings = settings::load_or_init_global_settings(app)?;
+    let profile_path = install_profile_path(app, &launcher_settings)?;
+    let manifest = read_install_manifest(&profile_path)
+        .ok_or_else(|| "No install manifest was found in the current profile.".to_string())?;
+
+    // ファイル一覧の記録前に導入したプロファイルは、導入時のキャッシュzipで代用する。
+    let installed_files = if manifest.files.is_empty() {
+        let installed_platform = settings::GamePlatform::from_user_value(&manifest.platform)?;
+        let installed_zip = release_cache_zip_path(app, &manifest.tag, &installed_platform)?;
+        if !is_cached_download_valid(&installed_zip) {
+            return Err(format!(
+                "The installed file list for '{}' is unavailable. Reinstall once to record it.",
+                manifest.tag
+            ));
+        }
+        zip::list_zip_files(&installed_zip)?
+            .into_iter()
+            .map(InstallManifestFile::from)
+            .collect()
+    } else {
+        manifest.files
+    };
+
+    // 同じキャッシュzipをインストールと同時に書き換えないよう、インストールと排他にする。
+    let _install_guard = InstallGuard::acquire()?;
+    let target_zip = release_cache_zip_path(app, &target_tag, &platform)?;
+    if !is_cached_download_valid(&target_zip) {
+        let client = download::github_client()?;
+        let release = fetch_release_by_tag(&client, &target_tag).await?;
+        let asset = resolve_asset(&release, &platform)?;
+        ensure_free_space(&target_zip, asset.size)?;
+        // 読み取り専用の比較なので、実行中のインストール進捗表示を上書きしない。
+        download_release_asset_to_cache(&client, asset, &target_zip, None, |_, _| {}).await?;
+        verify_release_asset_digest(asset, &target_zip)?;
+    }
+    if let Err(error) = zip::validate_zip(&target_zip) {
+        discard_cached_download(&target_zip);
+        return Err(format!(
+            "{DOWNLOAD_CORRUPTED_ERROR_PREFIX} The downloaded archive appears corrupted. Please retry. ({error})"
+        ));
+    }
+    let target_files = zip::list_zip_files(&target_zip)?
+        .into_iter()
+        .map(InstallManifestFile::from)
+        .collect::<Vec<_>>();
+
+    let (added, removed, changed, unchanged_count) =
+        diff_install_files(&installed_files, &target_files);
+    Ok(ModUpdateDiff {
+        installed_tag: manifest.tag,
+        target_tag,
+        platform: platform.as_str().to_string(),
+        added,
+        removed,
+        changed,
+        unchanged_count,
+    })
+}
+
+/// 検証済みのzipをステージングへ展開し、パッチャー同期・セーブデータ復元を経てプロファイルへ反映する。
+/// 反映後に設定を保存し、復元したセーブファイル数を返す。
+#[allow(clippy::too_many_arguments)]
+async fn install_from_archive<R: Runtime>(
+    app: &AppHandle<R>,
+    archive_zip: &Path,
+    tag: &str,
+    platform: &settings::GamePlatform,
+    asset_name: &str,
+    profile_path: &Path,
+    launcher_settings: &mut settings::LauncherSettings,
+    restore_preserved_save_data: bool,
+    restore_paths: Option<&[String]>,
+    sync_patchers: bool,
+    cancel: &InstallCancelToken,
+) -> Result<usize, String> {
+    let (staging_path, backup_path) = make_profile_paths(profile_path)?;
     clean_path(&staging_path)?;
     clean_path(&backup_path)?;
 
@@ -1284,31 +3315,44 @@ async fn install_snr_release_inner<R: Runtime>(
         None,
     );
 
-    zip::extract_zip(&cache_zip, &staging_path, |current, total| {
-        let progress = if total == 0 {
-            100.0
-        } else {
-            (current as f64 / total as f64) * 100.0
-        };
-        emit_progress(
-            app,
-            "extracting",
-            progress.clamp(0.0, 100.0),
-            "Extracting package...",
-            None,
-            None,
-            Some(current),
-            Some(total),
-        );
-    })?;
+    zip::extract_zip_cancellable(
+        archive_zip,
+        &staging_path,
+        Some(cancel.flag()),
+        |current, total| {
+            let progress = if total == 0 {
+                100.0
+            } else {
+                (current as f64 / total as f64) * 100.0
+            };
+            emit_progress(
+                app,
+                "extracting",
+                progress.clamp(0.0, 100.0),
+                "Extracting package...",
+                None,
+                None,
+                Some(current),
+                Some(total),
+            );
+        },
+    )?;
 
-    if mod_profile::get().distribution.patchers.enabled {
+    if sync_patchers && mod_profile::get().distribution.patchers.enabled {
         let patcher_sync_result = match patcher_sync_client() {
             Ok(patcher_client) => {
-                download_patchers_into_staging(app, &patcher_client, &staging_path).await
+                download_patchers_into_staging(
+                    app,
+                    &patcher_client,
+                    &staging_path,
+                    Some(cancel.flag()),
+                )
+                .await
             }
             Err(error) => Err(error),
         };
+        // パッチャー同期の失敗は継続扱いだが、中断要求だけはここで打ち切る。
+        cancel.ensure_not_cancelled()?;
         if let Err(error) = patcher_sync_result {
             emit_progress(
                 app,
@@ -1320,8 +3364,19 @@ async fn install_snr_release_inner<R: Runtime>(
                 None,
                 None,
             );
-            eprintln!("Failed to synchronize patchers: {error}");
+            log::warn!("Failed to synchronize patchers: {error}");
         }
+    } else if !sync_patchers {
+        emit_progress(
+            app,
+            "patchers",
+            100.0,
+            "Patchers synchronization skipped.",
+            None,
+            None,
+            None,
+            None,
+        );
     } else {
         emit_progress(
             app,
@@ -1347,7 +3402,7 @@ async fn install_snr_release_inner<R: Runtime>(
             None,
         );
 
-        let restored = restore_preserved_save_data_into_profile(app, &staging_path)?;
+        let restored = restore_preserved_save_data_into_profile(app, &staging_path, restore_paths)?;
         emit_progress(
             app,
             "restoring",
@@ -1363,13 +3418,182 @@ async fn install_snr_release_inner<R: Runtime>(
         0
     };
 
-    settings::verify_profile_required_files(&staging_path)?;
-    promote_staging_to_profile(&staging_path, &profile_path, &backup_path)?;
+    if launcher_settings.preserve_bepinex_config {
+        let carried = carry_over_bepinex_config(profile_path, &staging_path)?;
+        if carried > 0 {
+            log::info!("Carried over {carried} BepInEx config file(s) to the updated profile");
+        }
+    }
+
+    // プロファイル単位の上書き設定は更新後も引き継ぐ。
+    if let Some(overrides) = settings::load_profile_settings_overrides(profile_path) {
+        settings::save_profile_settings_overrides(&staging_path, overrides)?;
+    }
+    write_install_manifest(
+        &staging_path,
+        &InstallManifest {
+            tag: tag.to_string(),
+            platform: platform.as_str().to_string(),
+            asset_name: asset_name.to_string(),
+            installed_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            files: zip::list_zip_files(archive_zip)?
+                .into_iter()
+                .map(InstallManifestFile::from)
+                .collect(),
+            game_version: detect_game_version(app),
+        },
+    )?;
+
+    emit_progress(
+        app,
+        "verifying",
+        0.0,
+        "Verifying required files...",
+        None,
+        None,
+        None,
+        None,
+    );
+    // 欠落ファイル名を含むエラーをそのまま failed メッセージへ流す。
+    settings::verify_profile_required_files(&staging_path)
+        .map_err(|error| format!("Required file verification failed: {error}"))?;
+    emit_progress(
+        app,
+        "verifying",
+        50.0,
+        "Applying installed files to profile...",
+        None,
+        None,
+        None,
+        None,
+    );
+    cancel.commit()?;
+    promote_staging_to_profile(&staging_path, profile_path, &backup_path)?;
+    emit_progress(
+        app,
+        "verifying",
+        100.0,
+        "Required files verified",
+        None,
+        None,
+        None,
+        None,
+    );
 
     launcher_settings.selected_release_tag = tag.to_string();
-    launcher_settings.game_platform = platform.clone();
+    // プロファイルの上書きでプラットフォームを固定している場合は、そちらを更新する。
+    if !settings::update_profile_platform_override(profile_path, platform)? {
+        launcher_settings.game_platform = platform.clone();
+    }
     launcher_settings.profile_path = profile_path.to_string_lossy().to_string();
-    settings::save_settings(app, &launcher_settings)?;
+    settings::save_settings(app, launcher_settings)?;
+
+    Ok(restored_save_files)
+}
+
+async fn install_snr_release_inner<R: Runtime>(
+    app: &AppHandle<R>,
+    tag: &str,
+    platform: &settings::GamePlatform,
+    restore_preserved_save_data: bool,
+    restore_paths: Option<&[String]>,
+    reuse_cached_download: bool,
+    cancel: &InstallCancelToken,
+) -> Result<InstallResult, String> {
+    emit_progress(
+        app,
+        "resolving",
+        0.0,
+        "Resolving release metadata...",
+        None,
+        None,
+        None,
+        None,
+    );
+
+    let client = download::github_client()?;
+    let release = fetch_release_by_tag(&client, tag).await?;
+    let asset = resolve_asset(&release, platform)?;
+    cancel.ensure_not_cancelled()?;
+
+    let mut launcher_settings = settings::load_or_init_global_settings(app)?;
+    let profile_path = install_profile_path(app, &launcher_settings)?;
+    let cache_zip = release_cache_zip_path(app, tag, platform)?;
+    // 再試行時は、チェックサムが一致するキャッシュがあればダウンロードを省略する。
+    let use_cached_download = reuse_cached_download && is_cached_download_valid(&cache_zip);
+    // ダウンロードを始める前に弾き、失敗時の作業用ディレクトリを残さない。
+    ensure_sufficient_disk_space(&profile_path, &cache_zip, asset.size, use_cached_download)?;
+
+    if let Some(parent) = profile_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create profile parent directory: {e}"))?;
+    }
+
+    if use_cached_download {
+        emit_progress(
+            app,
+            "downloading",
+            100.0,
+            format!("Using cached '{}'", asset.name),
+            None,
+            None,
+            None,
+            None,
+        );
+    } else {
+        download_release_asset(app, &client, asset, &cache_zip, Some(cancel.flag())).await?;
+    }
+    cancel.ensure_not_cancelled()?;
+
+    // 展開前に公開ダイジェストと照合し、壊れたキャッシュをプロファイルへ展開しない。
+    verify_release_asset_digest(asset, &cache_zip)?;
+
+    // 途中で切れたダウンロードを汎用の展開エラーにせず、再試行を促すエラーにする。
+    if let Err(error) = zip::validate_zip(&cache_zip) {
+        discard_cached_download(&cache_zip);
+        return Err(format!(
+            "{DOWNLOAD_CORRUPTED_ERROR_PREFIX} The downloaded archive appears corrupted. Please retry the install. ({error})"
+        ));
+    }
+
+    let restored_save_files = install_from_archive(
+        app,
+        &cache_zip,
+        tag,
+        platform,
+        &asset.name,
+        &profile_path,
+        &mut launcher_settings,
+        restore_preserved_save_data,
+        restore_paths,
+        true,
+        cancel,
+    )
+    .await?;
+
+    // キャッシュ整理の失敗はインストール結果に影響させない。
+    if launcher_settings.cache_max_size_mb > 0 {
+        if let Some(tag_cache_dir) = cache_zip.parent() {
+            if let Some(cache_root) = tag_cache_dir.parent() {
+                let evicted = evict_cache_to_cap(
+                    cache_root,
+                    launcher_settings
+                        .cache_max_size_mb
+                        .saturating_mul(1024 * 1024),
+                    tag_cache_dir,
+                );
+                if !evicted.is_empty() {
+                    log::info!(
+                        "Evicted {} cached release(s) over the size cap",
+                        evicted.len()
+                    );
+                }
+            }
+        }
+    }
 
     emit_progress(
         app,
@@ -1394,7 +3618,6 @@ async fn install_snr_release_inner<R: Runtime>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::time::{SystemTime, UNIX_EPOCH};
 
     fn make_temp_dir(label: &str) -> PathBuf {
         let millis = SystemTime::now()
@@ -1417,6 +3640,416 @@ mod tests {
         bytes
     }
 
+    #[test]
+    fn swap_in_resynced_patchers_keeps_existing_files_without_staged_copy() {
+        let root = make_temp_dir("patcher-swap");
+        let _ = fs::remove_dir_all(&root);
+        let profile = root.join("profile");
+        let staging = root.join("profile._staging");
+        let profile_patchers = profile.join("BepInEx").join("patchers");
+        let staged_patchers = staging.join("BepInEx").join("patchers");
+        fs::create_dir_all(&profile_patchers).expect("create profile patchers");
+        fs::create_dir_all(&staged_patchers).expect("create staged patchers");
+        fs::write(profile_patchers.join("a.dll"), b"old-a").expect("write a");
+        fs::write(profile_patchers.join("b.dll"), b"old-b").expect("write b");
+        // b.dll は取得に失敗した想定で作業ディレクトリに存在しない。
+        fs::write(staged_patchers.join("a.dll"), b"new-a").expect("write staged a");
+
+        let swapped = swap_in_resynced_patchers(&staging, &profile).expect("swap patchers");
+
+        assert_eq!(swapped, 1);
+        assert_eq!(
+            fs::read(profile_patchers.join("a.dll")).expect("read a"),
+            b"new-a"
+        );
+        assert_eq!(
+            fs::read(profile_patchers.join("b.dll")).expect("read b"),
+            b"old-b"
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn collect_managed_artifacts_marks_in_use_and_retained_backups() {
+        let root = make_temp_dir("managed-artifacts");
+        let _ = fs::remove_dir_all(&root);
+        let app_data = root.join("app");
+        let profile_path = root.join("profile");
+        fs::create_dir_all(root.join("profile._staging")).expect("create staging");
+        fs::create_dir_all(
+            app_data
+                .join(bootstrap::MIGRATION_BACKUPS_DIR_NAME)
+                .join("import-1"),
+        )
+        .expect("create migration backup");
+        let elevated_dir = app_data.join(launch_service::ELEVATED_LAUNCH_DIR_NAME);
+        fs::create_dir_all(&elevated_dir).expect("create elevated dir");
+        fs::write(
+            elevated_dir.join(format!("{}-1-2.payload.json", std::process::id())),
+            b"{}",
+        )
+        .expect("write own payload");
+        fs::write(elevated_dir.join("0-1-2.payload.json"), b"{}").expect("write stale payload");
+
+        let cache_root = app_data
+            .join(bootstrap::CACHE_DIR_NAME)
+            .join(mod_profile::get().mod_info.id.as_str());
+        fs::create_dir_all(cache_root.join("v1.0.0")).expect("create kept cache");
+        fs::create_dir_all(cache_root.join("v0.9.0")).expect("create old cache");
+
+        let artifacts = collect_managed_artifacts(
+            &app_data,
+            &profile_path,
+            &[OperationKind::Install],
+            &["v1.0.0".to_string()],
+        )
+        .expect("collect artifacts");
+        assert_eq!(artifacts.len(), 6);
+
+        let staging = artifacts
+            .iter()
+            .find(|artifact| artifact.kind == ManagedArtifactKind::ProfileStaging)
+            .expect("staging listed");
+        assert!(staging.in_use && !staging.safe_to_delete);
+        let migration = artifacts
+            .iter()
+            .find(|artifact| artifact.kind == ManagedArtifactKind::MigrationImportBackup)
+            .expect("migration backup listed");
+        assert!(!migration.in_use && !migration.safe_to_delete);
+        let mut elevated = artifacts
+            .iter()
+            .filter(|artifact| artifact.kind == ManagedArtifactKind::ElevatedLaunch)
+            .map(|artifact| artifact.in_use)
+            .collect::<Vec<_>>();
+        elevated.sort();
+        assert_eq!(elevated, vec![false, true]);
+
+        // インストール中でなければ、残す指定のないキャッシュだけが削除対象になる。
+        let artifacts =
+            collect_managed_artifacts(&app_data, &profile_path, &[], &["v1.0.0".to_string()])
+                .expect("collect artifacts");
+        let mut caches = artifacts
+            .iter()
+            .filter(|artifact| artifact.kind == ManagedArtifactKind::ReleaseCache)
+            .map(|artifact| {
+                let name = Path::new(&artifact.path)
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                (name, artifact.safe_to_delete)
+            })
+            .collect::<Vec<_>>();
+        caches.sort();
+        assert_eq!(
+            caches,
+            vec![("v0.9.0".to_string(), true), ("v1.0.0".to_string(), false)]
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn infer_local_archive_tag_reads_version_from_file_name() {
+        assert_eq!(
+            infer_local_archive_tag(Path::new("/tmp/SuperNewRoles_v2.1.0_Steam.zip")),
+            "v2.1.0"
+        );
+        assert_eq!(
+            infer_local_archive_tag(Path::new("SuperNewRoles-1.8.2.zip")),
+            "1.8.2"
+        );
+        assert_eq!(
+            infer_local_archive_tag(Path::new("SuperNewRoles.zip")),
+            LOCAL_ARCHIVE_TAG
+        );
+    }
+
+    #[test]
+    fn check_disk_space_reports_required_and_available_sizes() {
+        assert!(check_disk_space(100, 100).is_ok());
+        assert_eq!(
+            check_disk_space(3 * 1024 * 1024, 1024 * 1024).unwrap_err(),
+            "Not enough disk space: need ~3.0 MB, have 1.0 MB"
+        );
+    }
+
+    #[test]
+    fn sha256_from_asset_digest_accepts_only_sha256() {
+        assert_eq!(sha256_from_asset_digest("sha256:abc123"), Some("abc123"));
+        assert_eq!(sha256_from_asset_digest(" SHA256: abc123 "), Some("abc123"));
+        assert_eq!(sha256_from_asset_digest("sha512:abc123"), None);
+        assert_eq!(sha256_from_asset_digest("sha256:"), None);
+        assert_eq!(sha256_from_asset_digest("abc123"), None);
+    }
+
+    #[test]
+    fn install_cancel_token_stops_accepting_requests_after_commit() {
+        let token = InstallCancelToken::register("v-cancel-test");
+        assert!(!request_install_cancel(Some("v-other-tag")));
+        assert!(token.ensure_not_cancelled().is_ok());
+        assert!(token.commit().is_ok());
+        assert!(!request_install_cancel(Some("v-cancel-test")));
+        assert!(!token.is_cancelled());
+
+        let cancelled = InstallCancelToken::register("v-cancel-test-2");
+        assert!(request_install_cancel(Some(" v-cancel-test-2 ")));
+        assert_eq!(
+            cancelled.commit().unwrap_err(),
+            INSTALL_CANCELLED_ERROR.to_string()
+        );
+        drop(cancelled);
+        assert!(!request_install_cancel(Some("v-cancel-test-2")));
+    }
+
+    #[test]
+    fn repatch_needed_only_when_both_versions_are_known_and_differ() {
+        assert!(repatch_needed(Some("2025.3.25"), Some("2024.11.26")));
+        assert!(!repatch_needed(Some("2025.3.25"), Some("2025.3.25")));
+        assert!(!repatch_needed(Some("2025.3.25"), None));
+        assert!(!repatch_needed(None, Some("2025.3.25")));
+    }
+
+    #[test]
+    fn options_versions_compatible_flags_only_differing_versions() {
+        assert!(options_versions_compatible(Some(1), Some(1)));
+        assert!(!options_versions_compatible(Some(2), Some(1)));
+        assert!(options_versions_compatible(Some(2), None));
+        assert!(options_versions_compatible(None, Some(1)));
+    }
+
+    #[test]
+    fn locate_archive_save_data_accepts_supported_layouts() {
+        let root = make_temp_dir("archive-layouts");
+        let _ = fs::remove_dir_all(&root);
+
+        // プロファイルと同じ階層で格納されたアーカイブ。
+        let full = root.join("full");
+        fs::create_dir_all(full.join(save_data_root())).expect("failed to create save data dir");
+        fs::write(
+            full.join(save_data_root()).join(OPTIONS_DATA_FILE_NAME),
+            make_minimal_options_data(),
+        )
+        .expect("failed to write options");
+        fs::create_dir_all(full.join("BepInEx")).expect("failed to create bepinex dir");
+        let (save_data, bepinex) =
+            locate_archive_save_data(&full).expect("full layout should be accepted");
+        assert_eq!(save_data, full.join(save_data_root()));
+        assert_eq!(bepinex, full.join("BepInEx").join("config"));
+
+        // SaveDataの中身だけを単一フォルダで包んだアーカイブ。
+        let wrapped = root.join("wrapped");
+        fs::create_dir_all(wrapped.join("Backup")).expect("failed to create wrapper dir");
+        fs::write(
+            wrapped.join("Backup").join(OPTIONS_DATA_FILE_NAME),
+            make_minimal_options_data(),
+        )
+        .expect("failed to write options");
+        let (save_data, _) =
+            locate_archive_save_data(&wrapped).expect("wrapped layout should be accepted");
+        assert_eq!(save_data, wrapped.join("Backup"));
+
+        let empty = root.join("empty");
+        fs::create_dir_all(empty.join("Other")).expect("failed to create unrelated dir");
+        let error = locate_archive_save_data(&empty).expect_err("expected missing SaveData");
+        assert!(error.contains(OPTIONS_DATA_FILE_NAME));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn carry_over_bepinex_config_overwrites_shipped_defaults() {
+        let root = make_temp_dir("bepinex-config");
+        let _ = fs::remove_dir_all(&root);
+        let profile = root.join("profile");
+        let staging = root.join("staging");
+        fs::create_dir_all(profile.join("BepInEx").join("config").join("nested"))
+            .expect("failed to create profile config dir");
+        fs::create_dir_all(staging.join("BepInEx").join("config"))
+            .expect("failed to create staging config dir");
+        fs::write(profile.join("BepInEx/config/BepInEx.cfg"), b"user")
+            .expect("failed to write user config");
+        fs::write(profile.join("BepInEx/config/nested/Mod.cfg"), b"tuned")
+            .expect("failed to write nested config");
+        fs::write(staging.join("BepInEx/config/BepInEx.cfg"), b"default")
+            .expect("failed to write shipped config");
+
+        let carried = carry_over_bepinex_config(&profile, &staging).expect("carry over");
+        assert_eq!(carried, 2);
+        assert_eq!(
+            fs::read(staging.join("BepInEx/config/BepInEx.cfg")).expect("read config"),
+            b"user"
+        );
+        assert!(staging.join("BepInEx/config/nested/Mod.cfg").is_file());
+
+        let preserved = collect_preserved_profile_files(&profile, true).expect("collect");
+        assert!(preserved
+            .iter()
+            .any(|(_, relative)| relative == "BepInEx/config/nested/Mod.cfg"));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn diff_install_files_classifies_added_removed_and_changed() {
+        let file = |path: &str, crc32: u32| InstallManifestFile {
+            path: path.to_string(),
+            size: 10,
+            crc32,
+        };
+        let installed = vec![
+            file("BepInEx/plugins/Mod.dll", 1),
+            file("BepInEx/core/Old.dll", 2),
+            file("winhttp.dll", 3),
+        ];
+        let target = vec![
+            file("BepInEx/plugins/Mod.dll", 9),
+            file("BepInEx/core/New.dll", 4),
+            file("winhttp.dll", 3),
+        ];
+
+        let (added, removed, changed, unchanged) = diff_install_files(&installed, &target);
+        assert_eq!(added, vec!["BepInEx/core/New.dll".to_string()]);
+        assert_eq!(removed, vec!["BepInEx/core/Old.dll".to_string()]);
+        assert_eq!(changed, vec!["BepInEx/plugins/Mod.dll".to_string()]);
+        assert_eq!(unchanged, 1);
+    }
+
+    #[test]
+    fn evict_cache_to_cap_removes_oldest_tags_but_keeps_current() {
+        let root = make_temp_dir("cache-cap");
+        let _ = fs::remove_dir_all(&root);
+        for tag in ["v1", "v2", "v3"] {
+            fs::create_dir_all(root.join(tag)).expect("failed to create tag dir");
+            fs::write(root.join(tag).join("steam.zip"), vec![0u8; 100]).expect("write zip");
+            std::thread::sleep(Duration::from_millis(20));
+        }
+
+        let evicted = evict_cache_to_cap(&root, 150, &root.join("v1"));
+        assert_eq!(evicted, vec![root.join("v2"), root.join("v3")]);
+        assert!(root.join("v1").is_dir());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn operation_guard_tracks_progress_until_dropped() {
+        let count_report_sends = || {
+            active_operations()
+                .into_iter()
+                .filter(|operation| operation.kind == OperationKind::ReportSend)
+                .count()
+        };
+        let before = count_report_sends();
+
+        let guard = begin_operation(OperationKind::ReportSend);
+        record_operation_progress(
+            OperationKind::ReportSend,
+            &serde_json::json!({ "progress": 50 }),
+        );
+        let recorded = active_operations()
+            .into_iter()
+            .rfind(|operation| operation.kind == OperationKind::ReportSend)
+            .expect("operation should be recorded");
+        assert_eq!(
+            recorded.progress,
+            Some(serde_json::json!({ "progress": 50 }))
+        );
+
+        drop(guard);
+        assert_eq!(count_report_sends(), before);
+    }
+
+    #[test]
+    fn restore_preserved_files_honors_selected_paths() {
+        let root = make_temp_dir("selective-restore");
+        let _ = fs::remove_dir_all(&root);
+        let preserved = root.join("preserved");
+        let profile = root.join("profile");
+        let save_dir = preserved.join("SaveData");
+        fs::create_dir_all(&save_dir).expect("failed to create preserved dir");
+        fs::write(save_dir.join("Options.data"), b"options").expect("write options");
+        fs::write(save_dir.join("PresetOptions_0.data"), b"preset").expect("write preset");
+
+        let restored = restore_preserved_files(
+            &preserved,
+            &profile,
+            Some(&["SaveData\\Options.data".to_string()]),
+        )
+        .expect("restore should succeed");
+        assert_eq!(restored, 1);
+        assert!(profile.join("SaveData").join("Options.data").is_file());
+        assert!(!profile
+            .join("SaveData")
+            .join("PresetOptions_0.data")
+            .exists());
+
+        let restored_all =
+            restore_preserved_files(&preserved, &profile, None).expect("restore should succeed");
+        assert_eq!(restored_all, 2);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn copy_directory_recursive_stops_when_cancelled() {
+        let root = make_temp_dir("copy-cancel");
+        let _ = fs::remove_dir_all(&root);
+        let source = root.join("source");
+        let destination = root.join("destination");
+        fs::create_dir_all(&source).expect("failed to create source");
+        fs::write(source.join("a.dat"), b"a").expect("failed to write source file");
+
+        let cancel = AtomicBool::new(true);
+        let error = copy_directory_recursive(&source, &destination, Some(&cancel))
+            .expect_err("copy should be cancelled");
+        assert_eq!(error, SAVE_DATA_IMPORT_CANCELLED_ERROR);
+        assert!(!destination.join("a.dat").exists());
+
+        cancel.store(false, Ordering::SeqCst);
+        copy_directory_recursive(&source, &destination, Some(&cancel)).expect("copy");
+        assert!(destination.join("a.dat").is_file());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn uninstall_preview_counts_preserved_files_without_deleting() {
+        let profile = make_temp_dir("uninstall-preview");
+        let _ = fs::remove_dir_all(&profile);
+        let config_dir = profile.join("BepInEx").join("config");
+        fs::create_dir_all(&config_dir).expect("failed to create config dir");
+        fs::write(config_dir.join("a.cfg"), [0u8; 4]).expect("failed to write config");
+        fs::write(profile.join("other.bin"), [0u8; 6]).expect("failed to write file");
+
+        let preserved = preview_profile_uninstall(&profile, true, false).expect("preview");
+        assert!(preserved.profile_exists);
+        assert_eq!(preserved.preserved_files, 1);
+        assert_eq!(preserved.removed_bytes, 10);
+
+        let discarded = preview_profile_uninstall(&profile, false, false).expect("preview");
+        assert_eq!(discarded.preserved_files, 0);
+        assert!(config_dir.join("a.cfg").is_file());
+
+        let _ = fs::remove_dir_all(&profile);
+    }
+
+    #[test]
+    fn save_data_paths_follow_configured_root() {
+        let configured = mod_profile::to_relative_path(&mod_profile::get().paths.save_data_root);
+        let among_us = PathBuf::from("among-us");
+        let profile = PathBuf::from("profile");
+
+        assert_eq!(
+            source_save_data_path_from_among_us(&among_us),
+            among_us.join(&configured)
+        );
+        assert_eq!(
+            save_data_path_in_profile(&profile),
+            profile.join(&configured)
+        );
+    }
+
     #[test]
     fn preview_savedata_requires_among_us_exe() {
         let path = make_temp_dir("missing-exe");
@@ -1471,6 +4104,95 @@ mod tests {
         let _ = fs::remove_dir_all(&path);
     }
 
+    #[test]
+    fn verifying_stage_sits_between_restoring_and_complete() {
+        assert_eq!(
+            map_install_progress("restoring", 100.0),
+            INSTALL_RESTORE_END
+        );
+        assert_eq!(map_install_progress("verifying", 0.0), INSTALL_RESTORE_END);
+        assert!(map_install_progress("verifying", 50.0) > INSTALL_RESTORE_END);
+        assert_eq!(map_install_progress("verifying", 100.0), 100.0);
+    }
+
+    #[test]
+    fn cleanup_orphans_removes_staging_and_backup() {
+        let root = make_temp_dir("orphans");
+        let _ = fs::remove_dir_all(&root);
+        let profile = root.join("default");
+        let (staging, backup) = make_profile_paths(&profile).expect("profile paths");
+        fs::create_dir_all(&profile).expect("failed to create profile");
+        fs::create_dir_all(&staging).expect("failed to create staging");
+        fs::create_dir_all(&backup).expect("failed to create backup");
+        fs::write(staging.join("a.bin"), [0u8; 10]).expect("failed to write staging file");
+        fs::write(backup.join("b.bin"), [0u8; 5]).expect("failed to write backup file");
+
+        let result = cleanup_orphaned_profile_dirs(&profile).expect("cleanup should succeed");
+        assert_eq!(result.removed_paths.len(), 2);
+        assert_eq!(result.reclaimed_bytes, 15);
+        assert!(!result.restored_profile_from_backup);
+        assert!(!staging.exists());
+        assert!(!backup.exists());
+        assert!(profile.is_dir());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn cleanup_orphans_restores_missing_profile_from_backup() {
+        let root = make_temp_dir("orphans-restore");
+        let _ = fs::remove_dir_all(&root);
+        let profile = root.join("default");
+        let (_, backup) = make_profile_paths(&profile).expect("profile paths");
+        fs::create_dir_all(&backup).expect("failed to create backup");
+        fs::write(backup.join("keep.txt"), b"keep").expect("failed to write backup file");
+
+        let result = cleanup_orphaned_profile_dirs(&profile).expect("cleanup should succeed");
+        assert!(result.restored_profile_from_backup);
+        assert!(result.removed_paths.is_empty());
+        assert!(profile.join("keep.txt").is_file());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn detach_orphans_moves_staging_aside_and_restores_profile() {
+        let root = make_temp_dir("orphans-detach");
+        let _ = fs::remove_dir_all(&root);
+        let profile = root.join("default");
+        let (staging, backup) = make_profile_paths(&profile).expect("profile paths");
+        fs::create_dir_all(&staging).expect("failed to create staging");
+        fs::create_dir_all(&backup).expect("failed to create backup");
+        fs::write(backup.join("keep.txt"), b"keep").expect("failed to write backup file");
+
+        let trash = detach_orphaned_profile_dirs(&profile).expect("detach should succeed");
+        assert!(profile.join("keep.txt").is_file());
+        assert!(!staging.exists());
+        assert_eq!(trash.len(), 1);
+        assert!(trash[0].is_dir());
+        // 消し切れなかった削除待ちは次回も対象にする。
+        assert_eq!(
+            detach_orphaned_profile_dirs(&profile).expect("detach should succeed"),
+            trash
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn install_guard_rejects_concurrent_install() {
+        let guard = InstallGuard::acquire().expect("first install should acquire guard");
+        assert!(is_install_in_progress());
+        let error = InstallGuard::acquire()
+            .err()
+            .expect("second install should be rejected");
+        assert_eq!(error, INSTALL_ALREADY_IN_PROGRESS_ERROR);
+
+        drop(guard);
+        assert!(!is_install_in_progress());
+        assert!(InstallGuard::acquire().is_ok());
+    }
+
     #[test]
     fn not_found_io_error_is_classified() {
         assert!(is_not_found_io_error(&io::Error::from(
@@ -0,0 +1,196 @@
+// Windows セキュリティ センター(WSC)からウイルス対策製品の状態を取得する。
+// DLLの隔離やインストール失敗の原因説明に使う情報提供専用で、取得失敗はエラーにしない。
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// 起動のたびに照会すると WMI の応答待ちで起動が遅れるため、結果を一定時間使い回す。
+const AV_STATUS_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+// WMI/Defender が応答しない場合でも、起動前チェックを止めない上限。
+#[cfg_attr(not(windows), allow(dead_code))]
+const WSC_QUERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+static AV_STATUS_CACHE: Mutex<Option<(Instant, AvStatus)>> = Mutex::new(None);
+
+/// WSC に登録されたウイルス対策製品1件分の状態。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvProduct {
+    pub name: String,
+    pub real_time_enabled: bool,
+    pub definitions_up_to_date: bool,
+}
+
+/// ウイルス対策のリアルタイム保護状態。WSC を照会できない環境では queryable が false になる。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvStatus {
+    pub queryable: bool,
+    pub real_time_protection_enabled: Option<bool>,
+    pub active_product: Option<String>,
+    pub products: Vec<AvProduct>,
+    pub error: Option<String>,
+}
+
+#[cfg_attr(not(windows), allow(dead_code))]
+#[derive(Debug, Deserialize)]
+struct WscAntiVirusProduct {
+    #[serde(rename = "displayName", default)]
+    display_name: String,
+    #[serde(rename = "productState", default)]
+    product_state: u32,
+}
+
+// productState は公開仕様が無いため、広く使われているビット解釈に従う。
+// 0xF000 の桁が 1 ならリアルタイム保護が有効、0x00F0 の桁が 0 なら定義ファイルが最新。
+#[cfg_attr(not(windows), allow(dead_code))]
+fn decode_product(product: WscAntiVirusProduct) -> AvProduct {
+    AvProduct {
+        name: product.display_name.trim().to_string(),
+        real_time_enabled: (product.product_state >> 12) & 0xF == 1,
+        definitions_up_to_date: (product.product_state >> 4) & 0xF == 0,
+    }
+}
+
+fn unavailable_status(error: String) -> AvStatus {
+    AvStatus {
+        queryable: false,
+        real_time_protection_enabled: None,
+        active_product: None,
+        products: Vec::new(),
+        error: Some(error),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn query_wsc_products() -> Result<Vec<WscAntiVirusProduct>, String> {
+    use std::io::Read;
+    use std::os::windows::process::CommandExt;
+    use std::process::{Command, Output, Stdio};
+    use std::thread;
+
+    // コンソールウィンドウを表示せずに PowerShell を実行する。
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+    // 単一製品でも配列で受け取れるよう -InputObject で配列のまま JSON 化する。
+    const SCRIPT: &str = "$p = @(Get-CimInstance -Namespace root/SecurityCenter2 \
+        -ClassName AntiVirusProduct -ErrorAction Stop | Select-Object displayName, productState); \
+        ConvertTo-Json -InputObject $p -Compress";
+
+    let mut child = Command::new("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", SCRIPT])
+        .creation_flags(CREATE_NO_WINDOW)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to run PowerShell for Security Center query: {e}"))?;
+
+    // パイプが詰まって子プロセスが止まらないよう、出力は別スレッドで読み切る。
+    let stdout_reader = child.stdout.take().map(|mut stdout| {
+        thread::spawn(move || {
+            let mut bytes = Vec::new();
+            let _ = stdout.read_to_end(&mut bytes);
+            bytes
+        })
+    });
+    let stderr_reader = child.stderr.take().map(|mut stderr| {
+        thread::spawn(move || {
+            let mut bytes = Vec::new();
+            let _ = stderr.read_to_end(&mut bytes);
+            bytes
+        })
+    });
+
+    let deadline = Instant::now() + WSC_QUERY_TIMEOUT;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() < deadline => thread::sleep(Duration::from_millis(50)),
+            Ok(None) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!(
+                    "Security Center query timed out after {} seconds",
+                    WSC_QUERY_TIMEOUT.as_secs()
+                ));
+            }
+            Err(error) => {
+                let _ = child.kill();
+                return Err(format!("Failed to wait for Security Center query: {error}"));
+            }
+        }
+    };
+    let read_output = |reader: Option<thread::JoinHandle<Vec<u8>>>| {
+        reader
+            .and_then(|reader| reader.join().ok())
+            .unwrap_or_default()
+    };
+    let output = Output {
+        status,
+        stdout: read_output(stdout_reader),
+        stderr: read_output(stderr_reader),
+    };
+    if !output.status.success() {
+        // Windows Server など WSC が存在しない環境ではここに来る。
+        return Err(format!(
+            "Security Center query failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stdout = stdout.trim();
+    if stdout.is_empty() {
+        return Ok(Vec::new());
+    }
+    serde_json::from_str(stdout)
+        .map_err(|e| format!("Failed to parse Security Center response: {e}"))
+}
+
+/// 直近の照会結果が有効期間内ならそれを返し、無ければ WSC を照会する。
+/// 起動前チェックなど、頻繁に呼ばれる箇所ではこちらを使う。
+pub fn cached_av_status() -> AvStatus {
+    if let Ok(guard) = AV_STATUS_CACHE.lock() {
+        if let Some((queried_at, status)) = guard.as_ref() {
+            if queried_at.elapsed() < AV_STATUS_CACHE_TTL {
+                return status.clone();
+            }
+        }
+    }
+    av_status()
+}
+
+/// WSC を照会し、リアルタイム保護の有無と有効な製品を返す。結果はキャッシュへも保存する。
+pub fn av_status() -> AvStatus {
+    let status = query_av_status();
+    if let Ok(mut guard) = AV_STATUS_CACHE.lock() {
+        *guard = Some((Instant::now(), status.clone()));
+    }
+    status
+}
+
+fn query_av_status() -> AvStatus {
+    #[cfg(target_os = "windows")]
+    {
+        let products = match query_wsc_products() {
+            Ok(products) => products.into_iter().map(decode_product).collect::<Vec<_>>(),
+            Err(error) => return unavailable_status(error),
+        };
+        let active_product = products
+            .iter()
+            .find(|product| product.real_time_enabled)
+            .map(|product| product.name.clone());
+
+        AvStatus {
+            queryable: true,
+            real_time_protection_enabled: Some(active_product.is_some()),
+            active_product,
+            products,
+            error: None,
+        }
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        unavailable_status("Windows Security Center is only available on Windows".to_string())
+    }
+}
@@ -1,5 +1,7 @@
 // バックグラウンドで通知状態を監視し、必要時のみOS通知を出す。
 use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
 use std::sync::{Mutex, OnceLock};
 use std::time::{Duration, Instant};
 
@@ -9,7 +11,8 @@ use serde::{Deserialize, Serialize};
 use tauri::Emitter;
 use tauri::{AppHandle, Manager, Runtime};
 
-use crate::utils::{mod_profile, reporting_api, settings};
+use crate::utils::settings::{self, NotificationDetailLevel};
+use crate::utils::{mod_profile, reporting_api};
 
 #[cfg(target_os = "windows")]
 pub const BACKGROUND_NOTIFICATION_OPEN_EVENT: &str = "background-notification-open";
@@ -23,6 +26,75 @@ const REPORT_KNOWN_MESSAGE_LIMIT: usize = 10_000;
 const ANNOUNCE_KNOWN_ARTICLE_LIMIT: usize = 2_000;
 
 static PENDING_OPEN_TARGET: OnceLock<Mutex<Option<NotificationOpenTarget>>> = OnceLock::new();
+// インストールなど重い処理の実行数。1件以上ならポーリングを見送る。
+static ACTIVE_HEAVY_OPERATIONS: AtomicUsize = AtomicUsize::new(0);
+// 起動中ワーカーへの制御チャネル。start_worker 前は未設定。
+static WORKER_CONTROL: OnceLock<Mutex<Sender<WorkerControl>>> = OnceLock::new();
+
+/// ワーカーループへ送る制御メッセージ。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WorkerControl {
+    /// ユーザー操作による一時停止。Resume まで一切ポーリングしない。
+    Pause,
+    /// 一時停止を解除し、待ち時間を無視して即時に取得する。
+    Resume,
+    /// 設定の再読込を兼ねて、報告とお知らせを即時に取得する。
+    PollNow,
+    /// お知らせのみ即時に取得する。
+    RefreshAnnounce,
+}
+
+/// 生存中はバックグラウンド通知のポーリングを止めるガード。
+pub struct PollingPauseGuard;
+
+impl Drop for PollingPauseGuard {
+    fn drop(&mut self) {
+        ACTIVE_HEAVY_OPERATIONS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// 帯域競合や処理中の通知を避けるため、ガード破棄までポーリングを一時停止する。
+pub fn pause_polling() -> PollingPauseGuard {
+    ACTIVE_HEAVY_OPERATIONS.fetch_add(1, Ordering::SeqCst);
+    PollingPauseGuard
+}
+
+fn is_polling_paused() -> bool {
+    ACTIVE_HEAVY_OPERATIONS.load(Ordering::SeqCst) > 0
+}
+
+fn send_worker_control(control: WorkerControl) -> Result<(), String> {
+    let sender = WORKER_CONTROL
+        .get()
+        .ok_or_else(|| "Background notification worker is not running".to_string())?;
+    let sender = sender
+        .lock()
+        .map_err(|_| "Failed to lock background notification worker channel".to_string())?;
+    sender
+        .send(control)
+        .map_err(|e| format!("Failed to send control to background notification worker: {e}"))
+}
+
+/// お知らせのポーリング間隔を待たず、即時に再取得させる。
+pub fn request_announce_refresh() {
+    if let Err(error) = send_worker_control(WorkerControl::RefreshAnnounce) {
+        log::warn!("[background-notifications] announce refresh skipped: {error}");
+    }
+}
+
+/// 設定を読み直し、報告とお知らせを即時に取得させる。
+pub fn request_poll_now() -> Result<(), String> {
+    send_worker_control(WorkerControl::PollNow)
+}
+
+/// ワーカーのポーリングを一時停止または再開する。再開時は即時に取得する。
+pub fn set_worker_paused(paused: bool) -> Result<(), String> {
+    send_worker_control(if paused {
+        WorkerControl::Pause
+    } else {
+        WorkerControl::Resume
+    })
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "camelCase")]
@@ -37,6 +109,14 @@ pub enum NotificationOpenTarget {
     },
 }
 
+/// バッジ表示用の未読件数。取得できなかった項目は None。
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnreadCounts {
+    pub report_threads: Option<usize>,
+    pub announcements: Option<usize>,
+}
+
 #[derive(Debug, Clone)]
 struct ReportNotificationItem {
     thread_id: String,
@@ -84,6 +164,8 @@ struct AnnounceArticleMinimal {
     id: String,
     #[serde(default)]
     title: String,
+    #[serde(default)]
+    created_at: String,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -132,6 +214,7 @@ impl BackgroundNotificationWorker {
         enabled: bool,
         locale: &str,
         suppress_notifications: bool,
+        detail_level: NotificationDetailLevel,
     ) {
         // 機能フラグまたは設定で無効なら、保持状態をクリアして終了する。
         if !mod_profile::feature_enabled(mod_profile::Feature::Reporting) {
@@ -150,7 +233,7 @@ impl BackgroundNotificationWorker {
             match tauri::async_runtime::block_on(reporting_api::get_notifications(app)) {
                 Ok(state) => state,
                 Err(error) => {
-                    eprintln!(
+                    log::warn!(
                     "[background-notifications] failed to fetch reporting notifications: {error}"
                 );
                     return;
@@ -214,10 +297,30 @@ impl BackgroundNotificationWorker {
         }
 
         let launcher_name = &mod_profile::get().branding.launcher_name;
+        let texts = notification_texts(locale);
+
+        // 件数のみ通知する設定では、スレッド名も本文も出さず1件にまとめる。
+        if detail_level == NotificationDetailLevel::CountOnly {
+            if let Some(first_item) = new_items.first() {
+                show_background_notification(
+                    app,
+                    launcher_name,
+                    &format_count(texts.report_count, new_items.len()),
+                    NotificationOpenTarget::Report {
+                        thread_id: first_item.thread_id.clone(),
+                    },
+                );
+            }
+            return;
+        }
 
         for item in new_items.iter().take(MAX_REPORT_NOTIFICATIONS_PER_POLL) {
-            let content = condense_whitespace(&item.content);
-            let body = report_notification_body(&item.message_type, &content, locale);
+            let body = if detail_level == NotificationDetailLevel::TitleOnly {
+                texts.new_message.to_string()
+            } else {
+                let content = condense_whitespace(&item.content);
+                report_notification_body(&item.message_type, &content, locale)
+            };
             show_background_notification(
                 app,
                 &format!("{launcher_name} - {}", item.thread_title),
@@ -233,8 +336,8 @@ impl BackgroundNotificationWorker {
             if let Some(first_item) = new_items.first() {
                 show_background_notification(
                     app,
-                    &format!("{launcher_name} - Report Center"),
-                    &format!("{remaining} additional new message(s)."),
+                    &format!("{launcher_name} - {}", texts.report_center),
+                    &format_count(texts.additional_report_count, remaining),
                     NotificationOpenTarget::Report {
                         thread_id: first_item.thread_id.clone(),
                     },
@@ -249,6 +352,7 @@ impl BackgroundNotificationWorker {
         enabled: bool,
         locale: &str,
         suppress_notifications: bool,
+        detail_level: NotificationDetailLevel,
     ) {
         if !mod_profile::feature_enabled(mod_profile::Feature::Announce) {
             self.announce.disable();
@@ -271,7 +375,7 @@ impl BackgroundNotificationWorker {
         let items = match tauri::async_runtime::block_on(fetch_announce_list(client, locale)) {
             Ok(items) => items,
             Err(error) => {
-                eprintln!("[background-notifications] failed to fetch announce list: {error}");
+                log::warn!("[background-notifications] failed to fetch announce list: {error}");
                 return;
             }
         };
@@ -311,28 +415,47 @@ impl BackgroundNotificationWorker {
         }
 
         let launcher_name = &mod_profile::get().branding.launcher_name;
+        let texts = notification_texts(locale);
+        if detail_level == NotificationDetailLevel::CountOnly {
+            if let Some(first_item) = new_items.first() {
+                show_background_notification(
+                    app,
+                    &format!("{launcher_name} - {}", texts.announcement),
+                    &format_count(texts.announcement_count, new_items.len()),
+                    NotificationOpenTarget::Announce {
+                        article_id: first_item.id.trim().to_string(),
+                    },
+                );
+            }
+            return;
+        }
+
         for item in new_items {
-            let body = match tauri::async_runtime::block_on(fetch_announce_preview(
-                client, locale, &item.id,
-            )) {
+            // タイトルのみの設定では本文プレビューを取得しない。
+            let preview = if detail_level == NotificationDetailLevel::TitleOnly {
+                Ok(String::new())
+            } else {
+                tauri::async_runtime::block_on(fetch_announce_preview(client, locale, &item.id))
+            };
+            let body = match preview {
                 Ok(preview) => {
                     if preview.is_empty() {
-                        "New announcement available.".to_string()
+                        texts.new_announcement.to_string()
                     } else {
                         preview
                     }
                 }
                 Err(error) => {
-                    eprintln!(
+                    log::warn!(
                         "[background-notifications] failed to fetch announce article '{}': {error}",
                         item.id
                     );
-                    "New announcement available.".to_string()
+                    texts.new_announcement.to_string()
                 }
             };
 
             let title = if item.title.trim().is_empty() {
-                format!("{launcher_name} - Announcement")
+                format!("{launcher_name} - {}", texts.announcement)
             } else {
                 item.title.trim().to_string()
             };
@@ -350,12 +473,46 @@ impl BackgroundNotificationWorker {
 }
 
 pub fn start_worker<R: Runtime + 'static>(app: AppHandle<R>) {
+    let (sender, receiver) = mpsc::channel();
+    if WORKER_CONTROL.set(Mutex::new(sender)).is_err() {
+        log::warn!("[background-notifications] worker already started; ignoring start request");
+        return;
+    }
+
     std::thread::spawn(move || {
         let mut worker = BackgroundNotificationWorker::default();
         let mut next_report_poll = Instant::now();
         let mut next_announce_poll = Instant::now();
+        let mut user_paused = false;
 
         loop {
+            // 制御メッセージを待ちつつ、タイムアウトを通常のティックとして扱う。
+            match receiver.recv_timeout(WORKER_TICK_INTERVAL) {
+                Ok(WorkerControl::Pause) => {
+                    user_paused = true;
+                    continue;
+                }
+                Ok(WorkerControl::Resume) => {
+                    user_paused = false;
+                    next_report_poll = Instant::now();
+                    next_announce_poll = Instant::now();
+                }
+                Ok(WorkerControl::PollNow) => {
+                    next_report_poll = Instant::now();
+                    next_announce_poll = Instant::now();
+                }
+                Ok(WorkerControl::RefreshAnnounce) => {
+                    next_announce_poll = Instant::now();
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+
+            // 一時停止中は次回予定を据え置き、再開直後のティックでまとめて取得する。
+            if user_paused || is_polling_paused() {
+                continue;
+            }
+
             // 通知ワーカー単体の panic で常駐機能全体が止まらないように保護する。
             let tick_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
                 let now = Instant::now();
@@ -376,10 +533,20 @@ pub fn start_worker<R: Runtime + 'static>(app: AppHandle<R>) {
                         .as_ref()
                         .map(|s| normalize_locale(&s.ui_locale))
                         .unwrap_or("ja");
+                    let detail_level = current_settings
+                        .as_ref()
+                        .map(|s| s.notification_detail_level)
+                        .unwrap_or_default();
                     let suppress_notifications = is_main_window_visible(&app);
 
                     if should_poll_report {
-                        worker.poll_report(&app, report_enabled, locale, suppress_notifications);
+                        worker.poll_report(
+                            &app,
+                            report_enabled,
+                            locale,
+                            suppress_notifications,
+                            detail_level,
+                        );
                         next_report_poll = now + REPORT_POLL_INTERVAL;
                     }
                     if should_poll_announce {
@@ -388,18 +555,17 @@ pub fn start_worker<R: Runtime + 'static>(app: AppHandle<R>) {
                             announce_enabled,
                             locale,
                             suppress_notifications,
+                            detail_level,
                         );
                         next_announce_poll = now + ANNOUNCE_POLL_INTERVAL;
                     }
                 }
             }));
             if tick_result.is_err() {
-                eprintln!(
+                log::error!(
                     "[background-notifications] worker tick panicked; continuing notification loop"
                 );
             }
-
-            std::thread::sleep(WORKER_TICK_INTERVAL);
         }
     });
 }
@@ -461,19 +627,61 @@ fn report_latest_message_key(
     )
 }
 
+/// 通知に表示する定型文。件数を含む文言は `{count}` を置き換えて使う。
+struct NotificationTexts {
+    new_message: &'static str,
+    new_announcement: &'static str,
+    status_updated: &'static str,
+    report_center: &'static str,
+    announcement: &'static str,
+    report_count: &'static str,
+    additional_report_count: &'static str,
+    announcement_count: &'static str,
+}
+
+const NOTIFICATION_TEXTS_JA: NotificationTexts = NotificationTexts {
+    new_message: "新しいメッセージを受信しました。",
+    new_announcement: "新しいお知らせがあります。",
+    status_updated: "ステータス更新",
+    report_center: "報告センター",
+    announcement: "お知らせ",
+    report_count: "報告に新着メッセージが{count}件あります。",
+    additional_report_count: "ほかに新着メッセージが{count}件あります。",
+    announcement_count: "新しいお知らせが{count}件あります。",
+};
+
+const NOTIFICATION_TEXTS_EN: NotificationTexts = NotificationTexts {
+    new_message: "New message received.",
+    new_announcement: "New announcement available.",
+    status_updated: "Status updated",
+    report_center: "Report Center",
+    announcement: "Announcement",
+    report_count: "{count} new report message(s).",
+    additional_report_count: "{count} additional new message(s).",
+    announcement_count: "{count} new announcement(s).",
+};
+
+fn notification_texts(locale: &str) -> &'static NotificationTexts {
+    if normalize_locale(locale) == "en" {
+        &NOTIFICATION_TEXTS_EN
+    } else {
+        &NOTIFICATION_TEXTS_JA
+    }
+}
+
+fn format_count(template: &str, count: usize) -> String {
+    template.replace("{count}", &count.to_string())
+}
+
 fn report_notification_body(message_type: &str, content: &str, locale: &str) -> String {
+    let texts = notification_texts(locale);
     if content.trim().is_empty() {
-        return "New message received.".to_string();
+        return texts.new_message.to_string();
     }
 
     let normalized = truncate_chars(content, 120);
     if message_type == "status" {
-        let status_label = if locale == "en" {
-            "Status updated"
-        } else {
-            "ステータス更新"
-        };
-        format!("{status_label}: {normalized}")
+        format!("{}: {normalized}", texts.status_updated)
     } else {
         normalized
     }
@@ -487,11 +695,7 @@ fn is_main_window_visible<R: Runtime>(app: &AppHandle<R>) -> bool {
 
 fn build_announce_client() -> Option<Client> {
     Client::builder()
-        .user_agent(format!(
-            "{}/{}",
-            mod_profile::get().branding.launcher_name,
-            env!("CARGO_PKG_VERSION")
-        ))
+        .user_agent(mod_profile::launcher_user_agent())
         .build()
         .ok()
 }
@@ -564,6 +768,132 @@ async fn fetch_announce_preview(
     Ok(truncate_chars(&plain, ANNOUNCE_PREVIEW_CHARS))
 }
 
+/// RFC 3339 形式の日時をUNIXミリ秒へ変換する。解釈できない場合は None。
+fn parse_rfc3339_millis(value: &str) -> Option<i64> {
+    let value = value.trim();
+    let (date, time) = value.split_once(['T', 't', ' '])?;
+    let mut date_parts = date.splitn(3, '-');
+    let year = parse_fixed_digits(date_parts.next()?, 4)?;
+    let month = parse_fixed_digits(date_parts.next()?, 2)?;
+    let day = parse_fixed_digits(date_parts.next()?, 2)?;
+    if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+        return None;
+    }
+
+    // 末尾のタイムゾーン指定を切り出し、オフセット秒へ変換する。
+    let (clock, offset_seconds) = if let Some(clock) = time.strip_suffix(['Z', 'z']) {
+        (clock, 0)
+    } else {
+        let sign_index = time.rfind(['+', '-'])?;
+        let (clock, offset) = time.split_at(sign_index);
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+        let (hours, minutes) = offset[1..].split_once(':')?;
+        let hours = parse_fixed_digits(hours, 2)?;
+        let minutes = parse_fixed_digits(minutes, 2)?;
+        if hours > 23 || minutes > 59 {
+            return None;
+        }
+        (clock, sign * (hours * 3600 + minutes * 60))
+    };
+
+    let (clock, fraction) = match clock.split_once('.') {
+        Some((clock, fraction)) if !fraction.is_empty() => (clock, fraction),
+        Some(_) => return None,
+        None => (clock, ""),
+    };
+    if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let mut clock_parts = clock.splitn(3, ':');
+    let hour = parse_fixed_digits(clock_parts.next()?, 2)?;
+    let minute = parse_fixed_digits(clock_parts.next()?, 2)?;
+    // うるう秒 (60) は直後の秒として扱う。
+    let second = parse_fixed_digits(clock_parts.next()?, 2)?;
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+    let millis: i64 = format!("{fraction:0<3}").get(..3)?.parse().ok()?;
+
+    // 1970-01-01 からの日数 (proleptic Gregorian) を求める。
+    let (y, m) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era = y.div_euclid(400);
+    let year_of_era = y - era * 400;
+    let day_of_year = (153 * m + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    let seconds = days * 86_400 + hour * 3600 + minute * 60 + second - offset_seconds;
+    Some(seconds * 1000 + millis)
+}
+
+// 符号や空白を許さず、指定桁数の数字だけを受け付ける。
+fn parse_fixed_digits(value: &str, digits: usize) -> Option<i64> {
+    if value.len() != digits || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// 未読の報告スレッド数と、既読時刻より新しいお知らせ数をまとめて返す。
+/// `announce_read_at` はUIが保持する既読済みお知らせの作成日時(UNIXミリ秒)。
+pub async fn unread_counts<R: Runtime>(
+    app: &AppHandle<R>,
+    announce_read_at: Option<i64>,
+) -> UnreadCounts {
+    let mut counts = UnreadCounts::default();
+
+    if mod_profile::feature_enabled(mod_profile::Feature::Reporting) {
+        match reporting_api::list_threads(app).await {
+            Ok(threads) => {
+                counts.report_threads = Some(threads.iter().filter(|thread| thread.unread).count());
+            }
+            Err(error) => log::warn!("Failed to fetch report threads for unread counts: {error}"),
+        }
+    }
+
+    if mod_profile::feature_enabled(mod_profile::Feature::Announce) {
+        let locale = settings::load_or_init_settings(app)
+            .map(|settings| normalize_locale(&settings.ui_locale))
+            .unwrap_or("ja");
+        let items = match build_announce_client() {
+            Some(client) => fetch_announce_list(&client, locale).await,
+            None => Err("Failed to build announce client".to_string()),
+        };
+        match items {
+            Ok(items) => {
+                counts.announcements = Some(
+                    items
+                        .iter()
+                        .filter(|item| match announce_read_at {
+                            Some(read_at) => {
+                                parse_rfc3339_millis(&item.created_at).unwrap_or(0) > read_at
+                            }
+                            // 既読記録がない場合はUIのバッジと同じく全件を未読とみなす。
+                            None => true,
+                        })
+                        .count(),
+                );
+            }
+            Err(error) => log::warn!("Failed to fetch announcements for unread counts: {error}"),
+        }
+    }
+
+    counts
+}
+
 fn condense_whitespace(value: &str) -> String {
     value.split_whitespace().collect::<Vec<_>>().join(" ")
 }
@@ -630,12 +960,20 @@ fn show_background_notification<R: Runtime + 'static>(
 
     let app_handle = app.clone();
     let click_target = target.clone();
-    let app_id = mod_profile::get().branding.identifier.clone();
+    let app_id = notification_app_id(app);
 
-    let _ = Toast::new(&app_id)
+    let mut toast = Toast::new(&app_id)
         .title(title)
         .text1(body)
-        .duration(Duration::Short)
+        .duration(Duration::Short);
+    if let Some(icon) = notification_icon_path() {
+        toast = toast.icon(
+            &icon,
+            tauri_winrt_notification::IconCrop::Square,
+            &mod_profile::get().branding.launcher_name,
+        );
+    }
+    let _ = toast
         .on_activated(move |_| {
             set_pending_open_target(click_target.clone());
             crate::show_main_window_now(&app_handle);
@@ -645,6 +983,72 @@ fn show_background_notification<R: Runtime + 'static>(
         .show();
 }
 
+#[cfg(target_os = "windows")]
+fn notification_icon_path() -> Option<std::path::PathBuf> {
+    let configured = mod_profile::get()
+        .branding
+        .notification_icon
+        .as_deref()?
+        .trim();
+    if configured.is_empty() {
+        return None;
+    }
+    let path = std::path::Path::new(configured);
+    let path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_exe().ok()?.parent()?.join(path)
+    };
+    path.is_file().then_some(path)
+}
+
+// 未登録の AUMID で通知すると Windows が既定アイコンで表示するため、表示名とアイコンを登録する。
+#[cfg(target_os = "windows")]
+fn register_notification_app_id(app_id: &str) -> Result<(), String> {
+    use winreg::{enums::HKEY_CURRENT_USER, RegKey};
+
+    let key_path = format!("Software\\Classes\\AppUserModelId\\{app_id}");
+    let (key, _) = RegKey::predef(HKEY_CURRENT_USER)
+        .create_subkey(&key_path)
+        .map_err(|e| format!("Failed to open notification app id key: {e}"))?;
+    key.set_value("DisplayName", &mod_profile::get().branding.launcher_name)
+        .map_err(|e| format!("Failed to register notification display name: {e}"))?;
+    match notification_icon_path() {
+        Some(icon) => key
+            .set_value("IconUri", &icon.to_string_lossy().to_string())
+            .map_err(|e| format!("Failed to register notification icon: {e}"))?,
+        None => {
+            // 設定から外された古いアイコンが残らないようにする。
+            let _ = key.delete_value("IconUri");
+        }
+    }
+    Ok(())
+}
+
+/// 通知に使う AUMID を返す。インストーラーが登録する tauri の identifier をそのまま使う。
+/// 登録できなかった場合も通知自体は出せるよう PowerShell の ID で代用し、その旨をログに残す。
+#[cfg(target_os = "windows")]
+fn notification_app_id<R: Runtime>(app: &AppHandle<R>) -> String {
+    static REGISTERED: OnceLock<bool> = OnceLock::new();
+    let app_id = app.config().identifier.clone();
+    let registered = *REGISTERED.get_or_init(|| match register_notification_app_id(&app_id) {
+        Ok(()) => true,
+        Err(error) => {
+            log::warn!(
+                "Failed to register notification app id '{app_id}': {error}; \
+                 notifications fall back to the PowerShell app id and will be shown as \"Windows PowerShell\""
+            );
+            false
+        }
+    });
+    if registered {
+        app_id
+    } else {
+        log::debug!("Using PowerShell app id for notification instead of '{app_id}'");
+        tauri_winrt_notification::Toast::POWERSHELL_APP_ID.to_string()
+    }
+}
+
 #[cfg(not(target_os = "windows"))]
 fn show_background_notification<R: Runtime + 'static>(
     _app: &AppHandle<R>,
@@ -653,3 +1057,107 @@ fn show_background_notification<R: Runtime + 'static>(
     _target: NotificationOpenTarget,
 ) {
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rfc3339_millis_handles_utc_suffix() {
+        assert_eq!(parse_rfc3339_millis("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(
+            parse_rfc3339_millis("2024-01-02T03:04:05Z"),
+            Some(1_704_164_645_000)
+        );
+        assert_eq!(
+            parse_rfc3339_millis("2024-01-02t03:04:05z"),
+            Some(1_704_164_645_000)
+        );
+        assert_eq!(
+            parse_rfc3339_millis("2024-01-02 03:04:05Z"),
+            Some(1_704_164_645_000)
+        );
+    }
+
+    #[test]
+    fn parse_rfc3339_millis_reads_fractional_seconds() {
+        assert_eq!(parse_rfc3339_millis("1970-01-01T00:00:00.5Z"), Some(500));
+        assert_eq!(parse_rfc3339_millis("1970-01-01T00:00:00.12Z"), Some(120));
+        assert_eq!(parse_rfc3339_millis("1970-01-01T00:00:00.123Z"), Some(123));
+        assert_eq!(
+            parse_rfc3339_millis("1970-01-01T00:00:00.123456Z"),
+            Some(123)
+        );
+    }
+
+    #[test]
+    fn parse_rfc3339_millis_applies_offsets() {
+        let utc = parse_rfc3339_millis("2024-01-02T03:04:05Z");
+        assert_eq!(parse_rfc3339_millis("2024-01-02T12:04:05+09:00"), utc);
+        assert_eq!(parse_rfc3339_millis("2024-01-01T21:34:05-05:30"), utc);
+        assert_eq!(parse_rfc3339_millis("2024-01-02T03:04:05+00:00"), utc);
+        assert_eq!(
+            parse_rfc3339_millis("2024-01-02T03:04:05.250-00:00"),
+            utc.map(|ms| ms + 250)
+        );
+    }
+
+    #[test]
+    fn parse_rfc3339_millis_handles_leap_days() {
+        assert_eq!(
+            parse_rfc3339_millis("2024-02-29T00:00:00Z"),
+            Some(1_709_164_800_000)
+        );
+        assert_eq!(
+            parse_rfc3339_millis("2000-02-29T00:00:00Z"),
+            Some(951_782_400_000)
+        );
+        assert_eq!(
+            parse_rfc3339_millis("2024-03-01T00:00:00Z"),
+            parse_rfc3339_millis("2024-02-29T00:00:00Z").map(|ms| ms + 86_400_000)
+        );
+        assert_eq!(parse_rfc3339_millis("2023-02-29T00:00:00Z"), None);
+        assert_eq!(parse_rfc3339_millis("1900-02-29T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn parse_rfc3339_millis_rejects_malformed_input() {
+        for invalid in [
+            "",
+            "2024-01-02",
+            "2024-01-02T03:04:05",
+            "2024-01-02T03:04Z",
+            "2024-13-01T00:00:00Z",
+            "2024-00-01T00:00:00Z",
+            "2024-04-31T00:00:00Z",
+            "2024-01-02T24:00:00Z",
+            "2024-01-02T03:60:00Z",
+            "2024-01-02T03:04:05.Z",
+            "2024-01-02T03:04:05.12aZ",
+            "2024-01-02T03:04:05+0900",
+            "2024-01-02T03:04:05+24:00",
+            "2024-1-02T03:04:05Z",
+            "+024-01-02T03:04:05Z",
+            "not a date",
+        ] {
+            assert_eq!(parse_rfc3339_millis(invalid), None, "{invalid:?}");
+        }
+    }
+
+    #[test]
+    fn count_only_texts_follow_locale() {
+        assert_eq!(
+            format_count(notification_texts("en").report_count, 3),
+            "3 new report message(s)."
+        );
+        assert_eq!(
+            format_count(notification_texts("ja").announcement_count, 2),
+            "新しいお知らせが2件あります。"
+        );
+        // 想定外のロケールは通知APIと同じく日本語へ寄せる。
+        assert_eq!(
+            notification_texts("fr").report_count,
+            NOTIFICATION_TEXTS_JA.report_count
+        );
+    }
+}
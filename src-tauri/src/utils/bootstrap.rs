@@ -0,0 +1,77 @@
+// 初回起動時の準備処理をまとめる。
+// 各機能が初回利用時に個別で作っていた標準ディレクトリと既定設定を、起動時に一括で整える。
+use serde::Serialize;
+use std::fs;
+use tauri::{AppHandle, Runtime};
+
+use crate::utils::{logger, mod_profile, settings};
+
+pub const CACHE_DIR_NAME: &str = "cache";
+pub const PRESERVED_SAVE_DATA_DIR_NAME: &str = "preserved_save_data";
+pub const MIGRATION_BACKUPS_DIR_NAME: &str = "migration-import-backups";
+pub const PROFILES_DIR_NAME: &str = "profiles";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppBootstrapSummary {
+    pub app_data_dir: String,
+    pub created_directories: Vec<String>,
+    pub first_run: bool,
+    pub profile_path: String,
+    pub profile_path_initialized: bool,
+}
+
+fn standard_subdirectories() -> [&'static str; 5] {
+    [
+        CACHE_DIR_NAME,
+        logger::LOG_DIR_NAME,
+        MIGRATION_BACKUPS_DIR_NAME,
+        PRESERVED_SAVE_DATA_DIR_NAME,
+        PROFILES_DIR_NAME,
+    ]
+}
+
+/// アプリデータ配下の標準ディレクトリと既定設定を用意する。何度呼んでも結果は変わらない。
+pub fn app_bootstrap<R: Runtime>(app: &AppHandle<R>) -> Result<AppBootstrapSummary, String> {
+    mod_profile::validate()?;
+
+    let app_data_dir = settings::app_data_dir(app)?;
+    let mut created_directories = Vec::new();
+    let targets = std::iter::once(app_data_dir.clone()).chain(
+        standard_subdirectories()
+            .into_iter()
+            .map(|name| app_data_dir.join(name)),
+    );
+    for directory in targets {
+        if directory.is_dir() {
+            continue;
+        }
+        fs::create_dir_all(&directory).map_err(|e| {
+            format!(
+                "Failed to create app data directory '{}': {e}",
+                directory.display()
+            )
+        })?;
+        created_directories.push(directory.to_string_lossy().to_string());
+    }
+
+    let first_run = !settings::settings_file_exists(app)?;
+    let mut launcher_settings = settings::load_or_init_global_settings(app)?;
+    // 初回は既定値が保存される。保存先が空のまま残っている場合もここで既定値を確定させる。
+    let mut profile_path_initialized = first_run;
+    if launcher_settings.profile_path.trim().is_empty() {
+        launcher_settings.profile_path = settings::default_profile_path(app)?
+            .to_string_lossy()
+            .to_string();
+        settings::save_settings(app, &launcher_settings)?;
+        profile_path_initialized = true;
+    }
+
+    Ok(AppBootstrapSummary {
+        app_data_dir: app_data_dir.to_string_lossy().to_string(),
+        created_directories,
+        first_run,
+        profile_path: launcher_settings.profile_path,
+        profile_path_initialized,
+    })
+}
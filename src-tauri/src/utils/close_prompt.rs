@@ -0,0 +1,153 @@
+// メインウィンドウを閉じたときに、トレイへ最小化するか終了するかを確認する。
+// ネイティブのメッセージダイアログにはチェックボックスがないため、
+// 「選択を記憶する」は続けて表示する確認ダイアログで尋ねる。
+use tauri::{AppHandle, Emitter, Runtime};
+use tauri_plugin_dialog::{
+    DialogExt, MessageDialogButtons, MessageDialogKind, MessageDialogResult,
+};
+
+use crate::utils::settings::{self, LauncherSettingsInput};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloseAction {
+    MinimizeToTray,
+    Exit,
+}
+
+struct PromptTexts {
+    title: &'static str,
+    message: &'static str,
+    minimize: &'static str,
+    exit: &'static str,
+    cancel: &'static str,
+    remember: &'static str,
+}
+
+/// ロケールごとの確認ダイアログ文言。`SUPPORTED_UI_LOCALES` のすべてを揃える。
+const PROMPT_TEXTS: &[(&str, PromptTexts)] = &[
+    (
+        "ja",
+        PromptTexts {
+            title: "ウィンドウを閉じる",
+            message: "トレイに最小化しますか？それともランチャーを終了しますか？",
+            minimize: "トレイに最小化",
+            exit: "終了",
+            cancel: "キャンセル",
+            remember: "この選択を記憶しますか？あとから設定で変更できます。",
+        },
+    ),
+    (
+        "en",
+        PromptTexts {
+            title: "Close window",
+            message: "Minimize to the tray, or exit the launcher?",
+            minimize: "Minimize to tray",
+            exit: "Exit",
+            cancel: "Cancel",
+            remember: "Remember this choice? You can change it later in Settings.",
+        },
+    ),
+];
+
+fn prompt_texts(locale: &str) -> &'static PromptTexts {
+    // 想定外のロケールは設定と同じく既定ロケールへ寄せる。
+    let locale = settings::supported_ui_locale(locale).unwrap_or(settings::SUPPORTED_UI_LOCALES[0]);
+    PROMPT_TEXTS
+        .iter()
+        .find(|(code, _)| *code == locale)
+        .or_else(|| PROMPT_TEXTS.first())
+        .map(|(_, texts)| texts)
+        .expect("PROMPT_TEXTS must not be empty")
+}
+
+fn resolve_close_action(result: &MessageDialogResult, texts: &PromptTexts) -> Option<CloseAction> {
+    // OSによってはカスタムボタンでも Yes/No として返るため、両方を受け付ける。
+    match result {
+        MessageDialogResult::Yes => Some(CloseAction::MinimizeToTray),
+        MessageDialogResult::No => Some(CloseAction::Exit),
+        MessageDialogResult::Custom(label) if label == texts.minimize => {
+            Some(CloseAction::MinimizeToTray)
+        }
+        MessageDialogResult::Custom(label) if label == texts.exit => Some(CloseAction::Exit),
+        _ => None,
+    }
+}
+
+fn remember_close_action<R: Runtime>(app: &AppHandle<R>, action: CloseAction) {
+    match settings::apply_settings_input(
+        app,
+        LauncherSettingsInput {
+            close_to_tray_on_close: Some(action == CloseAction::MinimizeToTray),
+            close_action_prompt: Some(false),
+            ..LauncherSettingsInput::default()
+        },
+    ) {
+        Ok(updated) => {
+            let _ = app.emit(settings::SETTINGS_CHANGED_EVENT, updated);
+        }
+        Err(error) => log::warn!("Failed to save close action choice: {error}"),
+    }
+}
+
+/// 閉じる操作の扱いをダイアログで確認し、決まった操作を `on_decided` へ渡す。
+/// キャンセル時は何もしない。記憶を選ぶと以降は確認せず設定に従う。
+pub fn prompt_close_action<R, F>(app: &AppHandle<R>, on_decided: F)
+where
+    R: Runtime,
+    F: FnOnce(CloseAction) + Send + 'static,
+{
+    let locale = settings::load_or_init_settings(app)
+        .map(|launcher_settings| launcher_settings.ui_locale)
+        .unwrap_or_default();
+    let texts = prompt_texts(&locale);
+    let app_for_result = app.clone();
+    app.dialog()
+        .message(texts.message)
+        .title(texts.title)
+        .kind(MessageDialogKind::Info)
+        .buttons(MessageDialogButtons::YesNoCancelCustom(
+            texts.minimize.to_string(),
+            texts.exit.to_string(),
+            texts.cancel.to_string(),
+        ))
+        .show_with_result(move |result| {
+            let Some(action) = resolve_close_action(&result, texts) else {
+                return;
+            };
+            let app = app_for_result.clone();
+            app_for_result
+                .dialog()
+                .message(texts.remember)
+                .title(texts.title)
+                .kind(MessageDialogKind::Info)
+                .buttons(MessageDialogButtons::YesNo)
+                .show_with_result(move |remember| {
+                    if matches!(remember, MessageDialogResult::Yes) {
+                        remember_close_action(&app, action);
+                    }
+                    on_decided(action);
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prompt_texts_cover_every_supported_locale() {
+        for locale in settings::SUPPORTED_UI_LOCALES {
+            assert!(
+                PROMPT_TEXTS.iter().any(|(code, _)| code == locale),
+                "missing close prompt texts for {locale}"
+            );
+        }
+    }
+
+    #[test]
+    fn prompt_texts_fall_back_to_default_locale() {
+        let default = prompt_texts(settings::SUPPORTED_UI_LOCALES[0]);
+        assert_eq!(prompt_texts("fr").title, default.title);
+        assert_eq!(prompt_texts(" EN ").title, "Close window");
+    }
+}
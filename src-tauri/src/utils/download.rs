@@ -1,65 +1,200 @@
 // HTTPダウンロード処理と進捗通知の共通ユーティリティ。
 use futures_util::StreamExt;
-use reqwest::Client;
-use std::fs::{self, File};
-use std::io::Write;
-use std::path::Path;
+use reqwest::header::{CONTENT_RANGE, RANGE};
+use reqwest::{Client, StatusCode};
+use sha2::{Digest, Sha256};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
+use crate::utils::mod_profile;
+
 const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
 const REQUEST_TIMEOUT: Duration = Duration::from_secs(600);
-const USER_AGENT: &str = "SuperNewRolesLauncher/0.1";
 const DOWNLOAD_PROGRESS_MIN_INTERVAL: Duration = Duration::from_millis(120);
 const DOWNLOAD_PROGRESS_MIN_BYTES_DELTA: u64 = 512 * 1024;
 const DOWNLOAD_PROGRESS_MIN_PERCENT_DELTA: f64 = 1.0;
+const PARTIAL_DOWNLOAD_SUFFIX: &str = ".part";
+const DOWNLOAD_CANCELLED_ERROR: &str = "Download was cancelled.";
 
 pub fn github_client() -> Result<Client, String> {
     // すべての配布取得で同一タイムアウト設定を使う。
     Client::builder()
-        .user_agent(USER_AGENT)
+        .user_agent(mod_profile::launcher_user_agent())
         .connect_timeout(CONNECT_TIMEOUT)
         .timeout(REQUEST_TIMEOUT)
         .build()
         .map_err(|e| format!("Failed to create HTTP client: {e}"))
 }
 
-pub async fn download_file<F>(
+/// ファイル内容のSHA256を16進文字列で返す。
+pub fn sha256_file(path: &Path) -> Result<String, String> {
+    let mut file =
+        File::open(path).map_err(|e| format!("Failed to open '{}': {e}", path.display()))?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher)
+        .map_err(|e| format!("Failed to read '{}': {e}", path.display()))?;
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect())
+}
+
+/// 途中まで受信したデータを保持する一時ファイルのパス。
+pub fn partial_download_path(destination: &Path) -> PathBuf {
+    let mut file_name = destination
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    file_name.push(PARTIAL_DOWNLOAD_SUFFIX);
+    destination.with_file_name(file_name)
+}
+
+// Content-Range: bytes <start>-<end>/<total> の開始位置を取り出す。
+fn content_range_start(value: &str) -> Option<u64> {
+    value
+        .trim()
+        .strip_prefix("bytes ")?
+        .split('-')
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Range 要求に対する応答の扱い。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResumeDecision {
+    /// 要求した位置からの続きなので、途中ファイルへ追記する。
+    Resume,
+    /// 全体が返ってきたため、途中ファイルを捨ててこの応答を最初から使う。
+    Restart,
+    /// 続きとして使えないため、途中ファイルを捨てて取り直す。
+    Retry,
+}
+
+fn resume_decision(
+    status: StatusCode,
+    content_range: Option<&str>,
+    resume_offset: u64,
+) -> ResumeDecision {
+    if status == StatusCode::PARTIAL_CONTENT {
+        // 開始位置が異なる部分応答を追記すると内容が壊れるため、一致する場合だけ続きとして扱う。
+        return if content_range.and_then(content_range_start) == Some(resume_offset) {
+            ResumeDecision::Resume
+        } else {
+            ResumeDecision::Retry
+        };
+    }
+    if status.is_success() {
+        return ResumeDecision::Restart;
+    }
+    // 416 など続きから取得できない場合。
+    ResumeDecision::Retry
+}
+
+/// 前回の途中ファイルがあれば Range 要求で続きから取得する。
+/// サーバーが Range を無視した場合や範囲外と返した場合は最初から取り直す。
+async fn request_download(
+    client: &Client,
+    url: &str,
+    partial_path: &Path,
+) -> Result<(reqwest::Response, u64), String> {
+    let resume_offset = fs::metadata(partial_path)
+        .map(|metadata| metadata.len())
+        .unwrap_or(0);
+    if resume_offset > 0 {
+        let response = client
+            .get(url)
+            .header(RANGE, format!("bytes={resume_offset}-"))
+            .send()
+            .await
+            .map_err(|e| format!("Download request failed: {e}"))?;
+        let content_range = response
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(|value| value.to_str().ok());
+        match resume_decision(response.status(), content_range, resume_offset) {
+            ResumeDecision::Resume => return Ok((response, resume_offset)),
+            ResumeDecision::Restart => {
+                let _ = fs::remove_file(partial_path);
+                return Ok((response, 0));
+            }
+            ResumeDecision::Retry => {
+                let _ = fs::remove_file(partial_path);
+            }
+        }
+    }
+
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("Download request failed: {e}"))?;
+    Ok((response, 0))
+}
+
+/// `url` を `destination` へダウンロードする。チャンク受信ごとに中断要求を確認する。
+/// 中断時は書きかけのファイルを削除して `DOWNLOAD_CANCELLED_ERROR` を返す。
+pub async fn download_file_cancellable<F>(
     client: &Client,
     url: &str,
     destination: &Path,
+    cancel_requested: Option<&AtomicBool>,
     mut on_progress: F,
 ) -> Result<(), String>
 where
     F: FnMut(u64, Option<u64>),
 {
-    // まず0%相当を通知して、UI側で初期描画できるようにする。
-    // 総サイズ未確定でも downloaded=0 を先に渡して進捗バーの状態を固定する。
-    let response = client
-        .get(url)
-        .send()
-        .await
-        .map_err(|e| format!("Download request failed: {e}"))?;
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create cache directory: {e}"))?;
+    }
+
+    // 接続断で途切れても再開できるよう、受信中は .part へ書き込む。
+    let partial_path = partial_download_path(destination);
+    let (response, resume_offset) = request_download(client, url, &partial_path).await?;
 
     if !response.status().is_success() {
         return Err(format!("Download failed with status {}", response.status()));
     }
 
-    if let Some(parent) = destination.parent() {
-        fs::create_dir_all(parent).map_err(|e| format!("Failed to create cache directory: {e}"))?;
-    }
-
-    let total_size = response.content_length();
-    let mut file =
-        File::create(destination).map_err(|e| format!("Failed to create download file: {e}"))?;
+    // 再開時も進捗が巻き戻らないよう、既存分を含めた値で通知する。
+    let total_size = response
+        .content_length()
+        .map(|length| resume_offset + length);
+    let mut file = if resume_offset > 0 {
+        OpenOptions::new()
+            .append(true)
+            .open(&partial_path)
+            .map_err(|e| format!("Failed to open partial download file: {e}"))?
+    } else {
+        File::create(&partial_path).map_err(|e| format!("Failed to create download file: {e}"))?
+    };
     let mut stream = response.bytes_stream();
 
-    let mut downloaded = 0_u64;
-    let mut last_emitted_downloaded = 0_u64;
-    let mut last_emitted_percent = Some(0.0_f64);
+    // まず現在値を通知して、UI側で初期描画できるようにする。
+    // 総サイズ未確定でも downloaded を先に渡して進捗バーの状態を固定する。
+    let mut downloaded = resume_offset;
+    let mut last_emitted_downloaded = downloaded;
+    let mut last_emitted_percent = Some(
+        total_size
+            .filter(|total| *total > 0)
+            .map(|total| (downloaded as f64 / total as f64) * 100.0)
+            .unwrap_or(0.0),
+    );
     let mut last_emitted_at = Instant::now();
     on_progress(downloaded, total_size);
 
     while let Some(chunk) = stream.next().await {
+        if cancel_requested.is_some_and(|flag| flag.load(Ordering::SeqCst)) {
+            drop(file);
+            let _ = fs::remove_file(&partial_path);
+            return Err(DOWNLOAD_CANCELLED_ERROR.to_string());
+        }
+        // 通信エラー時は .part を残し、次回の取得で続きから再開する。
         let chunk = chunk.map_err(|e| format!("Download stream failed: {e}"))?;
         file.write_all(&chunk)
             .map_err(|e| format!("Failed to write download chunk: {e}"))?;
@@ -86,10 +221,68 @@ where
         }
     }
 
+    file.flush()
+        .map_err(|e| format!("Failed to flush download file: {e}"))?;
+    drop(file);
+
+    // 再開オフセットを含めたサイズが一致しない場合は、途中ファイルごと破棄する。
+    if let Some(expected) = total_size {
+        if downloaded != expected {
+            let _ = fs::remove_file(&partial_path);
+            return Err(format!(
+                "Downloaded size mismatch (expected {expected} bytes, got {downloaded} bytes)"
+            ));
+        }
+    }
+
     if downloaded != last_emitted_downloaded {
         // 最後の小さな差分がしきい値未満でも、完了値だけは必ず反映する。
         on_progress(downloaded, total_size);
     }
 
-    Ok(())
+    fs::rename(&partial_path, destination).map_err(|e| {
+        format!(
+            "Failed to move downloaded file into place '{}': {e}",
+            destination.display()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_range_start_parses_only_byte_ranges() {
+        assert_eq!(content_range_start("bytes 100-199/200"), Some(100));
+        assert_eq!(content_range_start(" bytes 0-9/10 "), Some(0));
+        assert_eq!(content_range_start("bytes */200"), None);
+        assert_eq!(content_range_start("items 100-199/200"), None);
+        assert_eq!(content_range_start("bytes abc-199/200"), None);
+        assert_eq!(content_range_start(""), None);
+    }
+
+    #[test]
+    fn resume_decision_appends_only_matching_partial_content() {
+        assert_eq!(
+            resume_decision(StatusCode::PARTIAL_CONTENT, Some("bytes 100-199/200"), 100),
+            ResumeDecision::Resume
+        );
+        assert_eq!(
+            resume_decision(StatusCode::PARTIAL_CONTENT, Some("bytes 50-199/200"), 100),
+            ResumeDecision::Retry
+        );
+        assert_eq!(
+            resume_decision(StatusCode::PARTIAL_CONTENT, None, 100),
+            ResumeDecision::Retry
+        );
+        assert_eq!(
+            resume_decision(StatusCode::OK, None, 100),
+            ResumeDecision::Restart
+        );
+        assert_eq!(
+            resume_decision(StatusCode::RANGE_NOT_SATISFIABLE, Some("bytes */200"), 100),
+            ResumeDecision::Retry
+        );
+    }
 }
@@ -19,6 +19,12 @@ static STORAGE: OnceLock<KeyringStorage<EpicSession>> = OnceLock::new();
 static SESSION_CACHE: OnceLock<Mutex<Option<EpicSession>>> = OnceLock::new();
 static STORAGE_SERVICE_NAME: OnceLock<&'static str> = OnceLock::new();
 static FALLBACK_SESSION_DIR_NAME: OnceLock<&'static str> = OnceLock::new();
+// 暗号化済みフォールバックファイルの先頭に付ける形式識別子。無ければ旧来の平文JSON。
+#[cfg_attr(not(windows), allow(dead_code))]
+const DPAPI_SESSION_PREFIX: &str = "dpapi:";
+// 復号時にUIを表示させないためのフラグ(CRYPTPROTECT_UI_FORBIDDEN)。
+#[cfg(windows)]
+const DPAPI_UI_FORBIDDEN: u32 = 0x1;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EpicSession {
@@ -37,6 +43,21 @@ struct GameTokenResponse {
     code: String,
 }
 
+/// ゲームトークン取得失敗の種別。再ログイン要否の判定に使う。
+#[derive(Debug, Clone)]
+pub enum GameTokenError {
+    Network(String),
+    Rejected(String),
+}
+
+impl GameTokenError {
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Network(message) | Self::Rejected(message) => message,
+        }
+    }
+}
+
 pub struct EpicApi {
     client: Client,
 }
@@ -89,6 +110,16 @@ impl EpicApi {
     }
 
     pub async fn get_game_token(&self, session: &EpicSession) -> Result<String, String> {
+        self.request_game_token(session)
+            .await
+            .map_err(|error| error.message().to_string())
+    }
+
+    /// ゲーム起動用の短命コードを発行し、失敗時は通信障害か認証拒否かを区別して返す。
+    pub async fn request_game_token(
+        &self,
+        session: &EpicSession,
+    ) -> Result<String, GameTokenError> {
         // ゲーム起動用の短命コードをアクセストークンから発行する。
         let response = self
             .client
@@ -96,20 +127,30 @@ impl EpicApi {
             .header("Authorization", format!("Bearer {}", session.access_token))
             .send()
             .await
-            .map_err(|e| format!("Failed to request Epic game token: {e}"))?;
+            .map_err(|e| {
+                GameTokenError::Network(format!("Failed to request Epic game token: {e}"))
+            })?;
 
         if !response.status().is_success() {
             // Epic側エラー本文を添えて調査しやすくする。
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            return Err(format!("Failed to get Epic game token ({status}): {body}"));
+            let message = format!("Failed to get Epic game token ({status}): {body}");
+            // 5xx はEpic側障害のため、再ログインでは解決しない通信エラーとして扱う。
+            return Err(if status.is_server_error() {
+                GameTokenError::Network(message)
+            } else {
+                GameTokenError::Rejected(message)
+            });
         }
 
         response
             .json::<GameTokenResponse>()
             .await
             .map(|payload| payload.code)
-            .map_err(|e| format!("Failed to parse Epic game token response: {e}"))
+            .map_err(|e| {
+                GameTokenError::Network(format!("Failed to parse Epic game token response: {e}"))
+            })
     }
 
     async fn oauth_request(&self, params: &[(&str, &str)]) -> Result<EpicSession, String> {
@@ -209,14 +250,118 @@ fn save_session_fallback_file(session: &EpicSession) -> Result<(), String> {
 
     let json =
         serde_json::to_string(session).map_err(|e| format!("Failed to serialize session: {e}"))?;
-    fs::write(&path, json).map_err(|e| format!("Failed to write fallback session file: {e}"))?;
+    let content = encode_session_file_content(&json)?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write fallback session file: {e}"))?;
     Ok(())
 }
 
+/// Windowsではユーザー・端末に紐づくDPAPIで暗号化し、別環境へコピーしても復号できないようにする。
+#[cfg(windows)]
+fn encode_session_file_content(json: &str) -> Result<String, String> {
+    let encrypted = dpapi_protect(json.as_bytes())?;
+    Ok(format!("{DPAPI_SESSION_PREFIX}{}", B64.encode(encrypted)))
+}
+
+#[cfg(not(windows))]
+fn encode_session_file_content(json: &str) -> Result<String, String> {
+    Ok(json.to_string())
+}
+
+/// 読み込んだセッションと、旧形式(平文)から読み込んだかどうかを返す。
+fn decode_session_file_content(content: &str) -> Option<(EpicSession, bool)> {
+    let trimmed = content.trim();
+    if let Some(encoded) = trimmed.strip_prefix(DPAPI_SESSION_PREFIX) {
+        let encrypted = B64.decode(encoded).ok()?;
+        let decrypted = dpapi_unprotect(&encrypted)?;
+        let session = serde_json::from_slice::<EpicSession>(&decrypted).ok()?;
+        return Some((session, false));
+    }
+    let session = serde_json::from_str::<EpicSession>(trimmed).ok()?;
+    Some((session, cfg!(windows)))
+}
+
+#[cfg(windows)]
+fn dpapi_protect(data: &[u8]) -> Result<Vec<u8>, String> {
+    use windows::core::PCWSTR;
+    use windows::Win32::Security::Cryptography::{CryptProtectData, CRYPT_INTEGER_BLOB};
+
+    let input = CRYPT_INTEGER_BLOB {
+        cbData: data.len() as u32,
+        pbData: data.as_ptr() as *mut u8,
+    };
+    let mut output = CRYPT_INTEGER_BLOB::default();
+    // 入力バッファは呼び出し中のみ参照され、書き換えられない。
+    unsafe {
+        CryptProtectData(
+            &input,
+            PCWSTR::null(),
+            None,
+            None,
+            None,
+            DPAPI_UI_FORBIDDEN,
+            &mut output,
+        )
+    }
+    .map_err(|e| format!("Failed to encrypt Epic session: {e}"))?;
+    Ok(take_dpapi_output(output))
+}
+
+#[cfg(windows)]
+fn dpapi_unprotect(data: &[u8]) -> Option<Vec<u8>> {
+    use windows::Win32::Security::Cryptography::{CryptUnprotectData, CRYPT_INTEGER_BLOB};
+
+    let input = CRYPT_INTEGER_BLOB {
+        cbData: data.len() as u32,
+        pbData: data.as_ptr() as *mut u8,
+    };
+    let mut output = CRYPT_INTEGER_BLOB::default();
+    // 別ユーザー・別端末で暗号化されたデータはここで失敗する。
+    unsafe {
+        CryptUnprotectData(
+            &input,
+            None,
+            None,
+            None,
+            None,
+            DPAPI_UI_FORBIDDEN,
+            &mut output,
+        )
+    }
+    .ok()?;
+    Some(take_dpapi_output(output))
+}
+
+#[cfg(not(windows))]
+fn dpapi_unprotect(_data: &[u8]) -> Option<Vec<u8>> {
+    None
+}
+
+/// DPAPIが確保した出力バッファを複製し、LocalFreeで解放する。
+#[cfg(windows)]
+fn take_dpapi_output(
+    output: windows::Win32::Security::Cryptography::CRYPT_INTEGER_BLOB,
+) -> Vec<u8> {
+    use windows::Win32::Foundation::{LocalFree, HLOCAL};
+
+    if output.pbData.is_null() {
+        return Vec::new();
+    }
+    // pbData は成功時にDPAPIが cbData バイト分確保した領域を指す。
+    let bytes =
+        unsafe { std::slice::from_raw_parts(output.pbData, output.cbData as usize) }.to_vec();
+    let _ = unsafe { LocalFree(HLOCAL(output.pbData as _)) };
+    bytes
+}
+
 fn load_session_fallback_file() -> Option<EpicSession> {
     let path = fallback_session_path()?;
     let content = fs::read_to_string(path).ok()?;
-    serde_json::from_str::<EpicSession>(&content).ok()
+    let (session, legacy_plaintext) = decode_session_file_content(&content)?;
+    if legacy_plaintext {
+        // 旧形式の平文ファイルは初回読み込み時に暗号化形式へ置き換える。
+        let _ = save_session_fallback_file(&session);
+    }
+    Some(session)
 }
 
 fn clear_session_fallback_file() -> Result<(), String> {
@@ -0,0 +1,54 @@
+// ネイティブのファイル選択ダイアログを開き、選択結果をパスとして返す。
+// キャンセルは Ok(None) で表し、呼び出し側でエラーと区別できるようにする。
+use std::path::PathBuf;
+
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_dialog::{DialogExt, FilePath};
+
+fn into_selected_path(selected: Option<FilePath>) -> Result<Option<PathBuf>, String> {
+    selected
+        .map(|path| {
+            path.into_path()
+                .map_err(|e| format!("Failed to resolve selected file path: {e}"))
+        })
+        .transpose()
+}
+
+/// 指定拡張子で絞り込んだ「開く」ダイアログを表示する。
+pub async fn pick_open_path<R: Runtime>(
+    app: &AppHandle<R>,
+    filter_name: &str,
+    extensions: Vec<&'static str>,
+) -> Result<Option<PathBuf>, String> {
+    let app = app.clone();
+    let filter_name = filter_name.to_string();
+    // メインスレッドを塞がないよう、ダイアログの完了待ちは別スレッドで行う。
+    let selected = tauri::async_runtime::spawn_blocking(move || {
+        app.dialog()
+            .file()
+            .add_filter(filter_name, &extensions)
+            .blocking_pick_file()
+    })
+    .await
+    .map_err(|e| format!("File dialog task failed: {e}"))?;
+    into_selected_path(selected)
+}
+
+/// 拡張子とファイル名を初期入力した「名前を付けて保存」ダイアログを表示する。
+pub async fn pick_save_path<R: Runtime>(
+    app: &AppHandle<R>,
+    extension: &'static str,
+    file_name: String,
+) -> Result<Option<PathBuf>, String> {
+    let app = app.clone();
+    let selected = tauri::async_runtime::spawn_blocking(move || {
+        app.dialog()
+            .file()
+            .add_filter(extension, &[extension])
+            .set_file_name(file_name)
+            .blocking_save_file()
+    })
+    .await
+    .map_err(|e| format!("File dialog task failed: {e}"))?;
+    into_selected_path(selected)
+}
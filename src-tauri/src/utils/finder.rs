@@ -45,28 +45,23 @@ fn parse_registry_icon_value(raw_value: &str) -> Option<PathBuf> {
 }
 
 #[cfg(target_os = "windows")]
-fn detect_from_registry() -> Option<PathBuf> {
+fn registry_candidates() -> Vec<PathBuf> {
     let hkcr = RegKey::predef(HKEY_CLASSES_ROOT);
 
-    for key_name in ["AmongUs", "amongus"] {
-        let path = hkcr
-            .open_subkey(key_name)
-            .ok()
-            .and_then(|key| key.open_subkey("DefaultIcon").ok())
-            .and_then(|icon_key| icon_key.get_value::<String, _>("").ok())
-            .and_then(|raw| parse_registry_icon_value(&raw))
-            .filter(|candidate| verify_among_us_directory(candidate));
-
-        if path.is_some() {
-            return path;
-        }
-    }
-
-    None
+    ["AmongUs", "amongus"]
+        .into_iter()
+        .filter_map(|key_name| {
+            hkcr.open_subkey(key_name)
+                .ok()
+                .and_then(|key| key.open_subkey("DefaultIcon").ok())
+                .and_then(|icon_key| icon_key.get_value::<String, _>("").ok())
+                .and_then(|raw| parse_registry_icon_value(&raw))
+        })
+        .collect()
 }
 
 #[cfg(target_os = "windows")]
-fn detect_common_paths() -> Vec<PathBuf> {
+fn common_path_candidates() -> Vec<PathBuf> {
     let mut candidates = Vec::new();
 
     if let Some(program_files_x86) = std::env::var_os("ProgramFiles(x86)") {
@@ -86,21 +81,18 @@ fn detect_common_paths() -> Vec<PathBuf> {
     }
 
     candidates
-        .into_iter()
-        .filter(|path| verify_among_us_directory(path))
-        .collect()
 }
 
-pub fn get_among_us_paths() -> Vec<PathBuf> {
+/// 検証前の探索候補を優先順に返す。
+pub fn among_us_candidate_paths() -> Vec<PathBuf> {
     #[cfg(target_os = "windows")]
     {
-        let mut paths = Vec::new();
-        if let Some(path) = detect_from_registry() {
-            paths.push(path);
-        }
-
-        // 共通候補を追加しつつ、重複は除外して順序を維持する。
-        for path in detect_common_paths() {
+        let mut paths: Vec<PathBuf> = Vec::new();
+        // レジストリ候補を優先し、重複は除外して順序を維持する。
+        for path in registry_candidates()
+            .into_iter()
+            .chain(common_path_candidates())
+        {
             if !paths.contains(&path) {
                 paths.push(path);
             }
@@ -113,6 +105,40 @@ pub fn get_among_us_paths() -> Vec<PathBuf> {
     Vec::new()
 }
 
+/// 候補の確認開始と検出を逐次通知しながら探索する。
+/// `is_cancelled` が true を返した時点で打ち切り、それまでの検出結果と中断有無を返す。
+pub fn scan_among_us_paths<F, G, C>(
+    mut on_checking: F,
+    mut on_found: G,
+    is_cancelled: C,
+) -> (Vec<PathBuf>, bool)
+where
+    F: FnMut(usize, usize, &Path),
+    G: FnMut(usize, usize, &Path),
+    C: Fn() -> bool,
+{
+    let candidates = among_us_candidate_paths();
+    let total = candidates.len();
+    let mut found = Vec::new();
+
+    for (index, candidate) in candidates.iter().enumerate() {
+        if is_cancelled() {
+            return (found, true);
+        }
+        on_checking(index, total, candidate);
+        if verify_among_us_directory(candidate) {
+            on_found(index, total, candidate);
+            found.push(candidate.clone());
+        }
+    }
+
+    (found, false)
+}
+
+pub fn get_among_us_paths() -> Vec<PathBuf> {
+    scan_among_us_paths(|_, _, _| {}, |_, _, _| {}, || false).0
+}
+
 pub fn detect_platform(path: &str) -> Result<String, String> {
     let path = PathBuf::from(path);
 
@@ -128,3 +154,87 @@ pub fn detect_platform(path: &str) -> Result<String, String> {
         Ok("steam".to_string())
     }
 }
+
+// Unity のシリアライズ文字列は 4byte 長 + 本体で並ぶため、その形で版数らしい値を探す。
+fn is_game_version_text(text: &str) -> bool {
+    let body = text.trim_end_matches(|c: char| c.is_ascii_lowercase());
+    // 末尾の配信元記号（例: 2024.8.13s）は1文字まで許容する。
+    if text.len() - body.len() > 1 {
+        return false;
+    }
+    let parts = body.split('.').collect::<Vec<_>>();
+    (3..=4).contains(&parts.len())
+        && parts[0].len() == 4
+        && parts
+            .iter()
+            .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn find_game_version(bytes: &[u8]) -> Option<String> {
+    bytes.windows(4).enumerate().find_map(|(offset, prefix)| {
+        let len = u32::from_le_bytes([prefix[0], prefix[1], prefix[2], prefix[3]]) as usize;
+        if !(8..=20).contains(&len) {
+            return None;
+        }
+        let text = bytes.get(offset + 4..offset + 4 + len)?;
+        let text = std::str::from_utf8(text).ok()?;
+        is_game_version_text(text).then(|| text.to_string())
+    })
+}
+
+/// ゲーム本体の globalgamemanagers から Among Us のバージョン文字列を読み取る。
+/// 読み取れない場合は None を返す。
+pub fn read_among_us_version(game_dir: &Path) -> Option<String> {
+    let path = game_dir
+        .join(mod_profile::to_relative_path(
+            &mod_profile::get().paths.among_us_data_dir,
+        ))
+        .join("globalgamemanagers");
+    let bytes = std::fs::read(path).ok()?;
+    find_game_version(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn serialized_string(text: &str) -> Vec<u8> {
+        let mut bytes = (text.len() as u32).to_le_bytes().to_vec();
+        bytes.extend_from_slice(text.as_bytes());
+        bytes
+    }
+
+    #[test]
+    fn is_game_version_text_accepts_release_versions() {
+        assert!(is_game_version_text("2024.8.13"));
+        assert!(is_game_version_text("2024.8.13s"));
+        assert!(is_game_version_text("2022.10.25.1"));
+        assert!(!is_game_version_text("2024.8.13ss"));
+        assert!(!is_game_version_text("2024.8"));
+        assert!(!is_game_version_text("2024.8.13.1.2"));
+        assert!(!is_game_version_text("24.8.13"));
+        assert!(!is_game_version_text("2024..13"));
+        assert!(!is_game_version_text("2024.8.1a3"));
+        assert!(!is_game_version_text("2024.8.13S"));
+    }
+
+    #[test]
+    fn find_game_version_reads_length_prefixed_string() {
+        let mut bytes = vec![0xff, 0x00, 0x12];
+        bytes.extend(serialized_string("6000.0.0f1"));
+        bytes.extend(serialized_string("2024.8.13s"));
+        bytes.extend(serialized_string("2023.1.1"));
+
+        assert_eq!(find_game_version(&bytes), Some("2024.8.13s".to_string()));
+    }
+
+    #[test]
+    fn find_game_version_ignores_unprefixed_or_truncated_text() {
+        assert_eq!(find_game_version(b"version 2024.8.13 here"), None);
+
+        let mut truncated = serialized_string("2024.8.13");
+        truncated.truncate(truncated.len() - 1);
+        assert_eq!(find_game_version(&truncated), None);
+        assert_eq!(find_game_version(&[]), None);
+    }
+}
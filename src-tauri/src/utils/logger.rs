@@ -0,0 +1,172 @@
+// ランチャー診断ログをファイルへ書き出す軽量ロガー。
+// windows_subsystem="windows" のリリースビルドでは標準エラーが見えないため、
+// app_data_dir/logs/launcher.log へレベル付きで追記する。
+// 常駐中に肥大化しないよう、上限を超えたら launcher.1.log へ退避して書き直す。
+use log::{LevelFilter, Log, Metadata, Record};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Runtime};
+
+use crate::utils::settings;
+
+pub(crate) const LOG_DIR_NAME: &str = "logs";
+const LOG_FILE_NAME: &str = "launcher.log";
+const ROTATED_LOG_FILE_NAME: &str = "launcher.1.log";
+const LOG_MAX_BYTES: u64 = 4 * 1024 * 1024;
+pub const DEFAULT_LOG_LEVEL: &str = "info";
+
+static LOGGER: FileLogger = FileLogger {
+    file: Mutex::new(None),
+};
+
+struct FileLogger {
+    file: Mutex<Option<LogFile>>,
+}
+
+struct LogFile {
+    file: File,
+    path: PathBuf,
+    size: u64,
+}
+
+impl LogFile {
+    fn open(path: &Path) -> Result<Self, String> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| format!("Failed to open log file '{}': {e}", path.display()))?;
+        let size = file.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+        Ok(Self {
+            file,
+            path: path.to_path_buf(),
+            size,
+        })
+    }
+
+    fn write_line(&mut self, line: &str) {
+        if self.size > 0 && self.size + line.len() as u64 > LOG_MAX_BYTES {
+            if let Err(error) = self.rotate() {
+                // ロガー自身のエラーはログに残せないため、開発時のみ標準エラーへ出す。
+                if cfg!(debug_assertions) {
+                    eprintln!("{error}");
+                }
+            }
+        }
+        if self.file.write_all(line.as_bytes()).is_ok() {
+            self.size += line.len() as u64;
+        }
+    }
+
+    /// 現在のログを1世代だけ退避し、空のファイルへ書き直す。
+    fn rotate(&mut self) -> Result<(), String> {
+        let _ = self.file.flush();
+        let rotated = self.path.with_file_name(ROTATED_LOG_FILE_NAME);
+        let _ = fs::remove_file(&rotated);
+        fs::rename(&self.path, &rotated)
+            .map_err(|e| format!("Failed to rotate log file '{}': {e}", self.path.display()))?;
+        *self = Self::open(&self.path)?;
+        Ok(())
+    }
+}
+
+impl Log for FileLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        let line = format!(
+            "{timestamp} [{}] {}: {}\n",
+            record.level(),
+            record.target(),
+            record.args()
+        );
+
+        // 開発時はコンソールでも追えるよう標準エラーへも出す。
+        if cfg!(debug_assertions) {
+            eprint!("{line}");
+        }
+        if let Ok(mut guard) = self.file.lock() {
+            if let Some(file) = guard.as_mut() {
+                file.write_line(&line);
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut guard) = self.file.lock() {
+            if let Some(file) = guard.as_mut() {
+                let _ = file.file.flush();
+            }
+        }
+    }
+}
+
+/// 設定値のログレベルを正規化する。未知の値は既定レベルへ寄せる。
+pub fn normalize_log_level(value: &str) -> &'static str {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "off" => "off",
+        "error" => "error",
+        "warn" => "warn",
+        "debug" => "debug",
+        "trace" => "trace",
+        _ => DEFAULT_LOG_LEVEL,
+    }
+}
+
+fn to_level_filter(value: &str) -> LevelFilter {
+    match normalize_log_level(value) {
+        "off" => LevelFilter::Off,
+        "error" => LevelFilter::Error,
+        "warn" => LevelFilter::Warn,
+        "debug" => LevelFilter::Debug,
+        "trace" => LevelFilter::Trace,
+        _ => LevelFilter::Info,
+    }
+}
+
+/// ログファイルの保存先を返す。
+pub fn log_file_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    Ok(settings::app_data_dir(app)?
+        .join(LOG_DIR_NAME)
+        .join(LOG_FILE_NAME))
+}
+
+/// 出力レベルを変更する。設定更新時に呼び出す。
+pub fn set_level(value: &str) {
+    log::set_max_level(to_level_filter(value));
+}
+
+/// ファイルロガーを初期化する。2回目以降の呼び出しはレベル反映のみ行う。
+pub fn init<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    let path = log_file_path(app)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create log directory '{}': {e}", parent.display()))?;
+    }
+    let mut file = LogFile::open(&path)?;
+    // 前回までに上限を超えていた場合は、起動時点で退避しておく。
+    if file.size > LOG_MAX_BYTES {
+        file.rotate()?;
+    }
+    if let Ok(mut guard) = LOGGER.file.lock() {
+        *guard = Some(file);
+    }
+
+    // ロガー登録はプロセスで一度きりのため、既登録エラーは無視する。
+    let _ = log::set_logger(&LOGGER);
+    set_level(&settings::load_or_init_global_settings(app)?.log_level);
+    Ok(())
+}
@@ -6,13 +6,14 @@ use rand::rngs::OsRng;
 use rand::RngCore;
 use regex::Regex;
 use std::fs::{self, File};
-use std::io::{self, Cursor, Seek, Write};
+use std::io::{self, Cursor, Read, Seek, Write};
 use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Runtime};
 use zip::{CompressionMethod, ZipArchive, ZipWriter};
 
-use crate::utils::{mod_profile, settings};
+use crate::utils::{bootstrap, mod_profile, presets, settings};
 
 const PROFILE_ARCHIVE_PREFIX: &str = "profile";
 const LOCALLOW_ARCHIVE_PREFIX: &str = "locallow";
@@ -20,13 +21,48 @@ const DEFAULT_ARCHIVE_DIR_NAME: &str = "migrations";
 
 const PROFILE_BACKUP_DIR_NAME: &str = "profile_backup";
 const LOCALLOW_BACKUP_DIR_NAME: &str = "locallow_backup";
+const MIGRATION_BACKUP_ID_PREFIX: &str = "import-";
 
 const LEGACY_MIGRATION_EXTENSION: &str = "snrdata";
 const LEGACY_ARCHIVE_MAGIC: &[u8] = b"SNRDATA1";
+const REEXPORT_TEMP_SUFFIX: &str = ".reexport.tmp";
 const ARCHIVE_VERSION: u8 = 1;
 const CONTAINER_FLAG_ENCRYPTED: u8 = 0b0000_0001;
 const ENCRYPTION_SALT_LEN: usize = 16;
 const ENCRYPTION_NONCE_LEN: usize = 24;
+const ENCRYPTION_TAG_LEN: usize = 16;
+// 見積もり用のZIP構造オーバーヘッド（ローカルヘッダ30 + 中央ディレクトリ46 + 終端22バイト）。
+const ZIP_ENTRY_OVERHEAD_BYTES: u64 = 30 + 46;
+const ZIP_END_OVERHEAD_BYTES: u64 = 22;
+// セーブデータはバイナリ中心で縮みにくいため、控えめな圧縮率で見積もる。
+const ESTIMATED_DEFLATE_RATIO: f64 = 0.8;
+const MIGRATION_IMPORT_ALREADY_IN_PROGRESS_ERROR: &str =
+    "A migration import is in progress. Try again after it finishes.";
+
+// 取り込みとバックアップ操作は同じバックアップ置き場を使うため、同時に1件のみ許可する。
+static MIGRATION_IMPORT_IN_PROGRESS: AtomicBool = AtomicBool::new(false);
+
+/// 取り込み・バックアップからの復元・バックアップ削除を排他するガード。
+pub struct MigrationImportGuard;
+
+impl MigrationImportGuard {
+    pub fn acquire() -> Result<Self, String> {
+        MIGRATION_IMPORT_IN_PROGRESS
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .map(|_| Self)
+            .map_err(|_| MIGRATION_IMPORT_ALREADY_IN_PROGRESS_ERROR.to_string())
+    }
+}
+
+impl Drop for MigrationImportGuard {
+    fn drop(&mut self) {
+        MIGRATION_IMPORT_IN_PROGRESS.store(false, Ordering::SeqCst);
+    }
+}
+
+pub fn is_migration_import_in_progress() -> bool {
+    MIGRATION_IMPORT_IN_PROGRESS.load(Ordering::SeqCst)
+}
 
 #[derive(Debug, Clone)]
 pub struct MigrationExportSummary {
@@ -37,17 +73,66 @@ pub struct MigrationExportSummary {
     pub encrypted: bool,
 }
 
+/// アーカイブのコンテナ形式。現行マジック・旧マジック・ヘッダなしZIPを区別する。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MigrationArchiveFormat {
+    Current,
+    Legacy,
+    Plain,
+}
+
+#[derive(Debug, Clone)]
+pub struct MigrationSizeEstimate {
+    pub profile_files: usize,
+    pub locallow_files: usize,
+    pub source_bytes: u64,
+    pub estimated_archive_bytes: u64,
+    pub encrypted: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct MigrationImportSummary {
     pub imported_files: usize,
     pub profile_files: usize,
     pub locallow_files: usize,
     pub encrypted: bool,
+    pub format: MigrationArchiveFormat,
+    pub reexported_archive_path: Option<PathBuf>,
+    pub missing_profile_files: Option<Vec<PathBuf>>,
+}
+
+/// 巻き戻しに失敗した取り込みで残されたバックアップ。
+#[derive(Debug, Clone)]
+pub struct RetainedMigrationBackup {
+    pub backup_id: String,
+    pub path: PathBuf,
+    pub has_profile_backup: bool,
+    pub has_locallow_backup: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct MigrationPasswordValidationSummary {
     pub encrypted: bool,
+    pub format: MigrationArchiveFormat,
+}
+
+/// 復号せずにヘッダだけから読み取ったアーカイブ情報。
+#[derive(Debug, Clone)]
+pub struct MigrationArchiveInfo {
+    pub format: MigrationArchiveFormat,
+    /// ヘッダなしZIPでは None。
+    pub version: Option<u8>,
+    pub encrypted: bool,
+    pub file_size: u64,
+}
+
+// マジックヘッダ付きアーカイブの先頭フィールド。
+struct ArchiveHeader {
+    format: MigrationArchiveFormat,
+    version: u8,
+    flags: u8,
+    payload_offset: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -70,8 +155,38 @@ fn locallow_relative_root_path() -> PathBuf {
     mod_profile::local_low_root_path()
 }
 
-fn locallow_allowed_prefix() -> String {
-    normalize_path_for_archive(&locallow_relative_root_path())
+fn locallow_relative_roots() -> Vec<PathBuf> {
+    // 既定ルートに追加許可プレフィックスを加え、包含関係にある重複は除外する。
+    // 入れ子のまま残すとバックアップ/巻き戻しで同じファイルを二重に扱ってしまう。
+    let mut candidates = vec![locallow_relative_root_path()];
+    candidates.extend(mod_profile::local_low_additional_prefix_paths());
+
+    let mut roots: Vec<PathBuf> = Vec::new();
+    for candidate in candidates {
+        if candidate.as_os_str().is_empty() {
+            continue;
+        }
+        if roots.iter().any(|root| candidate.starts_with(root)) {
+            continue;
+        }
+        roots.retain(|root| !root.starts_with(&candidate));
+        roots.push(candidate);
+    }
+    roots
+}
+
+fn locallow_allowed_prefixes() -> Vec<String> {
+    locallow_relative_roots()
+        .iter()
+        .map(|root| normalize_path_for_archive(root))
+        .collect()
+}
+
+fn matches_locallow_prefix(relative_normalized: &str, allowed_prefix: &str) -> bool {
+    relative_normalized == allowed_prefix
+        || relative_normalized
+            .strip_prefix(allowed_prefix)
+            .is_some_and(|suffix| suffix.starts_with('/'))
 }
 
 fn compile_profile_patterns() -> Result<Vec<Regex>, String> {
@@ -152,6 +267,30 @@ fn collect_profile_files(
     Ok(matched)
 }
 
+/// 除外指定されたプリセットIDのプリセットファイルを収集結果から取り除く。
+fn exclude_preset_files(
+    files: Vec<(PathBuf, String)>,
+    excluded_preset_ids: &[i32],
+) -> Vec<(PathBuf, String)> {
+    if excluded_preset_ids.is_empty() {
+        return files;
+    }
+
+    let excluded_file_names = excluded_preset_ids
+        .iter()
+        .map(|preset_id| presets::preset_file_name(*preset_id))
+        .collect::<Vec<_>>();
+    files
+        .into_iter()
+        .filter(|(_, relative)| {
+            let file_name = relative.rsplit('/').next().unwrap_or(relative);
+            !excluded_file_names
+                .iter()
+                .any(|excluded| excluded.eq_ignore_ascii_case(file_name))
+        })
+        .collect()
+}
+
 pub fn collect_supported_profile_save_files(
     profile_root: &Path,
 ) -> Result<Vec<(PathBuf, String)>, String> {
@@ -159,7 +298,7 @@ pub fn collect_supported_profile_save_files(
     collect_profile_files(profile_root, &patterns)
 }
 
-fn resolve_locallow_root() -> Result<PathBuf, String> {
+pub(crate) fn resolve_locallow_root() -> Result<PathBuf, String> {
     #[cfg(target_os = "windows")]
     {
         let user_profile = std::env::var_os("USERPROFILE").ok_or_else(|| {
@@ -173,22 +312,42 @@ fn resolve_locallow_root() -> Result<PathBuf, String> {
     Err("Data migration is currently supported on Windows only".to_string())
 }
 
-fn resolve_locallow_snr_dir() -> Result<(PathBuf, PathBuf), String> {
-    let locallow_root = resolve_locallow_root()?;
-    let snr_dir = locallow_root.join(locallow_relative_root_path());
-    Ok((locallow_root, snr_dir))
-}
+/// 取り込み先となる LocalLow の mod ディレクトリへ、一時ファイルを作成・削除して書き込めるか確かめる。
+/// 判定に使ったディレクトリを返す。
+pub fn probe_locallow_write_access() -> Result<PathBuf, String> {
+    let target = resolve_locallow_root()?.join(locallow_relative_root_path());
+    fs::create_dir_all(&target).map_err(|e| {
+        format!(
+            "Failed to create LocalLow directory '{}': {e}",
+            target.display()
+        )
+    })?;
 
-fn collect_locallow_files(
-    locallow_root: &Path,
-    locallow_snr_dir: &Path,
-) -> Result<Vec<(PathBuf, String)>, String> {
-    if !locallow_snr_dir.exists() {
-        return Ok(Vec::new());
-    }
+    let probe_path = target.join(format!(".write-probe-{}", std::process::id()));
+    File::create_new(&probe_path)
+        .and_then(|mut file| file.write_all(b"probe"))
+        .map_err(|e| {
+            format!(
+                "LocalLow directory is not writable '{}': {e}",
+                target.display()
+            )
+        })?;
+    let _ = fs::remove_file(&probe_path);
+    Ok(target)
+}
 
+fn collect_locallow_files(locallow_root: &Path) -> Result<Vec<(PathBuf, String)>, String> {
+    let allowed_prefixes = locallow_allowed_prefixes();
     let mut all_files = Vec::new();
-    collect_files_recursive(locallow_snr_dir, &mut all_files)?;
+    for relative_root in locallow_relative_roots() {
+        let target = locallow_root.join(relative_root);
+        // 追加プレフィックスは単一ファイルを指す場合もある。
+        if target.is_file() {
+            all_files.push(target);
+        } else if target.is_dir() {
+            collect_files_recursive(&target, &mut all_files)?;
+        }
+    }
 
     let mut matched = Vec::new();
     for file_path in all_files {
@@ -201,11 +360,9 @@ fn collect_locallow_files(
         })?;
 
         let relative_normalized = normalize_path_for_archive(relative);
-        let allowed_prefix = locallow_allowed_prefix();
-        if relative_normalized == allowed_prefix
-            || relative_normalized
-                .strip_prefix(&allowed_prefix)
-                .is_some_and(|suffix| suffix.starts_with('/'))
+        if allowed_prefixes
+            .iter()
+            .any(|prefix| matches_locallow_prefix(&relative_normalized, prefix))
         {
             matched.push((file_path, relative_normalized));
         }
@@ -214,26 +371,41 @@ fn collect_locallow_files(
     Ok(matched)
 }
 
-fn archive_extension_is_supported(path: &Path) -> bool {
+/// 取り込み可能なアーカイブ拡張子(設定値・旧拡張子)を返す。
+pub fn supported_archive_extensions() -> Vec<&'static str> {
     let configured_extension = migration_extension();
+    if configured_extension.eq_ignore_ascii_case(LEGACY_MIGRATION_EXTENSION) {
+        vec![configured_extension]
+    } else {
+        vec![configured_extension, LEGACY_MIGRATION_EXTENSION]
+    }
+}
+
+fn archive_extension_is_supported(path: &Path) -> bool {
     path.extension()
         .and_then(|ext| ext.to_str())
         .map(|ext| {
-            let ext_lower = ext.to_ascii_lowercase();
-            ext_lower == configured_extension.to_ascii_lowercase()
-                || ext_lower == LEGACY_MIGRATION_EXTENSION
+            supported_archive_extensions()
+                .iter()
+                .any(|supported| supported.eq_ignore_ascii_case(ext))
         })
         .unwrap_or(false)
 }
 
 fn make_default_archive_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
-    let base_dir = settings::app_data_dir(app)?;
+    // 既定の出力先が設定されていればそちらを優先する。
+    let configured_dir = settings::load_or_init_settings(app)?.migration_default_output_dir;
+    let output_dir = if configured_dir.is_empty() {
+        settings::app_data_dir(app)?.join(DEFAULT_ARCHIVE_DIR_NAME)
+    } else {
+        PathBuf::from(configured_dir)
+    };
     let timestamp = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap_or_default()
         .as_secs();
 
-    Ok(base_dir.join(DEFAULT_ARCHIVE_DIR_NAME).join(format!(
+    Ok(output_dir.join(format!(
         "{}-migration-{timestamp}.{}",
         mod_profile::get().mod_info.id,
         migration_extension()
@@ -386,19 +558,28 @@ fn build_snrdata_container(
     Ok((container, true))
 }
 
-fn extract_zip_bytes_from_archive_bytes(
-    archive_bytes: &[u8],
-    password: Option<&str>,
-) -> Result<(Vec<u8>, bool), String> {
+fn detect_archive_format(archive_bytes: &[u8]) -> MigrationArchiveFormat {
+    // 展開側と同じく現行マジックを優先して判定する。
+    if archive_bytes.starts_with(archive_magic_bytes()) {
+        MigrationArchiveFormat::Current
+    } else if archive_bytes.starts_with(LEGACY_ARCHIVE_MAGIC) {
+        MigrationArchiveFormat::Legacy
+    } else {
+        MigrationArchiveFormat::Plain
+    }
+}
+
+/// マジック・バージョン・フラグを検証する。ヘッダなしZIPは None を返す。
+fn parse_archive_header(archive_bytes: &[u8]) -> Result<Option<ArchiveHeader>, String> {
     let extension = migration_extension();
     let configured_magic = archive_magic_bytes();
     // 互換性のため、新旧どちらのマジックヘッダでも受理する。
-    let active_magic = if archive_bytes.starts_with(configured_magic) {
-        configured_magic
+    let (active_magic, format) = if archive_bytes.starts_with(configured_magic) {
+        (configured_magic, MigrationArchiveFormat::Current)
     } else if archive_bytes.starts_with(LEGACY_ARCHIVE_MAGIC) {
-        LEGACY_ARCHIVE_MAGIC
+        (LEGACY_ARCHIVE_MAGIC, MigrationArchiveFormat::Legacy)
     } else {
-        return Ok((archive_bytes.to_vec(), false));
+        return Ok(None);
     };
 
     if archive_bytes.len() < active_magic.len() + 2 {
@@ -415,8 +596,25 @@ fn extract_zip_bytes_from_archive_bytes(
         return Err(format!("Unsupported .{extension} flags"));
     }
 
-    let payload = &archive_bytes[(active_magic.len() + 2)..];
-    let encrypted = (flags & CONTAINER_FLAG_ENCRYPTED) != 0;
+    Ok(Some(ArchiveHeader {
+        format,
+        version,
+        flags,
+        payload_offset: active_magic.len() + 2,
+    }))
+}
+
+fn extract_zip_bytes_from_archive_bytes(
+    archive_bytes: &[u8],
+    password: Option<&str>,
+) -> Result<(Vec<u8>, bool), String> {
+    let extension = migration_extension();
+    let Some(header) = parse_archive_header(archive_bytes)? else {
+        return Ok((archive_bytes.to_vec(), false));
+    };
+
+    let payload = &archive_bytes[header.payload_offset..];
+    let encrypted = (header.flags & CONTAINER_FLAG_ENCRYPTED) != 0;
     if !encrypted {
         return Ok((payload.to_vec(), false));
     }
@@ -454,22 +652,52 @@ fn extract_zip_bytes_from_archive_bytes(
 fn read_zip_bytes_from_archive_file(
     archive_path: &Path,
     password: Option<&str>,
-) -> Result<(Vec<u8>, bool), String> {
+) -> Result<(Vec<u8>, bool, MigrationArchiveFormat), String> {
     let archive_bytes = fs::read(archive_path).map_err(|e| {
         format!(
             "Failed to read migration archive '{}': {e}",
             archive_path.display()
         )
     })?;
-    extract_zip_bytes_from_archive_bytes(&archive_bytes, password)
+    let format = detect_archive_format(&archive_bytes);
+    let (zip_bytes, encrypted) = extract_zip_bytes_from_archive_bytes(&archive_bytes, password)?;
+    Ok((zip_bytes, encrypted, format))
+}
+
+/// 旧形式のアーカイブを現行形式で保存し直す。暗号化の有無とパスワードは引き継ぐ。
+fn reexport_archive_in_current_format(
+    archive_path: &Path,
+    zip_bytes: &[u8],
+    encrypted: bool,
+    password: Option<&str>,
+) -> Result<PathBuf, String> {
+    let (container, _) = build_snrdata_container(zip_bytes, encrypted, password)?;
+    let target_path = archive_path.with_extension(migration_extension());
+    // 書き込み途中の失敗で元のアーカイブを失わないよう、隣の一時ファイルへ書いてから置き換える。
+    let mut temp_name = target_path
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    temp_name.push(REEXPORT_TEMP_SUFFIX);
+    let temp_path = target_path.with_file_name(temp_name);
+    let result = fs::write(&temp_path, container)
+        .and_then(|_| fs::rename(&temp_path, &target_path))
+        .map_err(|e| {
+            format!(
+                "Failed to re-export migration archive '{}': {e}",
+                target_path.display()
+            )
+        });
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result.map(|_| target_path)
 }
 
 fn is_locallow_entry_allowed(relative_normalized: &str) -> bool {
-    let allowed_prefix = locallow_allowed_prefix();
-    relative_normalized == allowed_prefix
-        || relative_normalized
-            .strip_prefix(&allowed_prefix)
-            .is_some_and(|suffix| suffix.starts_with('/'))
+    locallow_allowed_prefixes()
+        .iter()
+        .any(|prefix| matches_locallow_prefix(relative_normalized, prefix))
 }
 
 fn resolve_entry_target(
@@ -608,7 +836,7 @@ fn copy_directory_recursive(source: &Path, destination: &Path) -> Result<(), Str
 }
 
 fn create_backup_root<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
-    let base = settings::app_data_dir(app)?.join("migration-import-backups");
+    let base = settings::app_data_dir(app)?.join(bootstrap::MIGRATION_BACKUPS_DIR_NAME);
     fs::create_dir_all(&base).map_err(|e| {
         format!(
             "Failed to create migration backup base directory '{}': {e}",
@@ -628,7 +856,9 @@ fn create_backup_root<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String>
         } else {
             format!("-{attempt}")
         };
-        let candidate = base.join(format!("import-{timestamp}-{pid}{suffix}"));
+        let candidate = base.join(format!(
+            "{MIGRATION_BACKUP_ID_PREFIX}{timestamp}-{pid}{suffix}"
+        ));
         if candidate.exists() {
             continue;
         }
@@ -702,22 +932,56 @@ fn backup_and_clean_profile(
     Ok(())
 }
 
-fn backup_and_clean_locallow(locallow_snr_dir: &Path, backup_root: &Path) -> Result<(), String> {
-    if !locallow_snr_dir.exists() {
-        return Ok(());
+fn remove_locallow_target(target: &Path) -> io::Result<()> {
+    if target.is_dir() {
+        fs::remove_dir_all(target)
+    } else {
+        fs::remove_file(target)
     }
+}
 
-    let backup_locallow_dir = backup_root
-        .join(LOCALLOW_BACKUP_DIR_NAME)
-        .join(locallow_relative_root_path());
-    copy_directory_recursive(locallow_snr_dir, &backup_locallow_dir)?;
+fn copy_locallow_target(source: &Path, destination: &Path) -> Result<(), String> {
+    if !source.is_file() {
+        return copy_directory_recursive(source, destination);
+    }
 
-    fs::remove_dir_all(locallow_snr_dir).map_err(|e| {
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            format!(
+                "Failed to create directory for LocalLow backup '{}': {e}",
+                parent.display()
+            )
+        })?;
+    }
+    fs::copy(source, destination).map_err(|e| {
         format!(
-            "Failed to clear LocalLow migration target '{}' before import: {e}",
-            locallow_snr_dir.display()
+            "Failed to copy LocalLow file '{}' -> '{}': {e}",
+            source.display(),
+            destination.display()
         )
     })?;
+    Ok(())
+}
+
+fn backup_and_clean_locallow(locallow_root: &Path, backup_root: &Path) -> Result<(), String> {
+    for relative_root in locallow_relative_roots() {
+        let target = locallow_root.join(&relative_root);
+        if !target.exists() {
+            continue;
+        }
+
+        let backup_target = backup_root
+            .join(LOCALLOW_BACKUP_DIR_NAME)
+            .join(&relative_root);
+        copy_locallow_target(&target, &backup_target)?;
+
+        remove_locallow_target(&target).map_err(|e| {
+            format!(
+                "Failed to clear LocalLow migration target '{}' before import: {e}",
+                target.display()
+            )
+        })?;
+    }
 
     Ok(())
 }
@@ -727,31 +991,36 @@ fn restore_profile_from_backup(profile_root: &Path, backup_root: &Path) -> Resul
     copy_directory_recursive(&backup_profile_root, profile_root)
 }
 
-fn restore_locallow_from_backup(locallow_snr_dir: &Path, backup_root: &Path) -> Result<(), String> {
-    if locallow_snr_dir.exists() {
-        fs::remove_dir_all(locallow_snr_dir).map_err(|e| {
-            format!(
-                "Failed to remove partially imported LocalLow data '{}': {e}",
-                locallow_snr_dir.display()
-            )
-        })?;
+fn restore_locallow_from_backup(locallow_root: &Path, backup_root: &Path) -> Result<(), String> {
+    for relative_root in locallow_relative_roots() {
+        let target = locallow_root.join(&relative_root);
+        if target.exists() {
+            remove_locallow_target(&target).map_err(|e| {
+                format!(
+                    "Failed to remove partially imported LocalLow data '{}': {e}",
+                    target.display()
+                )
+            })?;
+        }
+
+        let backup_target = backup_root
+            .join(LOCALLOW_BACKUP_DIR_NAME)
+            .join(&relative_root);
+        copy_locallow_target(&backup_target, &target)?;
     }
 
-    let backup_locallow_dir = backup_root
-        .join(LOCALLOW_BACKUP_DIR_NAME)
-        .join(locallow_relative_root_path());
-    copy_directory_recursive(&backup_locallow_dir, locallow_snr_dir)
+    Ok(())
 }
 
 fn rollback_after_failed_import(
     profile_root: &Path,
     profile_patterns: &[Regex],
-    locallow_snr_dir: &Path,
+    locallow_root: &Path,
     backup_root: &Path,
 ) -> Result<(), String> {
     clean_managed_profile_files(profile_root, profile_patterns)?;
     restore_profile_from_backup(profile_root, backup_root)?;
-    restore_locallow_from_backup(locallow_snr_dir, backup_root)?;
+    restore_locallow_from_backup(locallow_root, backup_root)?;
     Ok(())
 }
 
@@ -809,20 +1078,113 @@ fn apply_import_files(
     ))
 }
 
+fn estimate_zip_bytes(entries: &[(u64, usize)]) -> u64 {
+    entries
+        .iter()
+        .map(|(size, entry_name_len)| {
+            (*size as f64 * ESTIMATED_DEFLATE_RATIO).ceil() as u64
+                + ZIP_ENTRY_OVERHEAD_BYTES
+                + 2 * *entry_name_len as u64
+        })
+        .sum::<u64>()
+        + ZIP_END_OVERHEAD_BYTES
+}
+
+fn estimate_archive_bytes(entries: &[(u64, usize)], encrypted: bool) -> u64 {
+    let zip_bytes = estimate_zip_bytes(entries);
+    let mut header_bytes = (archive_magic_bytes().len() + 2) as u64;
+    if encrypted {
+        header_bytes += (ENCRYPTION_SALT_LEN + ENCRYPTION_NONCE_LEN + ENCRYPTION_TAG_LEN) as u64;
+    }
+    zip_bytes + header_bytes
+}
+
+/// 書き出し対象を収集し、アーカイブを作らずに出力サイズを見積もる。
+/// 除外プリセットは `export_migration_data` と同じく対象から外す。
+pub fn estimate_migration_export<R: Runtime>(
+    app: &AppHandle<R>,
+    encryption_enabled: bool,
+    excluded_preset_ids: &[i32],
+) -> Result<MigrationSizeEstimate, String> {
+    let launcher_settings = settings::load_or_init_settings(app)?;
+    let profile_root = PathBuf::from(launcher_settings.profile_path);
+
+    let profile_patterns = compile_profile_patterns()?;
+    let profile_files = exclude_preset_files(
+        collect_profile_files(&profile_root, &profile_patterns)?,
+        excluded_preset_ids,
+    );
+
+    let locallow_root = resolve_locallow_root()?;
+    let locallow_files = collect_locallow_files(&locallow_root)?;
+
+    let mut source_bytes = 0;
+    let mut entries = Vec::with_capacity(profile_files.len() + locallow_files.len());
+    let tagged_files = profile_files
+        .iter()
+        .map(|file| (PROFILE_ARCHIVE_PREFIX, file))
+        .chain(
+            locallow_files
+                .iter()
+                .map(|file| (LOCALLOW_ARCHIVE_PREFIX, file)),
+        );
+    for (prefix, (source, relative)) in tagged_files {
+        let size = fs::metadata(source)
+            .map(|metadata| metadata.len())
+            .unwrap_or(0);
+        source_bytes += size;
+        // エントリ名は build_zip_bytes と同じく "prefix/relative" になる。
+        entries.push((size, prefix.len() + 1 + relative.len()));
+    }
+
+    Ok(MigrationSizeEstimate {
+        profile_files: profile_files.len(),
+        locallow_files: locallow_files.len(),
+        source_bytes,
+        estimated_archive_bytes: estimate_archive_bytes(&entries, encryption_enabled),
+        encrypted: encryption_enabled,
+    })
+}
+
+/// 指定プリセットのアーカイブを書き出した場合の出力サイズを見積もる。プリセットは暗号化しない。
+pub fn estimate_presets_export<R: Runtime>(
+    app: &AppHandle<R>,
+    preset_ids: Vec<i32>,
+) -> Result<MigrationSizeEstimate, String> {
+    let entries = presets::selected_presets_export_entries(app, preset_ids)?;
+    let source_bytes = entries.iter().map(|(_, size)| *size).sum();
+    let sized_entries = entries
+        .iter()
+        .map(|(name, size)| (*size, name.len()))
+        .collect::<Vec<_>>();
+
+    Ok(MigrationSizeEstimate {
+        profile_files: entries.len(),
+        locallow_files: 0,
+        source_bytes,
+        estimated_archive_bytes: estimate_zip_bytes(&sized_entries),
+        encrypted: false,
+    })
+}
+
 pub fn export_migration_data<R: Runtime>(
     app: &AppHandle<R>,
     output_path: Option<String>,
     encryption_enabled: bool,
     password: Option<String>,
+    excluded_preset_ids: &[i32],
 ) -> Result<MigrationExportSummary, String> {
     let launcher_settings = settings::load_or_init_settings(app)?;
     let profile_root = PathBuf::from(launcher_settings.profile_path);
 
     let profile_patterns = compile_profile_patterns()?;
-    let profile_files = collect_profile_files(&profile_root, &profile_patterns)?;
+    let profile_files = exclude_preset_files(
+        collect_profile_files(&profile_root, &profile_patterns)?,
+        excluded_preset_ids,
+    );
 
-    let (locallow_root, locallow_snr_dir) = resolve_locallow_snr_dir()?;
-    let locallow_files = collect_locallow_files(&locallow_root, &locallow_snr_dir)?;
+    let locallow_root = resolve_locallow_root()?;
+    let locallow_files = collect_locallow_files(&locallow_root)?;
 
     if profile_files.is_empty() && locallow_files.is_empty() {
         return Err(
@@ -865,6 +1227,8 @@ pub fn import_migration_data<R: Runtime>(
     app: &AppHandle<R>,
     archive_path: &Path,
     password: Option<String>,
+    reexport_legacy: bool,
+    verify_profile: bool,
 ) -> Result<MigrationImportSummary, String> {
     if !archive_path.is_file() {
         return Err(format!(
@@ -880,14 +1244,14 @@ pub fn import_migration_data<R: Runtime>(
         ));
     }
 
-    let (zip_bytes, encrypted) =
+    let (zip_bytes, encrypted, format) =
         read_zip_bytes_from_archive_file(archive_path, password.as_deref())?;
     let mut archive = ZipArchive::new(Cursor::new(zip_bytes))
         .map_err(|e| format!("Invalid migration archive format: {e}"))?;
 
     let launcher_settings = settings::load_or_init_settings(app)?;
     let profile_root = PathBuf::from(launcher_settings.profile_path);
-    let (locallow_root, locallow_snr_dir) = resolve_locallow_snr_dir()?;
+    let locallow_root = resolve_locallow_root()?;
     let profile_patterns = compile_profile_patterns()?;
 
     let planned_files = plan_import_files(
@@ -903,7 +1267,7 @@ pub fn import_migration_data<R: Runtime>(
     let backup_root = create_backup_root(app)?;
     let apply_result = (|| -> Result<MigrationImportSummary, String> {
         backup_and_clean_profile(&profile_root, &profile_patterns, &backup_root)?;
-        backup_and_clean_locallow(&locallow_snr_dir, &backup_root)?;
+        backup_and_clean_locallow(&locallow_root, &backup_root)?;
 
         let (imported_files, imported_profile_files, imported_locallow_files) =
             apply_import_files(&mut archive, &planned_files)?;
@@ -913,12 +1277,33 @@ pub fn import_migration_data<R: Runtime>(
             profile_files: imported_profile_files,
             locallow_files: imported_locallow_files,
             encrypted,
+            format,
+            reexported_archive_path: None,
+            missing_profile_files: None,
         })
     })();
 
     match apply_result {
-        Ok(summary) => {
+        Ok(mut summary) => {
             let _ = fs::remove_dir_all(&backup_root);
+            // 取り込み自体は完了しているため、再保存の失敗は警告に留める。
+            if reexport_legacy && format != MigrationArchiveFormat::Current {
+                let zip_bytes = archive.into_inner().into_inner();
+                match reexport_archive_in_current_format(
+                    archive_path,
+                    &zip_bytes,
+                    encrypted,
+                    password.as_deref(),
+                ) {
+                    Ok(path) => summary.reexported_archive_path = Some(path),
+                    Err(error) => log::warn!("{error}"),
+                }
+            }
+            // セーブデータだけ戻ってMod本体が未導入の状態を区別できるようにする。
+            if verify_profile {
+                summary.missing_profile_files =
+                    Some(settings::missing_profile_required_files(&profile_root));
+            }
             Ok(summary)
         }
         Err(import_error) => {
@@ -926,22 +1311,170 @@ pub fn import_migration_data<R: Runtime>(
             let rollback_result = rollback_after_failed_import(
                 &profile_root,
                 &profile_patterns,
-                &locallow_snr_dir,
+                &locallow_root,
                 &backup_root,
             );
-            let _ = fs::remove_dir_all(&backup_root);
-
             if let Err(rollback_error) = rollback_result {
+                // 唯一の退避コピーになり得るため、巻き戻し失敗時はバックアップを残す。
+                let backup_id = backup_root
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default();
                 Err(format!(
-                    "{import_error} Rollback failed and manual recovery may be required: {rollback_error}"
+                    "{import_error} Rollback failed and the backup '{backup_id}' was kept for manual recovery: {rollback_error}"
                 ))
             } else {
+                let _ = fs::remove_dir_all(&backup_root);
                 Err(import_error)
             }
         }
     }
 }
 
+fn migration_backup_path<R: Runtime>(
+    app: &AppHandle<R>,
+    backup_id: &str,
+) -> Result<PathBuf, String> {
+    let backup_id = backup_id.trim();
+    // ディレクトリ名以外の指定で退避領域の外を触らないよう、形式を限定する。
+    let valid = backup_id
+        .strip_prefix(MIGRATION_BACKUP_ID_PREFIX)
+        .is_some_and(|rest| {
+            !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit() || c == '-')
+        });
+    if !valid {
+        return Err(format!("Invalid migration backup id: {backup_id}"));
+    }
+
+    let path = settings::app_data_dir(app)?
+        .join(bootstrap::MIGRATION_BACKUPS_DIR_NAME)
+        .join(backup_id);
+    if !path.is_dir() {
+        return Err(format!("Migration backup was not found: {backup_id}"));
+    }
+    Ok(path)
+}
+
+/// 巻き戻し失敗により残されている取り込みバックアップを列挙する。
+pub fn list_retained_migration_backups<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<Vec<RetainedMigrationBackup>, String> {
+    let base = settings::app_data_dir(app)?.join(bootstrap::MIGRATION_BACKUPS_DIR_NAME);
+    if !base.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let entries = fs::read_dir(&base).map_err(|e| {
+        format!(
+            "Failed to read migration backup directory '{}': {e}",
+            base.display()
+        )
+    })?;
+    let mut backups = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let backup_id = entry.file_name().to_string_lossy().to_string();
+        if !path.is_dir() || !backup_id.starts_with(MIGRATION_BACKUP_ID_PREFIX) {
+            continue;
+        }
+        backups.push(RetainedMigrationBackup {
+            has_profile_backup: path.join(PROFILE_BACKUP_DIR_NAME).is_dir(),
+            has_locallow_backup: path.join(LOCALLOW_BACKUP_DIR_NAME).is_dir(),
+            backup_id,
+            path,
+        });
+    }
+    backups.sort_by(|a, b| b.backup_id.cmp(&a.backup_id));
+    Ok(backups)
+}
+
+/// 残されたバックアップから取り込み前の状態を復元し、成功したらバックアップを削除する。
+pub fn recover_migration_backup<R: Runtime>(
+    app: &AppHandle<R>,
+    backup_id: &str,
+) -> Result<(), String> {
+    let backup_root = migration_backup_path(app, backup_id)?;
+    let launcher_settings = settings::load_or_init_settings(app)?;
+    let profile_root = PathBuf::from(launcher_settings.profile_path);
+    let locallow_root = resolve_locallow_root()?;
+    let profile_patterns = compile_profile_patterns()?;
+
+    rollback_after_failed_import(
+        &profile_root,
+        &profile_patterns,
+        &locallow_root,
+        &backup_root,
+    )?;
+    fs::remove_dir_all(&backup_root).map_err(|e| {
+        format!(
+            "Failed to remove recovered migration backup '{}': {e}",
+            backup_root.display()
+        )
+    })
+}
+
+/// 不要になった取り込みバックアップを削除する。
+pub fn discard_migration_backup<R: Runtime>(
+    app: &AppHandle<R>,
+    backup_id: &str,
+) -> Result<(), String> {
+    let backup_root = migration_backup_path(app, backup_id)?;
+    fs::remove_dir_all(&backup_root).map_err(|e| {
+        format!(
+            "Failed to remove migration backup '{}': {e}",
+            backup_root.display()
+        )
+    })
+}
+
+/// 先頭のヘッダだけを読み、形式・バージョン・暗号化の有無を返す。復号やZIPの検証は行わない。
+pub fn read_migration_archive_info(archive_path: &Path) -> Result<MigrationArchiveInfo, String> {
+    if !archive_path.is_file() {
+        return Err(format!(
+            "Migration archive was not found: {}",
+            archive_path.display()
+        ));
+    }
+
+    if !archive_extension_is_supported(archive_path) {
+        return Err(format!(
+            "Unsupported migration archive extension: {}",
+            archive_path.display()
+        ));
+    }
+
+    let file = File::open(archive_path).map_err(|e| {
+        format!(
+            "Failed to open migration archive '{}': {e}",
+            archive_path.display()
+        )
+    })?;
+    let file_size = file
+        .metadata()
+        .map_err(|e| format!("Failed to read migration archive metadata: {e}"))?
+        .len();
+    let header_len = archive_magic_bytes().len().max(LEGACY_ARCHIVE_MAGIC.len()) + 2;
+    let mut header_bytes = Vec::with_capacity(header_len);
+    file.take(header_len as u64)
+        .read_to_end(&mut header_bytes)
+        .map_err(|e| format!("Failed to read migration archive header: {e}"))?;
+
+    Ok(match parse_archive_header(&header_bytes)? {
+        Some(header) => MigrationArchiveInfo {
+            format: header.format,
+            version: Some(header.version),
+            encrypted: (header.flags & CONTAINER_FLAG_ENCRYPTED) != 0,
+            file_size,
+        },
+        None => MigrationArchiveInfo {
+            format: MigrationArchiveFormat::Plain,
+            version: None,
+            encrypted: false,
+            file_size,
+        },
+    })
+}
+
 pub fn validate_migration_archive_password(
     archive_path: &Path,
     password: Option<String>,
@@ -960,7 +1493,7 @@ pub fn validate_migration_archive_password(
         ));
     }
 
-    let (zip_bytes, encrypted) =
+    let (zip_bytes, encrypted, format) =
         read_zip_bytes_from_archive_file(archive_path, password.as_deref())?;
     let mut archive = ZipArchive::new(Cursor::new(zip_bytes))
         .map_err(|e| format!("Invalid migration archive format: {e}"))?;
@@ -971,5 +1504,107 @@ pub fn validate_migration_archive_password(
             .map_err(|e| format!("Failed to read migration archive entry {index}: {e}"))?;
     }
 
-    Ok(MigrationPasswordValidationSummary { encrypted })
+    Ok(MigrationPasswordValidationSummary { encrypted, format })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_temp_dir(label: &str) -> PathBuf {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        std::env::temp_dir().join(format!(
+            "snr-migration-{label}-{}-{millis}",
+            std::process::id()
+        ))
+    }
+
+    fn write_archive(root: &Path, name: &str, bytes: &[u8]) -> PathBuf {
+        let path = root.join(format!("{name}.{}", migration_extension()));
+        fs::write(&path, bytes).expect("write archive");
+        path
+    }
+
+    fn header(magic: &[u8], flags: u8) -> Vec<u8> {
+        let mut bytes = magic.to_vec();
+        bytes.push(ARCHIVE_VERSION);
+        bytes.push(flags);
+        bytes.extend_from_slice(b"PK\x03\x04payload");
+        bytes
+    }
+
+    #[test]
+    fn read_migration_archive_info_detects_header_formats() {
+        let root = make_temp_dir("archive-info");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).expect("create temp dir");
+
+        let current_bytes = header(archive_magic_bytes(), 0);
+        let current = read_migration_archive_info(&write_archive(&root, "current", &current_bytes))
+            .expect("read current archive info");
+        assert_eq!(current.format, MigrationArchiveFormat::Current);
+        assert_eq!(current.version, Some(ARCHIVE_VERSION));
+        assert!(!current.encrypted);
+        assert_eq!(current.file_size, current_bytes.len() as u64);
+
+        // 同梱設定のマジックが旧形式と同じ場合は、現行形式として判定される。
+        let legacy = read_migration_archive_info(&write_archive(
+            &root,
+            "legacy",
+            &header(LEGACY_ARCHIVE_MAGIC, 0),
+        ))
+        .expect("read legacy archive info");
+        let expected_legacy_format = if archive_magic_bytes() == LEGACY_ARCHIVE_MAGIC {
+            MigrationArchiveFormat::Current
+        } else {
+            MigrationArchiveFormat::Legacy
+        };
+        assert_eq!(legacy.format, expected_legacy_format);
+        assert_eq!(legacy.version, Some(ARCHIVE_VERSION));
+
+        let plain_bytes = b"PK\x03\x04plain zip";
+        let plain = read_migration_archive_info(&write_archive(&root, "plain", plain_bytes))
+            .expect("read plain archive info");
+        assert_eq!(plain.format, MigrationArchiveFormat::Plain);
+        assert_eq!(plain.version, None);
+        assert!(!plain.encrypted);
+        assert_eq!(plain.file_size, plain_bytes.len() as u64);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn read_migration_archive_info_reports_encrypted_flag() {
+        let root = make_temp_dir("archive-info-encrypted");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).expect("create temp dir");
+
+        let info = read_migration_archive_info(&write_archive(
+            &root,
+            "encrypted",
+            &header(archive_magic_bytes(), CONTAINER_FLAG_ENCRYPTED),
+        ))
+        .expect("read encrypted archive info");
+        assert!(info.encrypted);
+        assert_eq!(info.format, MigrationArchiveFormat::Current);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn read_migration_archive_info_rejects_truncated_header() {
+        let root = make_temp_dir("archive-info-truncated");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).expect("create temp dir");
+
+        let mut truncated = archive_magic_bytes().to_vec();
+        truncated.push(ARCHIVE_VERSION);
+        let path = write_archive(&root, "truncated", &truncated);
+        assert!(read_migration_archive_info(&path).is_err());
+
+        let _ = fs::remove_dir_all(&root);
+    }
 }
@@ -1,9 +1,14 @@
 // utils層のモジュール公開一覧。
 // 他層から直接参照する共通ユーティリティのみをここで re-export する。
+pub mod antivirus;
 pub mod background_notifications;
+pub mod bootstrap;
+pub mod close_prompt;
 pub mod download;
 pub mod epic_api;
+pub mod file_dialog;
 pub mod finder;
+pub mod logger;
 pub mod migration;
 pub mod mod_profile;
 pub mod presets;
@@ -2,16 +2,16 @@
 //! 1 build / 1 mod 前提で、起動時に一度だけ検証して全体で共有する。
 
 use regex::Regex;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
 const MOD_CONFIG_RAW: &str = include_str!("../../../src/shared/mod.config.json");
 
 static MOD_PROFILE: OnceLock<ModProfile> = OnceLock::new();
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ModProfile {
     pub schema_version: u32,
@@ -28,7 +28,7 @@ pub struct ModProfile {
     pub events: Events,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ModInfo {
     pub id: String,
@@ -36,7 +36,7 @@ pub struct ModInfo {
     pub short_name: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Branding {
     pub launcher_name: String,
@@ -44,9 +44,12 @@ pub struct Branding {
     pub tray_tooltip: String,
     pub identifier: String,
     pub modded_shortcut_name: String,
+    /// 通知に表示するアイコン画像。相対パスはランチャー実行ファイルの場所を基準にする。
+    #[serde(default)]
+    pub notification_icon: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FeatureFlags {
     pub announce: bool,
@@ -58,7 +61,7 @@ pub struct FeatureFlags {
     pub game_servers: bool,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Distribution {
     pub source: String,
@@ -68,14 +71,14 @@ pub struct Distribution {
     pub updater_latest_json_url: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AssetRegex {
     pub steam: String,
     pub epic: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Patchers {
     pub enabled: bool,
@@ -83,7 +86,7 @@ pub struct Patchers {
     pub base_url: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Paths {
     pub among_us_exe: String,
@@ -94,15 +97,17 @@ pub struct Paths {
     pub profile_required_files: Vec<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Migration {
     pub extension: String,
     pub magic: String,
     pub profile_include_patterns: Vec<String>,
+    #[serde(default)]
+    pub local_low_additional_prefixes: Vec<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Presets {
     pub extension: String,
@@ -110,7 +115,7 @@ pub struct Presets {
     pub save_data_root: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApiEndpoints {
     pub announce_base_url: String,
@@ -120,7 +125,7 @@ pub struct ApiEndpoints {
     pub join_direct: JoinDirectEndpoint,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GameServerEndpoint {
     pub id: String,
@@ -129,7 +134,7 @@ pub struct GameServerEndpoint {
     pub server_type: i32,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct JoinDirectEndpoint {
     pub localhost_base_url: String,
@@ -139,7 +144,7 @@ pub struct JoinDirectEndpoint {
     pub timeout_ms: u64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Links {
     pub wiki_url: String,
@@ -148,7 +153,7 @@ pub struct Links {
     pub official: Vec<OfficialLink>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OfficialLink {
     pub label: String,
@@ -157,7 +162,7 @@ pub struct OfficialLink {
     pub icon_id: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Events {
     pub install_progress: String,
@@ -184,14 +189,43 @@ fn non_empty(name: &str, value: &str) -> Result<(), String> {
     Ok(())
 }
 
+fn is_safe_relative_path(value: &str) -> bool {
+    // 区切りは '/' のみ許可し、絶対パスや親参照でLocalLow外へ出ないようにする。
+    let trimmed = value.trim();
+    if trimmed.is_empty()
+        || trimmed.starts_with('/')
+        || trimmed.contains('\\')
+        || trimmed.contains(':')
+    {
+        return false;
+    }
+    trimmed
+        .split('/')
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .all(|segment| segment != "." && segment != "..")
+}
+
 fn parse_mod_profile() -> Result<ModProfile, String> {
     // 埋め込みJSONを読み取り、起動時に一度だけ検証して共有する。
-    let mut profile = serde_json::from_str::<ModProfile>(MOD_CONFIG_RAW)
+    parse_mod_profile_str(MOD_CONFIG_RAW)
+}
+
+fn parse_mod_profile_str(raw: &str) -> Result<ModProfile, String> {
+    let mut profile = serde_json::from_str::<ModProfile>(raw)
         .map_err(|e| format!("Failed to parse mod.config.json: {e}"))?;
     validate_mod_profile(&mut profile)?;
     Ok(profile)
 }
 
+/// 任意の mod.config.json を読み込み、同梱設定と同じ検証を行って正規化済みの内容を返す。
+/// 実行中の設定には反映しない。
+pub fn validate_mod_profile_file(path: &Path) -> Result<ModProfile, String> {
+    let raw = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read mod config '{}': {e}", path.display()))?;
+    parse_mod_profile_str(&raw)
+}
+
 fn validate_mod_profile(profile: &mut ModProfile) -> Result<(), String> {
     // スキーマ不一致は後続処理が壊れるため、最優先で弾く。
     if profile.schema_version != 1 {
@@ -307,6 +341,23 @@ fn validate_mod_profile(profile: &mut ModProfile) -> Result<(), String> {
         })?;
     }
 
+    for (idx, prefix) in profile
+        .migration
+        .local_low_additional_prefixes
+        .iter()
+        .enumerate()
+    {
+        non_empty(
+            &format!("migration.localLowAdditionalPrefixes[{idx}]"),
+            prefix,
+        )?;
+        if !is_safe_relative_path(prefix) {
+            return Err(format!(
+                "Invalid mod config: migration.localLowAdditionalPrefixes[{idx}] must be a safe relative path."
+            ));
+        }
+    }
+
     non_empty("presets.extension", &profile.presets.extension)?;
     if !profile
         .presets
@@ -485,6 +536,19 @@ pub fn github_releases_api_url() -> String {
     )
 }
 
+/// ランチャーのHTTP通信で共通利用するUser-Agentを返す。
+pub fn launcher_user_agent() -> String {
+    format!(
+        "{}/{}",
+        get().branding.launcher_name,
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+pub fn github_rate_limit_api_url() -> &'static str {
+    "https://api.github.com/rate_limit"
+}
+
 pub fn github_release_by_tag_api_base_url() -> String {
     // タグ指定APIは呼び出し側でタグを後置できるよう、末尾 /tags まで返す。
     format!(
@@ -514,6 +578,15 @@ pub fn local_low_root_path() -> PathBuf {
     to_relative_path(&get().paths.local_low_root)
 }
 
+pub fn local_low_additional_prefix_paths() -> Vec<PathBuf> {
+    get()
+        .migration
+        .local_low_additional_prefixes
+        .iter()
+        .map(|prefix| to_relative_path(prefix))
+        .collect()
+}
+
 pub fn default_game_server_id() -> Option<&'static str> {
     get()
         .apis
@@ -1,4 +1,5 @@
 // プリセットデータの列挙・書き出し・取り込みを扱うユーティリティ。
+use sha2::{Digest, Sha256};
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs::{self, File};
 use std::io::{self, Read};
@@ -11,11 +12,18 @@ use crate::utils::{mod_profile, settings};
 
 const LEGACY_PRESET_ARCHIVE_EXTENSION: &str = "snrpresets";
 const PRESET_ARCHIVE_DIR_NAME: &str = "presets";
+const OPTIONS_BACKUP_DIR_NAME: &str = "options-backups";
+const OPTIONS_BACKUP_ID_PREFIX: &str = "options-";
 const OPTIONS_FILE_NAME: &str = "Options.data";
 const PRESET_FILE_PREFIX: &str = "PresetOptions_";
 const PRESET_FILE_SUFFIX: &str = ".data";
+// 取り込み中の一時ファイルと、上書き前の退避ファイルに付ける接尾辞。
+const PRESET_IMPORT_TEMP_SUFFIX: &str = ".importing";
+const PRESET_IMPORT_BACKUP_SUFFIX: &str = ".import-backup";
+// 書き出し時の内容を記録する改ざん検知用のサイドカーエントリ。
+const ARCHIVE_CHECKSUM_ENTRY_NAME: &str = "checksum.sha256";
 
-fn preset_archive_extension() -> &'static str {
+pub fn preset_archive_extension() -> &'static str {
     // 拡張子はmod設定から取得し、派生ランチャーでも同じ実装を流用できるようにする。
     mod_profile::get().presets.extension.as_str()
 }
@@ -34,6 +42,19 @@ fn options_archive_path() -> String {
     ))
 }
 
+fn profile_save_data_root_normalized() -> String {
+    // 旧形式アーカイブはプロファイル上のSaveData配置をそのまま格納している。
+    normalize_path_for_archive(&mod_profile::save_data_root_path())
+}
+
+fn legacy_options_archive_path() -> String {
+    format!(
+        "{}/{}",
+        profile_save_data_root_normalized(),
+        OPTIONS_FILE_NAME
+    )
+}
+
 fn preset_archive_prefix_candidates() -> [String; 2] {
     [
         format!(
@@ -42,7 +63,8 @@ fn preset_archive_prefix_candidates() -> [String; 2] {
             PRESET_FILE_PREFIX.to_ascii_lowercase()
         ),
         format!(
-            "supernewrolesnext/savedata/{}",
+            "{}/{}",
+            profile_save_data_root_normalized().to_ascii_lowercase(),
             PRESET_FILE_PREFIX.to_ascii_lowercase()
         ),
     ]
@@ -67,11 +89,31 @@ pub struct PresetImportSelection {
     pub name: Option<String>,
 }
 
+/// 取り込み時に同名プリセットが既にある場合の扱い。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PresetImportStrategy {
+    #[default]
+    AlwaysNew,
+    SkipDuplicateNames,
+    Overwrite,
+}
+
+/// プリセット1件ごとに実際に行った取り込み操作。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PresetImportAction {
+    Created,
+    Skipped,
+    Overwritten,
+}
+
 #[derive(Debug, Clone)]
 pub struct ImportedPresetSummary {
     pub source_id: i32,
     pub target_id: i32,
     pub name: String,
+    pub action: PresetImportAction,
 }
 
 #[derive(Debug, Clone)]
@@ -80,6 +122,22 @@ pub struct PresetImportSummary {
     pub imported: Vec<ImportedPresetSummary>,
 }
 
+#[derive(Debug, Clone)]
+pub struct OptionsBackupSummary {
+    pub backup_id: String,
+    pub current_preset: i32,
+    pub preset_count: usize,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsRepairResult {
+    /// チェックサムを再計算して書き直した場合は true。
+    pub repaired: bool,
+    /// 修復前の Options.data をそのまま退避したバックアップID。
+    pub backup_id: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 struct OptionsData {
     version: u8,
@@ -87,10 +145,20 @@ struct OptionsData {
     preset_names: BTreeMap<i32, String>,
 }
 
+/// プリセットアーカイブの改ざん検知結果。チェックサムの無い旧形式は Unsigned になる。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PresetArchiveIntegrity {
+    Unsigned,
+    Verified,
+    Modified,
+}
+
 #[derive(Debug, Clone)]
 struct ArchiveContents {
     options: OptionsData,
     preset_files: HashMap<i32, Vec<u8>>,
+    integrity: PresetArchiveIntegrity,
 }
 
 fn normalize_path_for_archive(path: &Path) -> String {
@@ -101,7 +169,7 @@ fn make_default_preset_name(preset_id: i32) -> String {
     format!("Preset {}", preset_id.saturating_add(1))
 }
 
-fn preset_file_name(preset_id: i32) -> String {
+pub fn preset_file_name(preset_id: i32) -> String {
     format!("{PRESET_FILE_PREFIX}{preset_id}{PRESET_FILE_SUFFIX}")
 }
 
@@ -223,7 +291,6 @@ fn parse_options_data(bytes: &[u8]) -> Result<OptionsData, String> {
         return Err("Options.data is too short to parse.".to_string());
     }
 
-    let version = bytes[0];
     let checksum_seed = bytes[1] as u16;
     let checksum = bytes[2] as u16;
 
@@ -233,6 +300,16 @@ fn parse_options_data(bytes: &[u8]) -> Result<OptionsData, String> {
         );
     }
 
+    parse_options_data_unchecked(bytes)
+}
+
+// チェックサムを検証せずに本体だけを解析する。修復時の読み込みに使う。
+fn parse_options_data_unchecked(bytes: &[u8]) -> Result<OptionsData, String> {
+    if bytes.len() < 11 {
+        return Err("Options.data is too short to parse.".to_string());
+    }
+
+    let version = bytes[0];
     let mut cursor = 3usize;
     let current_preset = read_i32_le(bytes, &mut cursor, "current preset")?;
     let name_count = read_i32_le(bytes, &mut cursor, "preset name count")?;
@@ -292,6 +369,16 @@ fn build_options_data(options: &OptionsData) -> Result<Vec<u8>, String> {
     Ok(output)
 }
 
+/// プリセット機能の設定(presets.saveDataRoot)で解決したプロファイル内SaveDataの場所。
+pub fn save_data_dir_in_profile(profile_path: &Path) -> PathBuf {
+    profile_path.join(save_data_relative_path())
+}
+
+/// SaveData内の Options.data の場所。
+pub fn options_data_path(save_data_dir: &Path) -> PathBuf {
+    save_data_dir.join(OPTIONS_FILE_NAME)
+}
+
 fn profile_save_data_dir<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
     let launcher_settings = settings::load_or_init_settings(app)?;
     let profile_path = launcher_settings.profile_path.trim();
@@ -299,7 +386,7 @@ fn profile_save_data_dir<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, Stri
         return Err("Profile path is not configured.".to_string());
     }
 
-    Ok(PathBuf::from(profile_path).join(save_data_relative_path()))
+    Ok(save_data_dir_in_profile(Path::new(profile_path)))
 }
 
 fn load_options_data(path: &Path) -> Result<Option<OptionsData>, String> {
@@ -349,18 +436,24 @@ fn collect_existing_preset_ids(save_data_dir: &Path) -> Result<BTreeSet<i32>, St
     Ok(ids)
 }
 
-fn make_default_archive_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
-    let base_dir = settings::app_data_dir(app)?;
+/// 書き出し先未指定時に使う、タイムスタンプ付きのアーカイブファイル名を返す。
+pub fn default_archive_file_name() -> String {
     let timestamp = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap_or_default()
         .as_secs();
-
-    Ok(base_dir.join(PRESET_ARCHIVE_DIR_NAME).join(format!(
+    format!(
         "{}-presets-{timestamp}.{}",
         mod_profile::get().mod_info.id,
         preset_archive_extension()
-    )))
+    )
+}
+
+fn make_default_archive_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    let base_dir = settings::app_data_dir(app)?;
+    Ok(base_dir
+        .join(PRESET_ARCHIVE_DIR_NAME)
+        .join(default_archive_file_name()))
 }
 
 fn resolve_archive_output_path<R: Runtime>(
@@ -395,15 +488,27 @@ fn resolve_archive_output_path<R: Runtime>(
     Ok(output)
 }
 
+/// 取り込み可能なアーカイブ拡張子(設定値・旧拡張子・zip)を返す。
+pub fn supported_archive_extensions() -> Vec<&'static str> {
+    let mut extensions = vec![preset_archive_extension()];
+    for extension in [LEGACY_PRESET_ARCHIVE_EXTENSION, "zip"] {
+        if !extensions
+            .iter()
+            .any(|existing| existing.eq_ignore_ascii_case(extension))
+        {
+            extensions.push(extension);
+        }
+    }
+    extensions
+}
+
 fn archive_extension_is_supported(path: &Path) -> bool {
-    let configured_extension = preset_archive_extension();
     path.extension()
         .and_then(|ext| ext.to_str())
         .map(|ext| {
-            let ext_lower = ext.to_ascii_lowercase();
-            ext_lower == configured_extension.to_ascii_lowercase()
-                || ext_lower == LEGACY_PRESET_ARCHIVE_EXTENSION
-                || ext_lower == "zip"
+            supported_archive_extensions()
+                .iter()
+                .any(|supported| supported.eq_ignore_ascii_case(ext))
         })
         .unwrap_or(false)
 }
@@ -427,29 +532,27 @@ fn write_bytes_to_zip(
     Ok(())
 }
 
-fn write_file_to_zip(
-    zip: &mut ZipWriter<File>,
-    source: &Path,
-    archive_path: &str,
-) -> Result<(), String> {
-    let mut input = File::open(source).map_err(|e| {
-        format!(
-            "Failed to open preset source file '{}': {e}",
-            source.display()
-        )
-    })?;
-
-    let options = zip::write::SimpleFileOptions::default()
-        .compression_method(CompressionMethod::Deflated)
-        .unix_permissions(0o644);
-
-    zip.start_file(archive_path, options)
-        .map_err(|e| format!("Failed to start zip entry '{archive_path}': {e}"))?;
-
-    io::copy(&mut input, zip)
-        .map_err(|e| format!("Failed to write zip entry '{archive_path}': {e}"))?;
-
-    Ok(())
+/// Options.data とプリセットファイル(ID順)からアーカイブ内容のSHA256を求める。
+fn compute_archive_checksum<'a>(
+    options_bytes: &[u8],
+    preset_files: impl IntoIterator<Item = (i32, &'a [u8])>,
+) -> String {
+    let mut preset_files = preset_files.into_iter().collect::<Vec<_>>();
+    preset_files.sort_by_key(|(id, _)| *id);
+
+    let mut hasher = Sha256::new();
+    hasher.update(options_bytes);
+    for (id, data) in preset_files {
+        // IDとデータ長も含め、ファイル境界の付け替えで同じ値にならないようにする。
+        hasher.update(id.to_le_bytes());
+        hasher.update((data.len() as u64).to_le_bytes());
+        hasher.update(data);
+    }
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
 }
 
 fn read_archive_contents(archive_path: &Path) -> Result<ArchiveContents, String> {
@@ -477,9 +580,10 @@ fn read_archive_contents(archive_path: &Path) -> Result<ArchiveContents, String>
         ZipArchive::new(input_file).map_err(|e| format!("Invalid preset archive format: {e}"))?;
 
     let mut options_bytes: Option<Vec<u8>> = None;
+    let mut recorded_checksum: Option<String> = None;
     let mut preset_files = HashMap::new();
     let configured_options_path = options_archive_path();
-    let legacy_options_path = "SuperNewRolesNext/SaveData/Options.data".to_string();
+    let legacy_options_path = legacy_options_archive_path();
 
     for index in 0..archive.len() {
         let mut entry = archive
@@ -513,6 +617,11 @@ fn read_archive_contents(archive_path: &Path) -> Result<ArchiveContents, String>
             continue;
         }
 
+        if normalized.eq_ignore_ascii_case(ARCHIVE_CHECKSUM_ENTRY_NAME) {
+            recorded_checksum = Some(String::from_utf8_lossy(&data).trim().to_string());
+            continue;
+        }
+
         if let Some(preset_id) = parse_preset_id_from_archive_path(&normalized) {
             preset_files.insert(preset_id, data);
         }
@@ -526,9 +635,30 @@ fn read_archive_contents(archive_path: &Path) -> Result<ArchiveContents, String>
     })?;
     let options = parse_options_data(&options_bytes)?;
 
+    let integrity = match recorded_checksum {
+        None => PresetArchiveIntegrity::Unsigned,
+        Some(recorded) => {
+            let actual = compute_archive_checksum(
+                &options_bytes,
+                preset_files.iter().map(|(id, data)| (*id, data.as_slice())),
+            );
+            if actual.eq_ignore_ascii_case(&recorded) {
+                PresetArchiveIntegrity::Verified
+            } else {
+                // 取り込み自体は止めず、受け取った側へ警告できるよう結果に残す。
+                log::warn!(
+                    "Preset archive was modified after export: {}",
+                    archive_path.display()
+                );
+                PresetArchiveIntegrity::Modified
+            }
+        }
+    };
+
     Ok(ArchiveContents {
         options,
         preset_files,
+        integrity,
     })
 }
 
@@ -536,6 +666,20 @@ fn normalize_name_key(name: &str) -> String {
     name.trim().to_lowercase()
 }
 
+/// 同名プリセットの有無と取り込み方針から、実行する操作と対象idを決める。
+fn resolve_import_action(
+    strategy: PresetImportStrategy,
+    existing_id: Option<i32>,
+) -> (PresetImportAction, Option<i32>) {
+    match (strategy, existing_id) {
+        (PresetImportStrategy::SkipDuplicateNames, Some(id)) => {
+            (PresetImportAction::Skipped, Some(id))
+        }
+        (PresetImportStrategy::Overwrite, Some(id)) => (PresetImportAction::Overwritten, Some(id)),
+        _ => (PresetImportAction::Created, None),
+    }
+}
+
 fn make_unique_name(base_name: &str, used_names: &HashSet<String>) -> String {
     let normalized_base = base_name.trim();
     let base = if normalized_base.is_empty() {
@@ -593,11 +737,20 @@ pub fn list_presets_from_save_data_dir(
     Ok(presets)
 }
 
-pub fn export_selected_presets<R: Runtime>(
+/// SaveData内 Options.data の形式バージョンを返す。Options.data が無ければ None。
+pub fn options_data_version(save_data_dir: &Path) -> Result<Option<u8>, String> {
+    let options = load_options_data(&save_data_dir.join(OPTIONS_FILE_NAME))?;
+    Ok(options.map(|options| options.version))
+}
+
+// 書き出すOptions.dataの内容と、プリセットIDごとのデータファイル内容。
+type PreparedPresetExport = (Vec<u8>, Vec<(i32, Vec<u8>)>);
+
+// 書き出しと見積もりで同じ検証・内容になるよう、書き出すOptions.dataとプリセットを組み立てる。
+fn prepare_selected_presets_export<R: Runtime>(
     app: &AppHandle<R>,
     preset_ids: Vec<i32>,
-    output_path: Option<String>,
-) -> Result<PresetExportSummary, String> {
+) -> Result<PreparedPresetExport, String> {
     let selected_ids: BTreeSet<i32> = preset_ids.into_iter().filter(|id| *id >= 0).collect();
     if selected_ids.is_empty() {
         return Err("At least one preset must be selected for export.".to_string());
@@ -634,8 +787,16 @@ pub fn export_selected_presets<R: Runtime>(
             preset_name.trim().to_string()
         };
 
+        // チェックサムと書き込み内容を一致させるため、一度だけ読み込んで両方に使う。
+        let data = fs::read(&source_path).map_err(|e| {
+            format!(
+                "Failed to open preset source file '{}': {e}",
+                source_path.display()
+            )
+        })?;
+
         exported_names.insert(preset_id, name);
-        selected_files.push((preset_id, source_path));
+        selected_files.push((preset_id, data));
     }
 
     let current_preset = if exported_names.contains_key(&local_options.current_preset) {
@@ -657,7 +818,37 @@ pub fn export_selected_presets<R: Runtime>(
         preset_names: exported_names,
     };
 
-    let options_bytes = build_options_data(&export_options)?;
+    Ok((build_options_data(&export_options)?, selected_files))
+}
+
+/// 指定プリセットを書き出した場合のアーカイブエントリ名とサイズを返す。ファイルは書き出さない。
+pub fn selected_presets_export_entries<R: Runtime>(
+    app: &AppHandle<R>,
+    preset_ids: Vec<i32>,
+) -> Result<Vec<(String, u64)>, String> {
+    let (options_bytes, selected_files) = prepare_selected_presets_export(app, preset_ids)?;
+    let save_data_relative_path = save_data_relative_path_normalized();
+    let mut entries = vec![(options_archive_path(), options_bytes.len() as u64)];
+    for (preset_id, data) in &selected_files {
+        entries.push((
+            format!("{save_data_relative_path}/{}", preset_file_name(*preset_id)),
+            data.len() as u64,
+        ));
+    }
+    // チェックサムはSHA-256の16進文字列。
+    entries.push((
+        ARCHIVE_CHECKSUM_ENTRY_NAME.to_string(),
+        (Sha256::output_size() * 2) as u64,
+    ));
+    Ok(entries)
+}
+
+pub fn export_selected_presets<R: Runtime>(
+    app: &AppHandle<R>,
+    preset_ids: Vec<i32>,
+    output_path: Option<String>,
+) -> Result<PresetExportSummary, String> {
+    let (options_bytes, selected_files) = prepare_selected_presets_export(app, preset_ids)?;
     let archive_path = resolve_archive_output_path(app, output_path)?;
 
     if let Some(parent) = archive_path.parent() {
@@ -681,11 +872,19 @@ pub fn export_selected_presets<R: Runtime>(
     write_bytes_to_zip(&mut zip, &options_archive_path, &options_bytes)?;
     let save_data_relative_path = save_data_relative_path_normalized();
 
-    for (preset_id, source_path) in &selected_files {
+    for (preset_id, data) in &selected_files {
         let archive_entry = format!("{save_data_relative_path}/{}", preset_file_name(*preset_id));
-        write_file_to_zip(&mut zip, source_path, &archive_entry)?;
+        write_bytes_to_zip(&mut zip, &archive_entry, data)?;
     }
 
+    let checksum = compute_archive_checksum(
+        &options_bytes,
+        selected_files
+            .iter()
+            .map(|(id, data)| (*id, data.as_slice())),
+    );
+    write_bytes_to_zip(&mut zip, ARCHIVE_CHECKSUM_ENTRY_NAME, checksum.as_bytes())?;
+
     zip.finish()
         .map_err(|e| format!("Failed to finalize preset archive: {e}"))?;
 
@@ -695,6 +894,20 @@ pub fn export_selected_presets<R: Runtime>(
     })
 }
 
+/// ローカルの全プリセットをまとめてアーカイブへ書き出す。
+pub fn export_all_presets<R: Runtime>(
+    app: &AppHandle<R>,
+    output_path: Option<String>,
+) -> Result<PresetExportSummary, String> {
+    // データファイルが欠けたプリセットは書き出せないため、一括出力では対象外にする。
+    let preset_ids = list_local_presets(app)?
+        .into_iter()
+        .filter(|preset| preset.has_data_file)
+        .map(|preset| preset.id)
+        .collect();
+    export_selected_presets(app, preset_ids, output_path)
+}
+
 pub fn inspect_preset_archive(archive_path: &Path) -> Result<Vec<PresetEntrySummary>, String> {
     let contents = read_archive_contents(archive_path)?;
 
@@ -716,6 +929,11 @@ pub fn inspect_preset_archive(archive_path: &Path) -> Result<Vec<PresetEntrySumm
     Ok(presets)
 }
 
+/// 書き出し時に記録したチェックサムとアーカイブ内容を照合する。
+pub fn verify_preset_archive(archive_path: &Path) -> Result<PresetArchiveIntegrity, String> {
+    Ok(read_archive_contents(archive_path)?.integrity)
+}
+
 pub fn import_presets_from_save_data_dir<R: Runtime>(
     app: &AppHandle<R>,
     source_save_data_dir: &Path,
@@ -839,6 +1057,7 @@ pub fn import_presets_from_save_data_dir<R: Runtime>(
             source_id,
             target_id,
             name: final_name,
+            action: PresetImportAction::Created,
         });
     }
 
@@ -867,10 +1086,156 @@ pub fn import_presets_from_save_data_dir<R: Runtime>(
     })
 }
 
+fn path_with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// プリセット取り込みの書き込みをまとめて反映する。
+/// データファイルは一時ファイルへ書いておき、Options.data 更新後に差し替える。
+/// 反映前に破棄された場合や反映中に失敗した場合は、取り込み前の状態へ戻す。
+#[derive(Default)]
+struct PresetImportTransaction {
+    // (一時ファイル, 反映先)
+    staged: Vec<(PathBuf, PathBuf)>,
+    // (反映先, 退避した既存ファイル)
+    applied: Vec<(PathBuf, Option<PathBuf>)>,
+    // Options.data のパスと更新前の内容（元々存在しなければ None）。
+    original_options: Option<(PathBuf, Option<Vec<u8>>)>,
+}
+
+impl PresetImportTransaction {
+    fn stage(&mut self, target_path: PathBuf, data: &[u8]) -> Result<(), String> {
+        let temp_path = path_with_suffix(&target_path, PRESET_IMPORT_TEMP_SUFFIX);
+        // 書きかけの一時ファイルも破棄対象にするため、書き込み前に登録する。
+        self.staged.push((temp_path.clone(), target_path));
+        fs::write(&temp_path, data).map_err(|e| {
+            format!(
+                "Failed to write imported preset file '{}': {e}",
+                temp_path.display()
+            )
+        })
+    }
+
+    fn commit(mut self, options_path: &Path, options_bytes: &[u8]) -> Result<(), String> {
+        self.apply(options_path, options_bytes)?;
+        for (_, backup_path) in self.applied.drain(..) {
+            if let Some(backup_path) = backup_path {
+                if let Err(error) = fs::remove_file(&backup_path) {
+                    log::warn!(
+                        "Failed to remove preset import backup '{}': {error}",
+                        backup_path.display()
+                    );
+                }
+            }
+        }
+        self.original_options = None;
+        Ok(())
+    }
+
+    fn apply(&mut self, options_path: &Path, options_bytes: &[u8]) -> Result<(), String> {
+        let original = match fs::read(options_path) {
+            Ok(bytes) => Some(bytes),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+            Err(error) => {
+                return Err(format!(
+                    "Failed to read Options.data '{}': {error}",
+                    options_path.display()
+                ))
+            }
+        };
+        self.original_options = Some((options_path.to_path_buf(), original));
+        fs::write(options_path, options_bytes).map_err(|e| {
+            format!(
+                "Failed to write updated Options.data '{}': {e}",
+                options_path.display()
+            )
+        })?;
+
+        while let Some((temp_path, target_path)) = self.staged.last().cloned() {
+            let backup_path = if target_path.exists() {
+                let backup_path = path_with_suffix(&target_path, PRESET_IMPORT_BACKUP_SUFFIX);
+                fs::rename(&target_path, &backup_path).map_err(|e| {
+                    format!(
+                        "Failed to back up existing preset file '{}': {e}",
+                        target_path.display()
+                    )
+                })?;
+                Some(backup_path)
+            } else {
+                None
+            };
+            self.applied.push((target_path.clone(), backup_path));
+            fs::rename(&temp_path, &target_path).map_err(|e| {
+                format!(
+                    "Failed to move imported preset file into '{}': {e}",
+                    target_path.display()
+                )
+            })?;
+            self.staged.pop();
+        }
+        Ok(())
+    }
+
+    fn rollback(&mut self) {
+        for (target_path, backup_path) in self.applied.drain(..).rev() {
+            let _ = fs::remove_file(&target_path);
+            if let Some(backup_path) = backup_path {
+                if let Err(error) = fs::rename(&backup_path, &target_path) {
+                    log::warn!(
+                        "Failed to restore preset file '{}' after failed import: {error}",
+                        target_path.display()
+                    );
+                }
+            }
+        }
+        for (temp_path, _) in self.staged.drain(..) {
+            let _ = fs::remove_file(&temp_path);
+        }
+        if let Some((options_path, original)) = self.original_options.take() {
+            let restored = match original {
+                Some(bytes) => fs::write(&options_path, bytes),
+                None => fs::remove_file(&options_path),
+            };
+            if let Err(error) = restored {
+                log::warn!(
+                    "Failed to restore Options.data '{}' after failed import: {error}",
+                    options_path.display()
+                );
+            }
+        }
+    }
+}
+
+impl Drop for PresetImportTransaction {
+    fn drop(&mut self) {
+        // commit 済みなら各リストは空で、何もしない。
+        self.rollback();
+    }
+}
+
 pub fn import_presets_from_archive<R: Runtime>(
     app: &AppHandle<R>,
     archive_path: &Path,
     selections: Vec<PresetImportSelection>,
+    strategy: PresetImportStrategy,
+) -> Result<PresetImportSummary, String> {
+    let save_data_dir = profile_save_data_dir(app)?;
+    import_presets_from_archive_into_save_data_dir(
+        &save_data_dir,
+        archive_path,
+        selections,
+        strategy,
+    )
+}
+
+/// アーカイブから選択したプリセットを指定の SaveData ディレクトリへ取り込む。
+fn import_presets_from_archive_into_save_data_dir(
+    save_data_dir: &Path,
+    archive_path: &Path,
+    selections: Vec<PresetImportSelection>,
+    strategy: PresetImportStrategy,
 ) -> Result<PresetImportSummary, String> {
     if selections.is_empty() {
         return Err("At least one preset must be selected for import.".to_string());
@@ -883,8 +1248,7 @@ pub fn import_presets_from_archive<R: Runtime>(
         contents.options.version
     };
 
-    let save_data_dir = profile_save_data_dir(app)?;
-    fs::create_dir_all(&save_data_dir).map_err(|e| {
+    fs::create_dir_all(save_data_dir).map_err(|e| {
         format!(
             "Failed to create profile SaveData directory '{}': {e}",
             save_data_dir.display()
@@ -904,7 +1268,7 @@ pub fn import_presets_from_archive<R: Runtime>(
         local_options.version = archive_version;
     }
 
-    let mut used_ids = collect_existing_preset_ids(&save_data_dir)?;
+    let mut used_ids = collect_existing_preset_ids(save_data_dir)?;
     used_ids.extend(
         local_options
             .preset_names
@@ -918,9 +1282,18 @@ pub fn import_presets_from_archive<R: Runtime>(
         .values()
         .map(|name| normalize_name_key(name))
         .collect();
+    // 同名判定用に、正規化した名前からローカルidを引けるようにしておく。
+    let mut local_ids_by_name: HashMap<String, i32> = local_options
+        .preset_names
+        .iter()
+        .filter(|(id, _)| **id >= 0)
+        .map(|(id, name)| (normalize_name_key(name), *id))
+        .collect();
 
     let mut imported = Vec::new();
     let mut seen_source_ids = HashSet::new();
+    // 途中で失敗した場合は、書き込み済みの一時ファイルを破棄する。
+    let mut transaction = PresetImportTransaction::default();
 
     for selection in selections {
         let source_id = selection.source_id;
@@ -962,34 +1335,53 @@ pub fn import_presets_from_archive<R: Runtime>(
             requested_name.trim().to_string()
         };
 
-        let final_name = make_unique_name(&base_name, &used_names);
-        used_names.insert(normalize_name_key(&final_name));
-
-        let target_id = used_ids
-            .iter()
-            .next_back()
-            .copied()
-            .unwrap_or(-1)
-            .checked_add(1)
-            .ok_or_else(|| "No free preset id remains for import.".to_string())?;
-        used_ids.insert(target_id);
+        let existing_id = local_ids_by_name
+            .get(&normalize_name_key(&base_name))
+            .copied();
+        let (action, existing_target) = resolve_import_action(strategy, existing_id);
+
+        let (target_id, final_name) = match existing_target {
+            Some(target_id) => {
+                let name = local_options
+                    .preset_names
+                    .get(&target_id)
+                    .cloned()
+                    .unwrap_or(base_name);
+                (target_id, name)
+            }
+            None => {
+                let final_name = make_unique_name(&base_name, &used_names);
+                used_names.insert(normalize_name_key(&final_name));
+
+                let target_id = used_ids
+                    .iter()
+                    .next_back()
+                    .copied()
+                    .unwrap_or(-1)
+                    .checked_add(1)
+                    .ok_or_else(|| "No free preset id remains for import.".to_string())?;
+                used_ids.insert(target_id);
+                (target_id, final_name)
+            }
+        };
 
-        let target_path = preset_file_path(&save_data_dir, target_id);
-        fs::write(&target_path, source_data).map_err(|e| {
-            format!(
-                "Failed to write imported preset file '{}': {e}",
-                target_path.display()
-            )
-        })?;
+        if action != PresetImportAction::Skipped {
+            // 上書き時も名前は既存のまま、データファイルだけを差し替える。
+            transaction.stage(preset_file_path(save_data_dir, target_id), source_data)?;
 
-        local_options
-            .preset_names
-            .insert(target_id, final_name.clone());
+            local_options
+                .preset_names
+                .insert(target_id, final_name.clone());
+            local_ids_by_name
+                .entry(normalize_name_key(&final_name))
+                .or_insert(target_id);
+        }
 
         imported.push(ImportedPresetSummary {
             source_id,
             target_id,
             name: final_name,
+            action,
         });
     }
 
@@ -1005,19 +1397,206 @@ pub fn import_presets_from_archive<R: Runtime>(
     }
 
     let updated_options = build_options_data(&local_options)?;
-    fs::write(&options_path, updated_options).map_err(|e| {
+    transaction
+        .commit(&options_path, &updated_options)
+        .map_err(|error| format!("{error} No presets were imported."))?;
+
+    Ok(PresetImportSummary {
+        imported_presets: imported
+            .iter()
+            .filter(|item| item.action != PresetImportAction::Skipped)
+            .count(),
+        imported,
+    })
+}
+
+fn options_backup_dir<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    Ok(settings::app_data_dir(app)?.join(OPTIONS_BACKUP_DIR_NAME))
+}
+
+fn options_backup_path(backup_dir: &Path, backup_id: &str) -> Result<PathBuf, String> {
+    // バックアップIDはファイル名へそのまま使うため、自前で採番した形式以外を拒否する。
+    let is_valid = backup_id
+        .strip_prefix(OPTIONS_BACKUP_ID_PREFIX)
+        .is_some_and(|suffix| !suffix.is_empty() && suffix.chars().all(|c| c.is_ascii_digit()));
+    if !is_valid {
+        return Err(format!("Invalid Options.data backup id: {backup_id}"));
+    }
+    Ok(backup_dir.join(format!("{backup_id}{PRESET_FILE_SUFFIX}")))
+}
+
+fn summarize_options_backup(backup_id: String, options: &OptionsData) -> OptionsBackupSummary {
+    OptionsBackupSummary {
+        backup_id,
+        current_preset: options.current_preset,
+        preset_count: options.preset_names.len(),
+    }
+}
+
+fn backup_options_file(
+    options_path: &Path,
+    backup_dir: &Path,
+    backup_id: String,
+) -> Result<OptionsBackupSummary, String> {
+    let bytes = fs::read(options_path).map_err(|e| {
         format!(
-            "Failed to write updated Options.data '{}': {e}",
+            "Failed to read Options.data '{}': {e}",
             options_path.display()
         )
     })?;
+    // 壊れたファイルを退避しても復元に使えないため、解析できることを先に確認する。
+    let options = parse_options_data(&bytes)?;
 
-    Ok(PresetImportSummary {
-        imported_presets: imported.len(),
-        imported,
+    fs::create_dir_all(backup_dir).map_err(|e| {
+        format!(
+            "Failed to create Options.data backup directory '{}': {e}",
+            backup_dir.display()
+        )
+    })?;
+    let backup_path = options_backup_path(backup_dir, &backup_id)?;
+    fs::write(&backup_path, bytes).map_err(|e| {
+        format!(
+            "Failed to write Options.data backup '{}': {e}",
+            backup_path.display()
+        )
+    })?;
+
+    Ok(summarize_options_backup(backup_id, &options))
+}
+
+fn restore_options_file(
+    backup_dir: &Path,
+    backup_id: &str,
+    options_path: &Path,
+) -> Result<OptionsBackupSummary, String> {
+    let backup_path = options_backup_path(backup_dir, backup_id)?;
+    let options = load_options_data(&backup_path)?.ok_or_else(|| {
+        format!(
+            "Options.data backup was not found: {}",
+            backup_path.display()
+        )
+    })?;
+
+    if let Some(parent) = options_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            format!(
+                "Failed to create SaveData directory '{}': {e}",
+                parent.display()
+            )
+        })?;
+    }
+    fs::write(options_path, build_options_data(&options)?).map_err(|e| {
+        format!(
+            "Failed to write restored Options.data '{}': {e}",
+            options_path.display()
+        )
+    })?;
+
+    Ok(summarize_options_backup(backup_id.to_string(), &options))
+}
+
+/// 現在のプロファイルの Options.data だけをアプリデータ配下へ退避する。
+pub fn backup_options_data<R: Runtime>(app: &AppHandle<R>) -> Result<OptionsBackupSummary, String> {
+    let options_path = profile_save_data_dir(app)?.join(OPTIONS_FILE_NAME);
+    if !options_path.is_file() {
+        return Err(format!(
+            "Options.data was not found: {}",
+            options_path.display()
+        ));
+    }
+
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    backup_options_file(
+        &options_path,
+        &options_backup_dir(app)?,
+        format!("{OPTIONS_BACKUP_ID_PREFIX}{millis}"),
+    )
+}
+
+fn repair_options_file(
+    options_path: &Path,
+    backup_dir: &Path,
+    backup_id: String,
+) -> Result<OptionsRepairResult, String> {
+    let bytes = fs::read(options_path).map_err(|e| {
+        format!(
+            "Failed to read Options.data '{}': {e}",
+            options_path.display()
+        )
+    })?;
+    if parse_options_data(&bytes).is_ok() {
+        return Ok(OptionsRepairResult {
+            repaired: false,
+            backup_id: None,
+        });
+    }
+
+    // チェックサム以外の構造が読めない場合は修復できないため、元のファイルに触れない。
+    let options = parse_options_data_unchecked(&bytes)
+        .map_err(|e| format!("Options.data could not be repaired: {e}"))?;
+
+    fs::create_dir_all(backup_dir).map_err(|e| {
+        format!(
+            "Failed to create Options.data backup directory '{}': {e}",
+            backup_dir.display()
+        )
+    })?;
+    let backup_path = options_backup_path(backup_dir, &backup_id)?;
+    fs::write(&backup_path, &bytes).map_err(|e| {
+        format!(
+            "Failed to write Options.data backup '{}': {e}",
+            backup_path.display()
+        )
+    })?;
+    fs::write(options_path, build_options_data(&options)?).map_err(|e| {
+        format!(
+            "Failed to write repaired Options.data '{}': {e}",
+            options_path.display()
+        )
+    })?;
+
+    Ok(OptionsRepairResult {
+        repaired: true,
+        backup_id: Some(backup_id),
     })
 }
 
+/// 本体は読めるがチェックサムだけが不正な Options.data を、元ファイルを退避してから書き直す。
+pub fn repair_options_data<R: Runtime>(app: &AppHandle<R>) -> Result<OptionsRepairResult, String> {
+    let options_path = profile_save_data_dir(app)?.join(OPTIONS_FILE_NAME);
+    if !options_path.is_file() {
+        return Err(format!(
+            "Options.data was not found: {}",
+            options_path.display()
+        ));
+    }
+
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    repair_options_file(
+        &options_path,
+        &options_backup_dir(app)?,
+        format!("{OPTIONS_BACKUP_ID_PREFIX}{millis}"),
+    )
+}
+
+/// 退避済みの Options.data を現在のプロファイルへ書き戻す。
+pub fn restore_options_data<R: Runtime>(
+    app: &AppHandle<R>,
+    backup_id: &str,
+) -> Result<OptionsBackupSummary, String> {
+    restore_options_file(
+        &options_backup_dir(app)?,
+        backup_id.trim(),
+        &profile_save_data_dir(app)?.join(OPTIONS_FILE_NAME),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1034,6 +1613,400 @@ mod tests {
         ))
     }
 
+    #[test]
+    fn preset_import_transaction_restores_files_after_partial_apply() {
+        let root = make_temp_dir("import-partial-rollback");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).expect("failed to create temp dir");
+        let options_path = root.join(OPTIONS_FILE_NAME);
+        fs::write(&options_path, b"original options").expect("write options");
+        fs::write(preset_file_path(&root, 0), b"original 0").expect("write preset 0");
+        fs::write(preset_file_path(&root, 2), b"original 2").expect("write preset 2");
+
+        let mut transaction = PresetImportTransaction::default();
+        transaction
+            .stage(preset_file_path(&root, 0), b"imported 0")
+            .expect("stage preset 0");
+        transaction
+            .stage(preset_file_path(&root, 1), b"imported 1")
+            .expect("stage preset 1");
+        transaction
+            .stage(preset_file_path(&root, 2), b"imported 2")
+            .expect("stage preset 2");
+        // 反映は登録の逆順に進むため、最後に反映する preset 0 の一時ファイルを消して
+        // Options.data と他のプリセットを差し替えた後で失敗させる。
+        fs::remove_file(path_with_suffix(
+            &preset_file_path(&root, 0),
+            PRESET_IMPORT_TEMP_SUFFIX,
+        ))
+        .expect("remove staged file");
+        assert!(transaction
+            .commit(&options_path, b"updated options")
+            .is_err());
+
+        assert_eq!(
+            fs::read(&options_path).expect("read options"),
+            b"original options"
+        );
+        assert_eq!(
+            fs::read(preset_file_path(&root, 0)).expect("read preset 0"),
+            b"original 0"
+        );
+        assert_eq!(
+            fs::read(preset_file_path(&root, 2)).expect("read preset 2"),
+            b"original 2"
+        );
+        let mut remaining = fs::read_dir(&root)
+            .expect("read dir")
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        remaining.sort();
+        assert_eq!(
+            remaining,
+            vec![
+                OPTIONS_FILE_NAME.to_string(),
+                preset_file_name(0),
+                preset_file_name(2)
+            ]
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn preset_import_transaction_rolls_back_on_failed_commit() {
+        let root = make_temp_dir("import-rollback");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).expect("failed to create temp dir");
+        let existing_path = preset_file_path(&root, 0);
+        fs::write(&existing_path, b"original").expect("write existing preset");
+
+        let mut transaction = PresetImportTransaction::default();
+        transaction
+            .stage(existing_path.clone(), b"imported")
+            .expect("stage overwrite");
+        transaction
+            .stage(preset_file_path(&root, 1), b"new")
+            .expect("stage new preset");
+        // Options.data の位置にディレクトリを置き、書き込みを失敗させる。
+        let options_path = root.join(OPTIONS_FILE_NAME);
+        fs::create_dir_all(&options_path).expect("create blocking dir");
+        assert!(transaction.commit(&options_path, b"options").is_err());
+
+        assert_eq!(fs::read(&existing_path).expect("read preset"), b"original");
+        let mut remaining = fs::read_dir(&root)
+            .expect("read dir")
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        remaining.sort();
+        assert_eq!(
+            remaining,
+            vec![OPTIONS_FILE_NAME.to_string(), preset_file_name(0)]
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn options_repair_rewrites_bad_checksum_and_keeps_original() {
+        let root = make_temp_dir("options-repair");
+        let _ = fs::remove_dir_all(&root);
+        let backup_dir = root.join("backups");
+        fs::create_dir_all(&root).expect("failed to create temp dir");
+
+        let mut names = BTreeMap::new();
+        names.insert(2, "Beta".to_string());
+        let mut bytes = build_options_data(&OptionsData {
+            version: 1,
+            current_preset: 2,
+            preset_names: names,
+        })
+        .expect("build options");
+        // 手動編集などでチェックサムだけが壊れた状態を再現する。
+        bytes[1] = 3;
+        bytes[2] = 4;
+        let options_path = root.join(OPTIONS_FILE_NAME);
+        fs::write(&options_path, &bytes).expect("write options");
+
+        let result = repair_options_file(&options_path, &backup_dir, "options-1".to_string())
+            .expect("repair should succeed");
+        assert!(result.repaired);
+        assert_eq!(result.backup_id.as_deref(), Some("options-1"));
+        assert_eq!(
+            fs::read(backup_dir.join("options-1.data")).expect("read backup"),
+            bytes
+        );
+        let repaired = load_options_data(&options_path)
+            .expect("repaired options should parse")
+            .expect("options should exist");
+        assert_eq!(repaired.current_preset, 2);
+
+        let again = repair_options_file(&options_path, &backup_dir, "options-2".to_string())
+            .expect("valid file should be left as is");
+        assert!(!again.repaired);
+
+        fs::write(&options_path, [1u8, 3, 4]).expect("write truncated options");
+        assert!(repair_options_file(&options_path, &backup_dir, "options-3".to_string()).is_err());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn archive_checksum_detects_modified_entries() {
+        let root = make_temp_dir("checksum");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).expect("failed to create temp dir");
+
+        let options_bytes = build_options_data(&OptionsData {
+            version: 1,
+            current_preset: 0,
+            preset_names: BTreeMap::from([(0, "Preset".to_string())]),
+        })
+        .expect("failed to build options");
+        let preset_entry = format!(
+            "{}/{}",
+            save_data_relative_path_normalized(),
+            preset_file_name(0)
+        );
+        let checksum = compute_archive_checksum(&options_bytes, [(0, [1u8, 2, 3].as_slice())]);
+
+        let write_archive = |name: &str, preset_data: &[u8], checksum: Option<&str>| {
+            let path = root.join(name);
+            let mut zip = ZipWriter::new(File::create(&path).expect("failed to create archive"));
+            write_bytes_to_zip(&mut zip, &options_archive_path(), &options_bytes)
+                .expect("failed to write options");
+            write_bytes_to_zip(&mut zip, &preset_entry, preset_data)
+                .expect("failed to write preset");
+            if let Some(checksum) = checksum {
+                write_bytes_to_zip(&mut zip, ARCHIVE_CHECKSUM_ENTRY_NAME, checksum.as_bytes())
+                    .expect("failed to write checksum");
+            }
+            zip.finish().expect("failed to finalize archive");
+            path
+        };
+
+        let verified = write_archive("verified.zip", &[1, 2, 3], Some(&checksum));
+        let modified = write_archive("modified.zip", &[9, 9, 9], Some(&checksum));
+        let unsigned = write_archive("unsigned.zip", &[1, 2, 3], None);
+        assert_eq!(
+            verify_preset_archive(&verified).expect("verified archive should be readable"),
+            PresetArchiveIntegrity::Verified
+        );
+        assert_eq!(
+            verify_preset_archive(&modified).expect("modified archive should stay readable"),
+            PresetArchiveIntegrity::Modified
+        );
+        assert_eq!(
+            verify_preset_archive(&unsigned).expect("unsigned archive should stay readable"),
+            PresetArchiveIntegrity::Unsigned
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn import_action_follows_strategy_for_duplicate_names() {
+        assert_eq!(
+            resolve_import_action(PresetImportStrategy::AlwaysNew, Some(3)),
+            (PresetImportAction::Created, None)
+        );
+        assert_eq!(
+            resolve_import_action(PresetImportStrategy::SkipDuplicateNames, Some(3)),
+            (PresetImportAction::Skipped, Some(3))
+        );
+        assert_eq!(
+            resolve_import_action(PresetImportStrategy::Overwrite, Some(3)),
+            (PresetImportAction::Overwritten, Some(3))
+        );
+        assert_eq!(
+            resolve_import_action(PresetImportStrategy::Overwrite, None),
+            (PresetImportAction::Created, None)
+        );
+    }
+
+    #[test]
+    fn import_from_archive_applies_duplicate_name_strategy() {
+        let root = make_temp_dir("import-strategy");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).expect("failed to create temp dir");
+
+        // アーカイブには既存と同名の "alpha" と新規の "Beta" を入れる。
+        let archive_options = build_options_data(&OptionsData {
+            version: 1,
+            current_preset: 0,
+            preset_names: BTreeMap::from([(0, "alpha".to_string()), (1, "Beta".to_string())]),
+        })
+        .expect("failed to build archive options");
+        let archive_path = root.join("presets.zip");
+        let mut zip =
+            ZipWriter::new(File::create(&archive_path).expect("failed to create archive"));
+        write_bytes_to_zip(&mut zip, &options_archive_path(), &archive_options)
+            .expect("failed to write options");
+        for (id, data) in [(0, [9u8, 9, 9]), (1, [8u8, 8, 8])] {
+            let entry = format!(
+                "{}/{}",
+                save_data_relative_path_normalized(),
+                preset_file_name(id)
+            );
+            write_bytes_to_zip(&mut zip, &entry, &data).expect("failed to write preset");
+        }
+        zip.finish().expect("failed to finalize archive");
+
+        let local_options = build_options_data(&OptionsData {
+            version: 1,
+            current_preset: 0,
+            preset_names: BTreeMap::from([(0, "Alpha".to_string())]),
+        })
+        .expect("failed to build local options");
+        let import_with = |label: &str, strategy: PresetImportStrategy| {
+            let save_data_dir = root.join(label);
+            fs::create_dir_all(&save_data_dir).expect("failed to create SaveData dir");
+            fs::write(save_data_dir.join(OPTIONS_FILE_NAME), &local_options)
+                .expect("failed to write local options");
+            fs::write(save_data_dir.join(preset_file_name(0)), [1u8, 1, 1])
+                .expect("failed to write local preset");
+            let selections = [0, 1]
+                .into_iter()
+                .map(|source_id| PresetImportSelection {
+                    source_id,
+                    name: None,
+                })
+                .collect();
+            let summary = import_presets_from_archive_into_save_data_dir(
+                &save_data_dir,
+                &archive_path,
+                selections,
+                strategy,
+            )
+            .expect("import failed");
+            (save_data_dir, summary)
+        };
+
+        let (skip_dir, skipped) = import_with("skip", PresetImportStrategy::SkipDuplicateNames);
+        assert_eq!(skipped.imported_presets, 1);
+        assert_eq!(skipped.imported[0].action, PresetImportAction::Skipped);
+        assert_eq!(skipped.imported[0].target_id, 0);
+        assert_eq!(
+            fs::read(skip_dir.join(preset_file_name(0))).expect("read preset"),
+            [1, 1, 1]
+        );
+        assert_eq!(skipped.imported[1].action, PresetImportAction::Created);
+        assert_eq!(
+            fs::read(skip_dir.join(preset_file_name(skipped.imported[1].target_id)))
+                .expect("read preset"),
+            [8, 8, 8]
+        );
+
+        let (overwrite_dir, overwritten) =
+            import_with("overwrite", PresetImportStrategy::Overwrite);
+        assert_eq!(overwritten.imported_presets, 2);
+        assert_eq!(
+            overwritten.imported[0].action,
+            PresetImportAction::Overwritten
+        );
+        assert_eq!(overwritten.imported[0].target_id, 0);
+        assert_eq!(overwritten.imported[0].name, "Alpha");
+        assert_eq!(
+            fs::read(overwrite_dir.join(preset_file_name(0))).expect("read preset"),
+            [9, 9, 9]
+        );
+
+        let (new_dir, created) = import_with("always-new", PresetImportStrategy::AlwaysNew);
+        assert_eq!(created.imported_presets, 2);
+        assert_eq!(created.imported[0].action, PresetImportAction::Created);
+        assert_ne!(created.imported[0].target_id, 0);
+        assert_ne!(normalize_name_key(&created.imported[0].name), "alpha");
+        assert_eq!(
+            fs::read(new_dir.join(preset_file_name(0))).expect("read preset"),
+            [1, 1, 1]
+        );
+        let presets = list_presets_from_save_data_dir(&new_dir).expect("list failed");
+        assert_eq!(presets.len(), 3);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn options_backup_round_trips_and_rejects_unknown_ids() {
+        let root = make_temp_dir("options-backup");
+        let _ = fs::remove_dir_all(&root);
+        let save_data_dir = root.join("SaveData");
+        let backup_dir = root.join("backups");
+        fs::create_dir_all(&save_data_dir).expect("failed to create temp dir");
+
+        let mut names = BTreeMap::new();
+        names.insert(0, "Alpha".to_string());
+        let options = OptionsData {
+            version: 1,
+            current_preset: 0,
+            preset_names: names,
+        };
+        let options_path = save_data_dir.join(OPTIONS_FILE_NAME);
+        fs::write(
+            &options_path,
+            build_options_data(&options).expect("build options"),
+        )
+        .expect("write options");
+
+        let backup = backup_options_file(&options_path, &backup_dir, "options-1".to_string())
+            .expect("backup should succeed");
+        assert_eq!(backup.preset_count, 1);
+
+        fs::remove_file(&options_path).expect("remove options");
+        let restored =
+            restore_options_file(&backup_dir, "options-1", &options_path).expect("restore");
+        assert_eq!(restored.current_preset, 0);
+        let reloaded = load_options_data(&options_path)
+            .expect("reload options")
+            .expect("options should exist");
+        assert_eq!(
+            reloaded.preset_names.get(&0).map(String::as_str),
+            Some("Alpha")
+        );
+
+        assert!(restore_options_file(&backup_dir, "../options-1", &options_path).is_err());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn archive_paths_follow_configured_roots() {
+        let presets_config = &mod_profile::get().presets;
+        let paths_config = &mod_profile::get().paths;
+
+        assert_eq!(
+            options_archive_path(),
+            presets_config.options_archive_path.trim_matches('/')
+        );
+        assert_eq!(
+            legacy_options_archive_path(),
+            format!(
+                "{}/{OPTIONS_FILE_NAME}",
+                paths_config.save_data_root.trim_matches('/')
+            )
+        );
+
+        let [configured_prefix, legacy_prefix] = preset_archive_prefix_candidates();
+        assert_eq!(
+            configured_prefix,
+            format!(
+                "{}/{PRESET_FILE_PREFIX}",
+                presets_config.save_data_root.trim_matches('/')
+            )
+            .to_ascii_lowercase()
+        );
+        assert_eq!(
+            legacy_prefix,
+            format!(
+                "{}/{PRESET_FILE_PREFIX}",
+                paths_config.save_data_root.trim_matches('/')
+            )
+            .to_ascii_lowercase()
+        );
+    }
+
     #[test]
     fn list_presets_from_save_data_dir_marks_missing_data_files() {
         let save_data_dir = make_temp_dir("list-presets");
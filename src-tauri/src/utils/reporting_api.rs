@@ -13,14 +13,20 @@ use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::sync::{Mutex, OnceLock};
-use std::time::SystemTime;
+use std::time::{Duration, Instant, SystemTime};
 use tauri::{AppHandle, Emitter, Runtime};
 
-use crate::utils::{mod_profile, settings};
+use crate::utils::{migration, mod_profile, settings};
 
 const TOKEN_FILE_NAME: &str = "RequestInGame.token";
 const NO_VALID_REPORTING_TOKEN_ERROR: &str = "No valid reporting token found";
 const LOG_OUTPUT_RELATIVE_PATH: &str = "BepInEx/LogOutput.log";
+const PLAYER_LOG_FILE_NAME: &str = "Player.log";
+// クラッシュ時のスタックは末尾に出るため、上限を超えた分は先頭側を切り捨てる。
+const PLAYER_LOG_MAX_BYTES: usize = 2 * 1024 * 1024;
+// 追加添付はbase64で膨らむため、1ファイルと合計の両方に上限を設ける。
+const ATTACHMENT_MAX_FILE_BYTES: u64 = 4 * 1024 * 1024;
+const ATTACHMENT_MAX_TOTAL_BYTES: u64 = 8 * 1024 * 1024;
 const LOG_ENCRYPTION_KEY_SOURCE: &str = "SNRLogKey2024!@#";
 const B64: base64::engine::GeneralPurpose = base64::engine::general_purpose::STANDARD;
 const REPORT_SEND_PROGRESS_EVENT: &str = "reporting-send-progress";
@@ -29,8 +35,10 @@ const REPORT_SEND_PREPARE_PROGRESS_MAX: f64 = 32.0;
 const REPORT_SEND_UPLOAD_PROGRESS_MIN: f64 = 32.0;
 const REPORT_SEND_UPLOAD_PROGRESS_MAX: f64 = 96.0;
 const REPORT_SEND_PROCESSING_PROGRESS: f64 = 99.0;
+const TERMS_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
 
 static TOKEN_CACHE: OnceLock<Mutex<Option<String>>> = OnceLock::new();
+static TERMS_CACHE: Mutex<Option<(Instant, String)>> = Mutex::new(None);
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -51,6 +59,24 @@ pub struct ReportThread {
     pub current_status: ReportStatus,
 }
 
+/// 同じ状態のスレッドをまとめた件数。状態名はサーバー側の表記をそのまま使う。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportStatusCount {
+    pub status: ReportStatus,
+    pub threads: usize,
+    pub unread: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportStatusSummary {
+    pub total_threads: usize,
+    pub unread_threads: usize,
+    /// 一覧で最初に現れた順に並べる。
+    pub by_status: Vec<ReportStatusCount>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReportMessage {
@@ -88,6 +114,25 @@ pub struct PrepareAccountSummary {
     pub created_account: bool,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportingHealthStep {
+    pub id: String,
+    pub success: bool,
+    pub latency_ms: u64,
+    pub message: Option<String>,
+}
+
+/// 通報APIの疎通・認証診断結果。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportingHealthcheck {
+    pub healthy: bool,
+    pub token_source: Option<String>,
+    pub created_account: bool,
+    pub steps: Vec<ReportingHealthStep>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LogSourceInfo {
@@ -106,6 +151,11 @@ pub struct SendReportInput {
     pub map: Option<String>,
     pub role: Option<String>,
     pub timing: Option<String>,
+    #[serde(default)]
+    pub include_player_log: bool,
+    /// クラッシュダンプや設定ファイルなど、追加で添付するファイルの絶対パス。
+    #[serde(default)]
+    pub attachment_paths: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -216,22 +266,14 @@ fn reporting_api_base_url() -> &'static str {
     mod_profile::get().apis.reporting_base_url.as_str()
 }
 
-fn reporting_user_agent() -> String {
-    // ランチャー識別情報をUser-Agentへ載せてサーバ側解析を容易にする。
-    format!(
-        "{}/{}",
-        mod_profile::get().branding.launcher_name,
-        env!("CARGO_PKG_VERSION")
-    )
-}
-
 fn reporting_token_relative_path() -> PathBuf {
     mod_profile::to_relative_path(&mod_profile::get().paths.report_token_relative_path)
 }
 
 fn reporting_client() -> Result<Client, String> {
     Client::builder()
-        .user_agent(reporting_user_agent())
+        // ランチャー識別情報をUser-Agentへ載せてサーバ側解析を容易にする。
+        .user_agent(mod_profile::launcher_user_agent())
         .build()
         .map_err(|e| format!("Failed to create reporting API client: {e}"))
 }
@@ -583,6 +625,34 @@ fn make_log_encryption_key() -> [u8; 32] {
     key
 }
 
+/// 上限バイト数を超えるログを、文字境界を保ったまま末尾側だけ残して切り詰める。
+fn truncate_log_tail(log_text: &str, max_bytes: usize) -> &str {
+    if log_text.len() <= max_bytes {
+        return log_text;
+    }
+    let mut start = log_text.len() - max_bytes;
+    while !log_text.is_char_boundary(start) {
+        start += 1;
+    }
+    &log_text[start..]
+}
+
+/// LocalLow配下のUnity Player.logを読み込む。見つからない場合は None を返す。
+fn read_player_log() -> Result<Option<String>, String> {
+    let path = migration::resolve_locallow_root()?
+        .join(mod_profile::local_low_root_path())
+        .join(PLAYER_LOG_FILE_NAME);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let bytes = fs::read(&path)
+        .map_err(|e| format!("Failed to read Unity Player.log '{}': {e}", path.display()))?;
+    let text = String::from_utf8_lossy(&bytes);
+    Ok(Some(
+        truncate_log_tail(&text, PLAYER_LOG_MAX_BYTES).to_string(),
+    ))
+}
+
 fn compress_and_encrypt_log(log_text: &str) -> Result<String, String> {
     if log_text.is_empty() {
         return Ok(String::new());
@@ -618,6 +688,76 @@ fn compress_and_encrypt_log(log_text: &str) -> Result<String, String> {
     Ok(B64.encode(output))
 }
 
+/// compress_and_encrypt_log の逆変換を行い、平文ログを返す。
+/// 送信ログの検証やサーバー側の復号失敗調査に使うため、デバッグビルドとテストでのみ提供する。
+#[cfg(any(debug_assertions, test))]
+pub fn decrypt_and_decompress_log(encoded: &str) -> Result<String, String> {
+    use brotli::Decompressor;
+    use cbc::cipher::BlockDecryptMut;
+    use cbc::Decryptor;
+    use std::io::Read;
+
+    let encoded = encoded.trim();
+    if encoded.is_empty() {
+        return Ok(String::new());
+    }
+
+    let payload = B64
+        .decode(encoded)
+        .map_err(|e| format!("Failed to decode encrypted log (base64): {e}"))?;
+    if payload.len() <= 16 {
+        return Err("Encrypted log is too short to contain IV and ciphertext.".to_string());
+    }
+
+    let key = make_log_encryption_key();
+    let (iv, ciphertext) = payload.split_at(16);
+    let mut buffer = ciphertext.to_vec();
+    let compressed = Decryptor::<Aes256>::new((&key).into(), iv.into())
+        .decrypt_padded_mut::<Pkcs7>(&mut buffer)
+        .map_err(|e| format!("Failed to decrypt log payload: {e}"))?;
+
+    let mut plain = Vec::new();
+    Decompressor::new(compressed, 4096)
+        .read_to_end(&mut plain)
+        .map_err(|e| format!("Failed to decompress log payload: {e}"))?;
+    String::from_utf8(plain).map_err(|e| format!("Decrypted log is not valid UTF-8: {e}"))
+}
+
+/// 通報利用規約の本文を取得する。取得結果は一定時間メモリへキャッシュする。
+pub async fn get_terms_text() -> Result<String, String> {
+    if let Ok(guard) = TERMS_CACHE.lock() {
+        if let Some((fetched_at, text)) = guard.as_ref() {
+            if fetched_at.elapsed() < TERMS_CACHE_TTL {
+                return Ok(text.clone());
+            }
+        }
+    }
+
+    let terms_url = mod_profile::get().apis.reporting_terms_url.as_str();
+    let response = reporting_client()?
+        .get(terms_url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch reporting terms: {e}"))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Failed to fetch reporting terms ({})",
+            response.status()
+        ));
+    }
+
+    let text = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read reporting terms: {e}"))?;
+
+    if let Ok(mut guard) = TERMS_CACHE.lock() {
+        *guard = Some((Instant::now(), text.clone()));
+    }
+    Ok(text)
+}
+
 pub async fn prepare_account<R: Runtime>(
     app: &AppHandle<R>,
 ) -> Result<PrepareAccountSummary, String> {
@@ -693,6 +833,57 @@ pub async fn list_threads<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<ReportTh
     Ok(threads)
 }
 
+fn summarize_threads(threads: &[ReportThread]) -> ReportStatusSummary {
+    let mut by_status: Vec<ReportStatusCount> = Vec::new();
+    for thread in threads {
+        let index = match by_status
+            .iter()
+            .position(|entry| entry.status.status == thread.current_status.status)
+        {
+            Some(index) => index,
+            None => {
+                by_status.push(ReportStatusCount {
+                    status: thread.current_status.clone(),
+                    threads: 0,
+                    unread: 0,
+                });
+                by_status.len() - 1
+            }
+        };
+        by_status[index].threads += 1;
+        if thread.unread {
+            by_status[index].unread += 1;
+        }
+    }
+
+    ReportStatusSummary {
+        total_threads: threads.len(),
+        unread_threads: threads.iter().filter(|thread| thread.unread).count(),
+        by_status,
+    }
+}
+
+/// スレッド一覧を状態ごとに集計する。
+pub async fn status_summary<R: Runtime>(app: &AppHandle<R>) -> Result<ReportStatusSummary, String> {
+    Ok(summarize_threads(&list_threads(app).await?))
+}
+
+/// 指定スレッドが現在もスレッド一覧に存在するか確認し、存在すれば最新の要約を返す。
+pub async fn resolve_thread<R: Runtime>(
+    app: &AppHandle<R>,
+    thread_id: &str,
+) -> Result<Option<ReportThread>, String> {
+    let normalized_thread_id = thread_id.trim();
+    if normalized_thread_id.is_empty() {
+        return Err("thread_id is required".to_string());
+    }
+
+    Ok(list_threads(app)
+        .await?
+        .into_iter()
+        .find(|thread| thread.thread_id == normalized_thread_id))
+}
+
 pub async fn get_messages<R: Runtime>(
     app: &AppHandle<R>,
     thread_id: &str,
@@ -794,6 +985,61 @@ pub async fn send_message<R: Runtime>(
     Ok(())
 }
 
+/// 追加添付ファイルを検証して読み込み、送信用の `{ name, size, data }` 配列にする。
+fn read_report_attachments(
+    paths: &[String],
+    max_file_bytes: u64,
+    max_total_bytes: u64,
+) -> Result<Vec<Value>, String> {
+    let mut total_bytes = 0u64;
+    let mut attachments = Vec::with_capacity(paths.len());
+    for raw_path in paths {
+        let path = Path::new(raw_path.trim());
+        if !path.is_absolute() {
+            return Err(format!("Attachment path must be absolute: {raw_path}"));
+        }
+        // リンク経由で意図しない場所を読まないよう、実体の通常ファイルだけを受け付ける。
+        let metadata = fs::symlink_metadata(path)
+            .map_err(|e| format!("Failed to read attachment '{}': {e}", path.display()))?;
+        if !metadata.is_file() {
+            return Err(format!(
+                "Attachment is not a regular file: {}",
+                path.display()
+            ));
+        }
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        // 報告用トークンは認証情報のため添付させない。
+        if name.eq_ignore_ascii_case(TOKEN_FILE_NAME) {
+            return Err(format!("Attachment is not allowed: {name}"));
+        }
+
+        let size = metadata.len();
+        if size > max_file_bytes {
+            return Err(format!(
+                "Attachment '{name}' is too large ({size} bytes, limit {max_file_bytes} bytes)"
+            ));
+        }
+        total_bytes += size;
+        if total_bytes > max_total_bytes {
+            return Err(format!(
+                "Attachments exceed the total size limit of {max_total_bytes} bytes"
+            ));
+        }
+
+        let bytes = fs::read(path)
+            .map_err(|e| format!("Failed to read attachment '{}': {e}", path.display()))?;
+        let mut attachment = Map::new();
+        attachment.insert("name".to_string(), Value::String(name));
+        attachment.insert("size".to_string(), Value::from(bytes.len() as u64));
+        attachment.insert("data".to_string(), Value::String(B64.encode(&bytes)));
+        attachments.push(Value::Object(attachment));
+    }
+    Ok(attachments)
+}
+
 pub async fn send_report<R: Runtime>(
     app: &AppHandle<R>,
     input: SendReportInput,
@@ -811,6 +1057,16 @@ pub async fn send_report<R: Runtime>(
 
     emit_report_send_progress(app, "preparing", 0.0, 0, 0);
 
+    // 添付の不備はトークン解決などの通信前に弾く。
+    let attachments = read_report_attachments(
+        &input.attachment_paths,
+        ATTACHMENT_MAX_FILE_BYTES,
+        ATTACHMENT_MAX_TOTAL_BYTES,
+    )
+    .inspect_err(|_| {
+        emit_report_send_progress(app, "failed", 0.0, 0, 0);
+    })?;
+
     let formatted_message = format_report_message(app, &input);
 
     let launcher_settings = settings::load_or_init_settings(app).inspect_err(|_| {
@@ -837,6 +1093,10 @@ pub async fn send_report<R: Runtime>(
         Value::String(launcher_settings.game_platform.as_str().to_string()),
     );
 
+    if !attachments.is_empty() {
+        payload.insert("attachments".to_string(), Value::Array(attachments));
+    }
+
     emit_report_send_progress(app, "preparing", 12.0, 0, 0);
 
     if report_type == "Bug" {
@@ -879,6 +1139,22 @@ pub async fn send_report<R: Runtime>(
         payload.insert("mode".to_string(), Value::String("Launcher".to_string()));
         payload.insert("log_compressed".to_string(), Value::String(compressed));
 
+        // Player.logは補助情報のため、取得できなくても報告自体は送信する。
+        if input.include_player_log {
+            match read_player_log()
+                .and_then(|log| log.map(|text| compress_and_encrypt_log(&text)).transpose())
+            {
+                Ok(Some(player_log)) => {
+                    payload.insert(
+                        "player_log_compressed".to_string(),
+                        Value::String(player_log),
+                    );
+                }
+                Ok(None) => log::info!("Unity Player.log was not found; skipping attachment."),
+                Err(error) => log::warn!("Skipping Unity Player.log attachment: {error}"),
+            }
+        }
+
         if let Some(map_value) = input
             .map
             .as_deref()
@@ -1066,15 +1342,244 @@ pub async fn get_notifications<R: Runtime>(
     })
 }
 
+fn health_step(id: &str, started_at: Instant, result: Result<(), String>) -> ReportingHealthStep {
+    ReportingHealthStep {
+        id: id.to_string(),
+        success: result.is_ok(),
+        latency_ms: started_at.elapsed().as_millis() as u64,
+        message: result.err(),
+    }
+}
+
+/// トークン確保から通知取得までを順に実行し、各段階の成否と所要時間を返す。
+pub async fn healthcheck<R: Runtime>(app: &AppHandle<R>) -> Result<ReportingHealthcheck, String> {
+    let client = reporting_client()?;
+    let mut steps = Vec::new();
+
+    // トークン段階は resolve_valid_token と同じ優先順位で検証・作成する。
+    let started_at = Instant::now();
+    let token_result = resolve_valid_token(app, &client, true).await;
+    let (token, token_source, created_account) = match token_result {
+        Ok(resolved) => {
+            steps.push(health_step("token", started_at, Ok(())));
+            resolved
+        }
+        Err(error) => {
+            steps.push(health_step("token", started_at, Err(error)));
+            return Ok(ReportingHealthcheck {
+                healthy: false,
+                token_source: None,
+                created_account: false,
+                steps,
+            });
+        }
+    };
+
+    let started_at = Instant::now();
+    let notification_result = match client
+        .get(format!("{}/getNotification/", reporting_api_base_url()))
+        .header("Authorization", format!("Bearer {token}"))
+        .send()
+        .await
+    {
+        Ok(response) if response.status().is_success() => Ok(()),
+        Ok(response) => Err(format!(
+            "Reporting notification endpoint returned {}",
+            response.status()
+        )),
+        Err(error) => Err(format!("Failed to reach reporting API: {error}")),
+    };
+    steps.push(health_step("notification", started_at, notification_result));
+
+    Ok(ReportingHealthcheck {
+        healthy: steps.iter().all(|step| step.success),
+        token_source: Some(token_source),
+        created_account,
+        steps,
+    })
+}
+
 pub async fn get_notification_flag<R: Runtime>(app: &AppHandle<R>) -> Result<bool, String> {
     Ok(get_notifications(app).await?.notification)
 }
 
+/// スレッドのメッセージ一覧を読みやすいMarkdownの記録へ整形する。
+pub fn format_thread_transcript(thread_id: &str, messages: &[ReportMessage]) -> String {
+    let mut transcript = format!("# Report thread {thread_id}\n\n");
+    for message in messages {
+        let created_at = if message.created_at.trim().is_empty() {
+            "-"
+        } else {
+            message.created_at.trim()
+        };
+        // ステータス変更は通常メッセージと区別して1行で記録する。
+        if message.message_type == "status" {
+            transcript.push_str(&format!(
+                "- **[{created_at}] Status changed:** {}\n\n",
+                message.content.trim()
+            ));
+            continue;
+        }
+
+        let sender = message
+            .sender
+            .as_deref()
+            .map(|value| value.trim_start_matches("github:"))
+            .filter(|value| !value.trim().is_empty())
+            .unwrap_or("unknown");
+        transcript.push_str(&format!("## [{created_at}] {sender}\n\n"));
+        transcript.push_str(message.content.trim_end());
+        transcript.push_str("\n\n");
+    }
+    transcript
+}
+
+/// スレッドの全メッセージを取得し、Markdown形式の記録として書き出す。
+pub async fn export_thread_transcript<R: Runtime>(
+    app: &AppHandle<R>,
+    thread_id: &str,
+    output_path: &str,
+) -> Result<PathBuf, String> {
+    let output_path = output_path.trim();
+    if output_path.is_empty() {
+        return Err("output_path is required".to_string());
+    }
+
+    let messages = get_messages(app, thread_id).await?;
+    let transcript = format_thread_transcript(thread_id.trim(), &messages);
+
+    let path = PathBuf::from(output_path);
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent).map_err(|e| {
+            format!(
+                "Failed to create transcript directory '{}': {e}",
+                parent.display()
+            )
+        })?;
+    }
+    fs::write(&path, transcript)
+        .map_err(|e| format!("Failed to write transcript '{}': {e}", path.display()))?;
+    Ok(path)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{classify_token_validation_status, TokenValidationState};
+    use super::{
+        classify_token_validation_status, compress_and_encrypt_log, decrypt_and_decompress_log,
+        format_thread_transcript, read_report_attachments, summarize_threads, truncate_log_tail,
+        ReportMessage, ReportStatus, ReportThread, TokenValidationState,
+    };
     use reqwest::StatusCode;
 
+    fn make_thread(status: &str, unread: bool) -> ReportThread {
+        ReportThread {
+            thread_id: String::new(),
+            title: String::new(),
+            first_message: String::new(),
+            created_at: String::new(),
+            unread,
+            current_status: ReportStatus {
+                status: status.to_string(),
+                color: "#32CD32".to_string(),
+                mark: "●".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn read_report_attachments_enforces_path_and_size_limits() {
+        let root =
+            std::env::temp_dir().join(format!("snr-report-attachments-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).expect("failed to create temp dir");
+        let small = root.join("crash.dmp");
+        let large = root.join("large.bin");
+        std::fs::write(&small, [1u8, 2, 3]).expect("write small attachment");
+        std::fs::write(&large, [0u8; 16]).expect("write large attachment");
+        let small_path = small.to_string_lossy().to_string();
+        let large_path = large.to_string_lossy().to_string();
+
+        let attachments = read_report_attachments(std::slice::from_ref(&small_path), 8, 8)
+            .expect("small file is accepted");
+        assert_eq!(attachments[0]["name"], "crash.dmp");
+        assert_eq!(attachments[0]["data"], "AQID");
+
+        assert!(read_report_attachments(&["crash.dmp".to_string()], 8, 8).is_err());
+        assert!(read_report_attachments(&[large_path], 8, 64).is_err());
+        assert!(read_report_attachments(&[small_path.clone(), small_path], 8, 5).is_err());
+        assert!(read_report_attachments(&[root.to_string_lossy().to_string()], 8, 8).is_err());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn summarize_threads_groups_by_status_in_first_seen_order() {
+        let summary = summarize_threads(&[
+            make_thread("open", true),
+            make_thread("resolved", false),
+            make_thread("open", false),
+        ]);
+        assert_eq!(summary.total_threads, 3);
+        assert_eq!(summary.unread_threads, 1);
+        let counts = summary
+            .by_status
+            .iter()
+            .map(|entry| (entry.status.status.as_str(), entry.threads, entry.unread))
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![("open", 2, 1), ("resolved", 1, 0)]);
+    }
+
+    #[test]
+    fn truncate_log_tail_keeps_end_on_char_boundary() {
+        assert_eq!(truncate_log_tail("short", 10), "short");
+        assert_eq!(truncate_log_tail("0123456789", 4), "6789");
+        // マルチバイト文字の途中では切らない。
+        assert_eq!(truncate_log_tail("あいう", 4), "う");
+    }
+
+    #[test]
+    fn thread_transcript_marks_status_changes() {
+        let message = |message_type: &str, content: &str, sender: Option<&str>| ReportMessage {
+            message_type: message_type.to_string(),
+            message_id: "m".to_string(),
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            content: content.to_string(),
+            sender: sender.map(str::to_string),
+            color: None,
+            mark: None,
+        };
+        let transcript = format_thread_transcript(
+            "thread-1",
+            &[
+                message("normal", "起動できません", Some("github:user")),
+                message("status", "対応中", None),
+            ],
+        );
+
+        assert!(transcript.starts_with("# Report thread thread-1"));
+        assert!(transcript.contains("## [2026-01-01T00:00:00Z] user\n\n起動できません"));
+        assert!(transcript.contains("**[2026-01-01T00:00:00Z] Status changed:** 対応中"));
+    }
+
+    #[test]
+    fn encrypted_log_round_trips() {
+        let log_text = "[Info   :BepInEx] Loading [SuperNewRoles]\n日本語のログ行\n";
+        let encoded = compress_and_encrypt_log(log_text).expect("log should encrypt");
+        assert_ne!(encoded, log_text);
+
+        let decoded = decrypt_and_decompress_log(&encoded).expect("log should decrypt");
+        assert_eq!(decoded, log_text);
+    }
+
+    #[test]
+    fn empty_encrypted_log_decodes_to_empty_text() {
+        assert_eq!(compress_and_encrypt_log("").as_deref(), Ok(""));
+        assert_eq!(decrypt_and_decompress_log("").as_deref(), Ok(""));
+    }
+
     #[test]
     fn token_validation_success_means_valid() {
         assert_eq!(
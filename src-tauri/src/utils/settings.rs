@@ -6,9 +6,10 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use tauri::{AppHandle, Manager, Runtime};
 
-use crate::utils::mod_profile;
+use crate::utils::{bootstrap, logger, mod_profile};
 
 const SETTINGS_FILE_NAME: &str = "settings.json";
+const PROFILE_SETTINGS_OVERRIDES_FILE_NAME: &str = "launcher-settings.override.json";
 
 fn required_profile_files() -> &'static [String] {
     // プロファイル必須ファイル定義はmod設定から取得する。
@@ -40,6 +41,16 @@ impl GamePlatform {
     }
 }
 
+/// バックグラウンド通知に含める内容の詳細度。
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum NotificationDetailLevel {
+    #[default]
+    Full,
+    TitleOnly,
+    CountOnly,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LauncherSettings {
@@ -54,6 +65,19 @@ pub struct LauncherSettings {
     pub announce_notifications_enabled: bool,
     pub ui_locale: String,
     pub onboarding_completed: bool,
+    pub migration_encrypt_by_default: bool,
+    pub migration_default_output_dir: String,
+    pub log_level: String,
+    pub notification_detail_level: NotificationDetailLevel,
+    /// ダウンロードキャッシュの上限(MB)。0 は無制限。
+    pub cache_max_size_mb: u64,
+    pub start_minimized: bool,
+    /// 更新・再インストール時にプロファイルの BepInEx/config を引き継ぐか。
+    pub preserve_bepinex_config: bool,
+    /// トレイメニューをカーソル移動で閉じず、項目かトレイアイコンの操作まで表示し続けるか。
+    pub tray_menu_pinned: bool,
+    /// ウィンドウを閉じたときに、トレイへ最小化するか終了するかを確認するか。
+    pub close_action_prompt: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -70,6 +94,15 @@ struct LauncherSettingsOnDisk {
     announce_notifications_enabled: Option<bool>,
     ui_locale: Option<String>,
     onboarding_completed: Option<bool>,
+    migration_encrypt_by_default: Option<bool>,
+    migration_default_output_dir: Option<String>,
+    log_level: Option<String>,
+    notification_detail_level: Option<NotificationDetailLevel>,
+    cache_max_size_mb: Option<u64>,
+    start_minimized: Option<bool>,
+    preserve_bepinex_config: Option<bool>,
+    tray_menu_pinned: Option<bool>,
+    close_action_prompt: Option<bool>,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -86,14 +119,81 @@ pub struct LauncherSettingsInput {
     pub announce_notifications_enabled: Option<bool>,
     pub ui_locale: Option<String>,
     pub onboarding_completed: Option<bool>,
+    pub migration_encrypt_by_default: Option<bool>,
+    pub migration_default_output_dir: Option<String>,
+    pub log_level: Option<String>,
+    pub notification_detail_level: Option<NotificationDetailLevel>,
+    pub cache_max_size_mb: Option<u64>,
+    pub start_minimized: Option<bool>,
+    pub preserve_bepinex_config: Option<bool>,
+    pub tray_menu_pinned: Option<bool>,
+    pub close_action_prompt: Option<bool>,
+}
+
+/// プロファイル単位でグローバル設定を上書きする項目。未指定項目はグローバル値を使う。
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileSettingsOverrides {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub among_us_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub game_platform: Option<GamePlatform>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_game_server_id: Option<String>,
+    // 以下は起動時の Doorstop 引数の上書き。相対パスはプロファイル基準で解決する。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doorstop_target_assembly: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doorstop_corlib_dir: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doorstop_coreclr_path: Option<String>,
+}
+
+impl ProfileSettingsOverrides {
+    fn normalized(self) -> Self {
+        // 空文字は「上書きなし」と同義に扱う。
+        let non_empty = |value: Option<String>| {
+            value
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        Self {
+            among_us_path: non_empty(self.among_us_path),
+            game_platform: self.game_platform,
+            selected_game_server_id: non_empty(self.selected_game_server_id),
+            doorstop_target_assembly: non_empty(self.doorstop_target_assembly),
+            doorstop_corlib_dir: non_empty(self.doorstop_corlib_dir),
+            doorstop_coreclr_path: non_empty(self.doorstop_coreclr_path),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.among_us_path.is_none()
+            && self.game_platform.is_none()
+            && self.selected_game_server_id.is_none()
+            && self.doorstop_target_assembly.is_none()
+            && self.doorstop_corlib_dir.is_none()
+            && self.doorstop_coreclr_path.is_none()
+    }
+}
+
+/// UIが翻訳を持つロケール。先頭を既定値とする。
+pub const SUPPORTED_UI_LOCALES: &[&str] = &["ja", "en"];
+/// 設定変更を全ウィンドウへ知らせるイベント。
+pub const SETTINGS_CHANGED_EVENT: &str = "settings-changed";
+
+/// 対応ロケールに一致すればその値を返す。大文字小文字と前後の空白は無視する。
+pub fn supported_ui_locale(value: &str) -> Option<&'static str> {
+    let value = value.trim();
+    SUPPORTED_UI_LOCALES
+        .iter()
+        .copied()
+        .find(|locale| locale.eq_ignore_ascii_case(value))
 }
 
 fn normalize_ui_locale(value: &str) -> &'static str {
     // 想定外の値は既定の日本語ロケールへ寄せる。
-    match value.trim().to_ascii_lowercase().as_str() {
-        "en" => "en",
-        _ => "ja",
-    }
+    supported_ui_locale(value).unwrap_or(SUPPORTED_UI_LOCALES[0])
 }
 
 fn default_selected_game_server_id() -> String {
@@ -130,13 +230,20 @@ pub fn app_data_dir<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
 
 /// デフォルトのSNRプロファイル保存先を返す。
 pub fn default_profile_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
-    Ok(app_data_dir(app)?.join("profiles").join("default"))
+    Ok(app_data_dir(app)?
+        .join(bootstrap::PROFILES_DIR_NAME)
+        .join("default"))
 }
 
 fn settings_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
     Ok(app_data_dir(app)?.join(SETTINGS_FILE_NAME))
 }
 
+/// 設定ファイルが保存済みかを返す。初回起動の判定に使う。
+pub fn settings_file_exists<R: Runtime>(app: &AppHandle<R>) -> Result<bool, String> {
+    Ok(settings_path(app)?.is_file())
+}
+
 fn make_default_settings<R: Runtime>(app: &AppHandle<R>) -> Result<LauncherSettings, String> {
     let profile_path = default_profile_path(app)?;
     Ok(LauncherSettings {
@@ -151,6 +258,15 @@ fn make_default_settings<R: Runtime>(app: &AppHandle<R>) -> Result<LauncherSetti
         announce_notifications_enabled: true,
         ui_locale: "ja".to_string(),
         onboarding_completed: false,
+        migration_encrypt_by_default: false,
+        migration_default_output_dir: String::new(),
+        log_level: logger::DEFAULT_LOG_LEVEL.to_string(),
+        notification_detail_level: NotificationDetailLevel::Full,
+        cache_max_size_mb: 0,
+        start_minimized: false,
+        preserve_bepinex_config: false,
+        tray_menu_pinned: false,
+        close_action_prompt: true,
     })
 }
 
@@ -162,6 +278,9 @@ fn normalize_settings(mut settings: LauncherSettings) -> LauncherSettings {
         normalize_selected_game_server_id(&settings.selected_game_server_id);
     settings.profile_path = settings.profile_path.trim().to_string();
     settings.ui_locale = normalize_ui_locale(&settings.ui_locale).to_string();
+    settings.migration_default_output_dir =
+        settings.migration_default_output_dir.trim().to_string();
+    settings.log_level = logger::normalize_log_level(&settings.log_level).to_string();
     settings
 }
 
@@ -219,11 +338,29 @@ pub fn load_settings_or_default<R: Runtime>(
         }
     }
     default_settings.onboarding_completed = on_disk.onboarding_completed.unwrap_or(false);
+    default_settings.migration_encrypt_by_default =
+        on_disk.migration_encrypt_by_default.unwrap_or(false);
+    default_settings.migration_default_output_dir =
+        on_disk.migration_default_output_dir.unwrap_or_default();
+    if let Some(log_level) = on_disk.log_level {
+        default_settings.log_level = log_level;
+    }
+    default_settings.notification_detail_level =
+        on_disk.notification_detail_level.unwrap_or_default();
+    default_settings.cache_max_size_mb = on_disk.cache_max_size_mb.unwrap_or(0);
+    default_settings.start_minimized = on_disk.start_minimized.unwrap_or(false);
+    default_settings.preserve_bepinex_config = on_disk.preserve_bepinex_config.unwrap_or(false);
+    default_settings.tray_menu_pinned = on_disk.tray_menu_pinned.unwrap_or(false);
+    // 項目追加前の設定ファイルでは、閉じたときに確認せず従来どおり設定に従う。
+    default_settings.close_action_prompt = on_disk.close_action_prompt.unwrap_or(false);
 
     Ok(normalize_settings(default_settings))
 }
 
-pub fn load_or_init_settings<R: Runtime>(app: &AppHandle<R>) -> Result<LauncherSettings, String> {
+/// グローバル設定のみを読み込む。読み込んだ値を保存し直す処理はこちらを使う。
+pub fn load_or_init_global_settings<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<LauncherSettings, String> {
     let path = settings_path(app)?;
 
     // 初回起動では既定値を即保存し、以降の処理を同一フローにそろえる。
@@ -239,11 +376,101 @@ pub fn load_or_init_settings<R: Runtime>(app: &AppHandle<R>) -> Result<LauncherS
     Ok(settings)
 }
 
+/// グローバル設定に、選択中プロファイルの上書き設定を重ねた実効設定を返す。
+pub fn load_or_init_settings<R: Runtime>(app: &AppHandle<R>) -> Result<LauncherSettings, String> {
+    Ok(with_profile_settings_overrides(
+        load_or_init_global_settings(app)?,
+    ))
+}
+
+fn with_profile_settings_overrides(settings: LauncherSettings) -> LauncherSettings {
+    match load_profile_settings_overrides(Path::new(&settings.profile_path)) {
+        Some(overrides) => apply_profile_settings_overrides(settings, overrides),
+        None => settings,
+    }
+}
+
+fn profile_settings_overrides_path(profile_path: &Path) -> PathBuf {
+    profile_path.join(PROFILE_SETTINGS_OVERRIDES_FILE_NAME)
+}
+
+fn apply_profile_settings_overrides(
+    mut settings: LauncherSettings,
+    overrides: ProfileSettingsOverrides,
+) -> LauncherSettings {
+    if let Some(among_us_path) = overrides.among_us_path {
+        settings.among_us_path = among_us_path;
+    }
+    if let Some(game_platform) = overrides.game_platform {
+        settings.game_platform = game_platform;
+    }
+    if let Some(selected_game_server_id) = overrides.selected_game_server_id {
+        settings.selected_game_server_id = selected_game_server_id;
+    }
+    normalize_settings(settings)
+}
+
+/// プロファイル内の上書き設定を読み込む。未作成・破損時は None を返す。
+pub fn load_profile_settings_overrides(profile_path: &Path) -> Option<ProfileSettingsOverrides> {
+    let content = fs::read_to_string(profile_settings_overrides_path(profile_path)).ok()?;
+    let overrides = serde_json::from_str::<ProfileSettingsOverrides>(&content)
+        .ok()?
+        .normalized();
+    (!overrides.is_empty()).then_some(overrides)
+}
+
+/// プロファイル内の上書き設定を保存する。全項目未指定ならファイルを削除する。
+pub fn save_profile_settings_overrides(
+    profile_path: &Path,
+    overrides: ProfileSettingsOverrides,
+) -> Result<Option<ProfileSettingsOverrides>, String> {
+    if !profile_path.is_dir() {
+        return Err(format!(
+            "Profile directory was not found: {}",
+            profile_path.display()
+        ));
+    }
+
+    let overrides = overrides.normalized();
+    let path = profile_settings_overrides_path(profile_path);
+    if overrides.is_empty() {
+        if path.exists() {
+            fs::remove_file(&path)
+                .map_err(|e| format!("Failed to remove profile settings overrides: {e}"))?;
+        }
+        return Ok(None);
+    }
+
+    let json = serde_json::to_string_pretty(&overrides).map_err(|e| e.to_string())?;
+    fs::write(&path, json)
+        .map_err(|e| format!("Failed to write profile settings overrides: {e}"))?;
+    Ok(Some(overrides))
+}
+
+/// プロファイルの上書き設定がプラットフォームを指定している場合だけ、その値を更新する。
+/// 上書きが無ければ何もせず false を返すため、呼び出し側でグローバル設定へ保存する。
+pub fn update_profile_platform_override(
+    profile_path: &Path,
+    platform: &GamePlatform,
+) -> Result<bool, String> {
+    let Some(mut overrides) = load_profile_settings_overrides(profile_path) else {
+        return Ok(false);
+    };
+    if overrides.game_platform.is_none() {
+        return Ok(false);
+    }
+    overrides.game_platform = Some(platform.clone());
+    save_profile_settings_overrides(profile_path, overrides)?;
+    Ok(true)
+}
+
+/// 入力をグローバル設定へ反映して保存し、保存したグローバル設定を返す。
+/// 取得・更新系コマンドで同じ形を返すため、プロファイルの上書きは合成しない。
 pub fn apply_settings_input<R: Runtime>(
     app: &AppHandle<R>,
     input: LauncherSettingsInput,
 ) -> Result<LauncherSettings, String> {
-    let mut settings = load_or_init_settings(app)?;
+    let mut settings = load_or_init_global_settings(app)?;
 
     if let Some(among_us_path) = input.among_us_path {
         settings.among_us_path = among_us_path;
@@ -278,6 +505,33 @@ pub fn apply_settings_input<R: Runtime>(
     if let Some(onboarding_completed) = input.onboarding_completed {
         settings.onboarding_completed = onboarding_completed;
     }
+    if let Some(migration_encrypt_by_default) = input.migration_encrypt_by_default {
+        settings.migration_encrypt_by_default = migration_encrypt_by_default;
+    }
+    if let Some(migration_default_output_dir) = input.migration_default_output_dir {
+        settings.migration_default_output_dir = migration_default_output_dir;
+    }
+    if let Some(log_level) = input.log_level {
+        settings.log_level = log_level;
+    }
+    if let Some(notification_detail_level) = input.notification_detail_level {
+        settings.notification_detail_level = notification_detail_level;
+    }
+    if let Some(cache_max_size_mb) = input.cache_max_size_mb {
+        settings.cache_max_size_mb = cache_max_size_mb;
+    }
+    if let Some(start_minimized) = input.start_minimized {
+        settings.start_minimized = start_minimized;
+    }
+    if let Some(preserve_bepinex_config) = input.preserve_bepinex_config {
+        settings.preserve_bepinex_config = preserve_bepinex_config;
+    }
+    if let Some(tray_menu_pinned) = input.tray_menu_pinned {
+        settings.tray_menu_pinned = tray_menu_pinned;
+    }
+    if let Some(close_action_prompt) = input.close_action_prompt {
+        settings.close_action_prompt = close_action_prompt;
+    }
 
     // 空文字で上書きされた場合でも、最低限の保存先は維持する。
     if settings.profile_path.trim().is_empty() {
@@ -287,6 +541,7 @@ pub fn apply_settings_input<R: Runtime>(
     // 外部入力を都度正規化してから保存し、不正な空白やlocale値を残さない。
     settings = normalize_settings(settings);
     save_settings(app, &settings)?;
+    logger::set_level(&settings.log_level);
     Ok(settings)
 }
 
@@ -297,6 +552,22 @@ pub fn is_profile_ready(profile_path: &Path) -> bool {
         .all(|relative_path| profile_path.join(relative_path).is_file())
 }
 
+/// mod設定で定義された必須ファイルのパスをすべて返す。
+pub fn profile_required_file_paths(profile_path: &Path) -> Vec<PathBuf> {
+    required_profile_files()
+        .iter()
+        .map(|relative_path| profile_path.join(relative_path))
+        .collect()
+}
+
+/// 不足している必須ファイルのパスをすべて返す。
+pub fn missing_profile_required_files(profile_path: &Path) -> Vec<PathBuf> {
+    profile_required_file_paths(profile_path)
+        .into_iter()
+        .filter(|file_path| !file_path.is_file())
+        .collect()
+}
+
 /// 必須ファイルの不足内容を詳細メッセージ付きで検証する。
 pub fn verify_profile_required_files(profile_path: &Path) -> Result<(), String> {
     for relative_path in required_profile_files() {
@@ -311,3 +582,55 @@ pub fn verify_profile_required_files(profile_path: &Path) -> Result<(), String>
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn make_temp_dir(label: &str) -> PathBuf {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        std::env::temp_dir().join(format!(
+            "snr-settings-{label}-{}-{millis}",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn platform_update_writes_active_profile_override() {
+        let profile = make_temp_dir("platform-override");
+        let _ = fs::remove_dir_all(&profile);
+        fs::create_dir_all(&profile).expect("failed to create temp dir");
+
+        // 上書きが無い場合はグローバル設定側で保存させる。
+        assert!(!update_profile_platform_override(&profile, &GamePlatform::Epic).unwrap());
+
+        save_profile_settings_overrides(
+            &profile,
+            ProfileSettingsOverrides {
+                game_platform: Some(GamePlatform::Epic),
+                ..ProfileSettingsOverrides::default()
+            },
+        )
+        .expect("save overrides");
+        assert!(update_profile_platform_override(&profile, &GamePlatform::Steam).unwrap());
+        let overrides = load_profile_settings_overrides(&profile).expect("overrides remain");
+        assert_eq!(overrides.game_platform, Some(GamePlatform::Steam));
+
+        // プラットフォームを指定しない上書きは対象外にする。
+        save_profile_settings_overrides(
+            &profile,
+            ProfileSettingsOverrides {
+                among_us_path: Some("C:/Games/AmongUs".to_string()),
+                ..ProfileSettingsOverrides::default()
+            },
+        )
+        .expect("save overrides");
+        assert!(!update_profile_platform_override(&profile, &GamePlatform::Epic).unwrap());
+
+        let _ = fs::remove_dir_all(&profile);
+    }
+}
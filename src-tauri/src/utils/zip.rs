@@ -3,10 +3,12 @@ use std::collections::HashSet;
 use std::fs::{self, File};
 use std::io::{BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
 const ZIP_COPY_BUFFER_SIZE: usize = 256 * 1024;
 const EXTRACT_PROGRESS_MIN_INTERVAL: Duration = Duration::from_millis(120);
+const EXTRACT_CANCELLED_ERROR: &str = "Extraction was cancelled.";
 
 fn copy_with_reused_buffer<R: Read, W: Write>(
     reader: &mut R,
@@ -29,7 +31,80 @@ fn copy_with_reused_buffer<R: Read, W: Write>(
     Ok(written)
 }
 
-pub fn extract_zip<F>(zip_path: &Path, destination: &Path, mut on_progress: F) -> Result<(), String>
+/// 展開せずに中央ディレクトリと各エントリのヘッダを読み、zipの構造が壊れていないか確認する。
+/// 成功時はエントリ数を返す。
+pub fn validate_zip(zip_path: &Path) -> Result<usize, String> {
+    let file = File::open(zip_path).map_err(|e| format!("Failed to open zip archive: {e}"))?;
+    let mut archive =
+        zip::ZipArchive::new(file).map_err(|e| format!("Invalid zip archive format: {e}"))?;
+    if archive.is_empty() {
+        return Err("Zip archive contains no entries".to_string());
+    }
+
+    for i in 0..archive.len() {
+        // raw参照はローカルヘッダまでの検証に留まり、伸長処理は行わない。
+        archive
+            .by_index_raw(i)
+            .map_err(|e| format!("Failed to read zip entry {i}: {e}"))?;
+    }
+    Ok(archive.len())
+}
+
+/// zip内のファイルエントリの概要。パスは `/` 区切りで正規化する。
+#[derive(Debug, Clone)]
+pub struct ZipFileEntry {
+    pub path: String,
+    pub size: u64,
+    pub crc32: u32,
+}
+
+/// 伸長せずに中央ディレクトリからファイルエントリの一覧を読み取る。
+pub fn list_zip_files(zip_path: &Path) -> Result<Vec<ZipFileEntry>, String> {
+    let file = File::open(zip_path).map_err(|e| format!("Failed to open zip archive: {e}"))?;
+    let mut archive =
+        zip::ZipArchive::new(file).map_err(|e| format!("Invalid zip archive format: {e}"))?;
+
+    let mut entries = Vec::new();
+    for i in 0..archive.len() {
+        let entry = archive
+            .by_index_raw(i)
+            .map_err(|e| format!("Failed to read zip entry {i}: {e}"))?;
+        if entry.is_dir() {
+            continue;
+        }
+        // 展開時と同じくenclosed_nameで検証し、展開されないエントリは一覧にも含めない。
+        let Some(enclosed) = entry.enclosed_name() else {
+            continue;
+        };
+        let path = enclosed
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        entries.push(ZipFileEntry {
+            path,
+            size: entry.size(),
+            crc32: entry.crc32(),
+        });
+    }
+    Ok(entries)
+}
+
+pub fn extract_zip<F>(zip_path: &Path, destination: &Path, on_progress: F) -> Result<(), String>
+where
+    F: FnMut(usize, usize),
+{
+    extract_zip_cancellable(zip_path, destination, None, on_progress)
+}
+
+/// `extract_zip` と同じだが、エントリごとに中断要求を確認する。
+/// 展開済みのファイルは残すため、呼び出し側で展開先を片付けること。
+pub fn extract_zip_cancellable<F>(
+    zip_path: &Path,
+    destination: &Path,
+    cancel_requested: Option<&AtomicBool>,
+    mut on_progress: F,
+) -> Result<(), String>
 where
     F: FnMut(usize, usize),
 {
@@ -52,6 +127,9 @@ where
     on_progress(0, total);
 
     for i in 0..total {
+        if cancel_requested.is_some_and(|flag| flag.load(Ordering::SeqCst)) {
+            return Err(EXTRACT_CANCELLED_ERROR.to_string());
+        }
         let mut entry = archive
             .by_index(i)
             .map_err(|e| format!("Failed to read zip entry {i}: {e}"))?;
@@ -2,7 +2,7 @@ import { getVersion } from "@tauri-apps/api/app";
 import { listen } from "@tauri-apps/api/event";
 import { downloadDir, join } from "@tauri-apps/api/path";
 import { getCurrentWindow } from "@tauri-apps/api/window";
-import { open, save } from "@tauri-apps/plugin-dialog";
+import { ask, open, save } from "@tauri-apps/plugin-dialog";
 import { openUrl } from "@tauri-apps/plugin-opener";
 import { check } from "@tauri-apps/plugin-updater";
 import React from "react";
@@ -55,8 +55,8 @@ import {
   epicStatusGet,
   finderDetectPlatform,
   finderDetectPlatforms,
+  gameStateGet,
   launchAutolaunchErrorTake,
-  launchGameRunningGet,
   launchModded,
   launchModdedElevated,
   launchModdedFirstSetupPending,
@@ -64,15 +64,19 @@ import {
   launchSteamRunningGet,
   launchVanilla,
   launchVanillaElevated,
+  localeSet,
   migrationExport,
   migrationImport,
   modPreservedSaveDataStatus,
   modUninstall,
   notificationsTakeOpenTarget,
+  operationStatus,
+  pathDriftCheck,
   presetsExport,
   presetsImportArchive,
   presetsInspectArchive,
   presetsListLocal,
+  presetsVerifyArchive,
   reportingLogSourceGet,
   reportingMessageSend,
   reportingMessagesList,
@@ -103,6 +107,7 @@ import type {
   PresetImportResult,
   PresetImportSelectionInput,
   PresetSummary,
+  ProfileHealth,
   ReportMessage,
   ReportThread,
   ReportType,
@@ -133,6 +138,7 @@ const LOCALE_SWITCH_RELOAD_ANIMATION_FLAG_KEY = "ui.localeSwitchReloadAnimation"
 const INSTALL_FLOW_HOME_AFTER_RELOAD_FLAG_KEY = "ui.installFlowHomeAfterReload";
 const LAST_MAIN_TAB_STORAGE_KEY = "ui.lastMainTab";
 const BACKGROUND_NOTIFICATION_OPEN_EVENT = "background-notification-open";
+const PROFILE_MISSING_EVENT = "profile-missing";
 const ONBOARDING_SPOTLIGHT_CLASS = "onboarding-spotlight-target";
 const ONBOARDING_SPOTLIGHT_FOCUS_CLASS = "onboarding-spotlight-target-focus";
 const ONBOARDING_EXIT_ANIMATION_MS = 340;
@@ -1666,6 +1672,35 @@ export async function runLauncher(container?: HTMLElement | null): Promise<void>
     return !EPIC_LOGIN_ENABLED && selectedSettings?.gamePlatform === "epic";
   }
 
+  async function promptPathDriftUpdate(): Promise<void> {
+    // Steamライブラリの移動や再インストールで保存済みパスが無効になった場合だけ確認する。
+    try {
+      const drift = await pathDriftCheck();
+      if (!drift.stale || !drift.detected) {
+        return;
+      }
+      const platform = drift.detected.platform as GamePlatform;
+      if (!isPlatformSelectable(platform, EPIC_LOGIN_ENABLED)) {
+        return;
+      }
+      const accepted = await ask(
+        t("launch.pathDriftConfirm", { saved: drift.savedPath, detected: drift.detected.path }),
+        { title: t("launch.pathDriftTitle"), kind: "warning" },
+      );
+      if (!accepted) {
+        return;
+      }
+      await saveSettings({ amongUsPath: drift.detected.path, gamePlatform: platform });
+      await refreshProfileReady();
+      setGeneralStatusLine(
+        t("detect.success", { path: drift.detected.path, platform }),
+        "success",
+      );
+    } catch {
+      // ignore drift check failures; launch preflight reports invalid paths.
+    }
+  }
+
   async function warnIfSteamIsNotRunningOnStartup(loadedSettings: LauncherSettings): Promise<void> {
     await ensureSteamRunningOrWarn(loadedSettings);
   }
@@ -1799,7 +1834,8 @@ export async function runLauncher(container?: HTMLElement | null): Promise<void>
 
   async function refreshGameRunningState(): Promise<void> {
     try {
-      const running = await launchGameRunningGet();
+      // Webview 再生成後もイベント待ちにせず、バックエンドの追跡状態から表示を合わせる。
+      const { running } = await gameStateGet();
       applyGameRunningState(running);
     } catch {
       // ignore game running state retrieval errors
@@ -3426,6 +3462,11 @@ export async function runLauncher(container?: HTMLElement | null): Promise<void>
     try {
       archivePresets = await presetsInspectArchive(archivePath);
       renderArchivePresetList();
+      // 改ざん検知は警告に留め、取り込み自体は利用者の判断に任せる。
+      const integrity = await presetsVerifyArchive(archivePath).catch(() => "unsigned");
+      if (integrity === "modified") {
+        showPresetResultOverlay(t("preset.archiveModifiedWarning"), t("preset.title"), [], false);
+      }
       return true;
     } catch (error) {
       archivePresets = [];
@@ -3779,8 +3820,8 @@ export async function runLauncher(container?: HTMLElement | null): Promise<void>
     try {
       saveLocale(nextLocale);
 
+      await localeSet(nextLocale);
       await settingsUpdate({
-        uiLocale: nextLocale,
         selectedGameServerId: resolveLocalePreferredGameServerId(nextLocale),
       });
     } catch {
@@ -3986,9 +4027,7 @@ export async function runLauncher(container?: HTMLElement | null): Promise<void>
     void notificationsTakeOpenTarget().catch(() => undefined);
   });
 
-  void listen<InstallProgressPayload>(installProgressEventName, (event) => {
-    const payload = event.payload;
-
+  const applyInstallProgress = (payload: InstallProgressPayload) => {
     if (
       (payload.stage === "downloading" || payload.stage === "patchers") &&
       typeof payload.downloaded === "number"
@@ -4023,6 +4062,27 @@ export async function runLauncher(container?: HTMLElement | null): Promise<void>
     }
 
     installStatus.textContent = payload.message;
+  };
+
+  void listen<InstallProgressPayload>(installProgressEventName, (event) => {
+    applyInstallProgress(event.payload);
+  });
+
+  // Webviewが再生成された場合でも、実行中インストールの直近進捗から表示を復元する。
+  void operationStatus()
+    .then((operations) => {
+      const install = operations.find((operation) => operation.kind === "install");
+      if (install?.progress) {
+        applyInstallProgress(install.progress as InstallProgressPayload);
+      }
+    })
+    .catch(() => undefined);
+
+  // 起動やプリセット操作でプロファイル消失が検出されたら、個別エラーではなく再導入を促す。
+  void listen<ProfileHealth>(PROFILE_MISSING_EVENT, (event) => {
+    profileIsReady = false;
+    updateButtons();
+    installStatus.textContent = t("install.profileMissing", { path: event.payload.profilePath });
   });
 
   void listen<GameStatePayload>("game-state-changed", (event) => {
@@ -4071,6 +4131,9 @@ export async function runLauncher(container?: HTMLElement | null): Promise<void>
     }
     void warnIfSteamIsNotRunningOnStartup(loadedSettings);
     await refreshProfileReady();
+    if (loadedSettings.onboardingCompleted) {
+      await promptPathDriftUpdate();
+    }
 
     await refreshLocalPresets(true);
     await refreshPreservedSaveDataStatus();
@@ -30,6 +30,7 @@ export interface ModConfig {
     trayTooltip: string;
     identifier: string;
     moddedShortcutName: string;
+    notificationIcon?: string;
   };
   features: {
     announce: boolean;
@@ -66,6 +67,7 @@ export interface ModConfig {
     extension: string;
     magic: string;
     profileIncludePatterns: string[];
+    localLowAdditionalPrefixes?: string[];
   };
   presets: {
     extension: string;
@@ -1,31 +1,79 @@
 import { invoke } from "@tauri-apps/api/core";
+import type { ModConfig } from "../modConfig";
 import type {
+  ActiveOperation,
+  AppBootstrapSummary,
+  AvStatus,
+  BepInExConfigPreservationStatus,
+  EpicLaunchPrecheckResult,
   EpicLoginStatus,
   GamePlatform,
+  GameProcessInfo,
+  GameReadyResult,
+  GitHubRateLimitStatus,
+  InstallAndLaunchResult,
+  GameServerRoomCheck,
   GameServersJoinDirectResult,
+  GameStateSnapshot,
   InstallResult,
+  InstalledProfileSummary,
+  InteropGenerationStatus,
+  LaunchPreflightReport,
   LauncherSettings,
   LauncherSettingsInput,
+  LegacyProfileDetection,
+  LegacyProfileMigrationResult,
+  LocalLowWritableCheck,
+  ManagedArtifact,
+  ManagedArtifactsCleanupResult,
+  MigrationArchiveInfoResult,
+  MigrationDialogImportResult,
+  MigrationEstimateResult,
+  MigrationEstimateScope,
   MigrationExportResult,
   MigrationImportResult,
   MigrationPasswordValidationResult,
+  ModUpdateDiff,
   NotificationOpenTarget,
+  OptionsBackupResult,
+  OptionsRepairResult,
+  OrphanCleanupResult,
+  PatcherResyncResult,
+  PreservedSaveDataDetails,
   PreservedSaveDataStatus,
+  PresetArchiveIntegrity,
+  PresetDialogExportResult,
+  PresetDialogImportResult,
   PresetExportResult,
   PresetImportResult,
   PresetImportSelectionInput,
+  PresetImportStrategy,
   PresetSummary,
+  ProfileHealth,
+  ProfileSettingsOverrides,
+  ProfileValidationReport,
+  ReleaseDownloadResult,
+  RepatchCheck,
   ReportMessage,
+  ReportStatusSummary,
   ReportThread,
+  ReportingHealthcheck,
   ReportingLogSourceInfo,
   ReportingPrepareResult,
   ReportingSendResult,
+  RetainedMigrationBackupResult,
+  SaveDataCompatReport,
   SaveDataImportResult,
+  SaveDataPaths,
   SaveDataPresetMergeResult,
   SaveDataPreviewResult,
   SendReportInput,
+  ShortcutCleanupResult,
   SnrReleaseSummary,
+  StartupAutolaunchStatus,
+  UninstallPreview,
   UninstallResult,
+  UnreadCounts,
 } from "../types";
 
 /**
@@ -34,6 +82,11 @@ import type {
  */
 
 // 設定関連API
+export function appBootstrap(): Promise<AppBootstrapSummary> {
+  // 初回起動準備の結果を取得する。再実行しても状態は変わらない。
+  return invoke<AppBootstrapSummary>("app_bootstrap");
+}
+
 export function settingsGet(): Promise<LauncherSettings> {
   // 現在保存されているランチャー設定を取得する。
   return invoke<LauncherSettings>("settings_get");
@@ -44,6 +97,37 @@ export function settingsUpdate(settings: LauncherSettingsInput): Promise<Launche
   return invoke<LauncherSettings>("settings_update", { settings });
 }
 
+export interface EnvReport {
+  launcherVersion: string;
+  os: string;
+  arch: string;
+  selectedPlatform: GamePlatform;
+  // パスは <appdata> / ~ からの相対表記に伏せられている。
+  amongUsPath: string;
+  amongUsPathExists: boolean;
+  detectedPlatforms: { path: string; platform: string }[];
+  profilePath: string;
+  profileExists: boolean;
+  profileReady: boolean;
+  installedTag: string | null;
+  interopExists: boolean;
+  epicLoggedIn: boolean | null;
+  features: ModConfig["features"];
+}
+
+export function envReport(): Promise<EnvReport> {
+  return invoke<EnvReport>("env_report");
+}
+
+export function localesSupported(): Promise<string[]> {
+  return invoke<string[]>("locales_supported");
+}
+
+export function localeSet(code: string): Promise<LauncherSettings> {
+  // 保存後に settings-changed が全ウィンドウへ通知される。
+  return invoke<LauncherSettings>("locale_set", { code });
+}
+
 export function settingsProfileReady(profilePath?: string): Promise<boolean> {
   return invoke<boolean>("settings_profile_ready", { profilePath });
 }
@@ -52,6 +136,73 @@ export function settingsOpenFolder(path: string): Promise<void> {
   return invoke<void>("settings_open_folder", { path });
 }
 
+export function openGameFolder(): Promise<void> {
+  return invoke<void>("open_game_folder");
+}
+
+export function openProfileBepInExFolder(): Promise<void> {
+  return invoke<void>("open_profile_bepinex_folder");
+}
+
+export function platformGet(): Promise<GamePlatform> {
+  return invoke<GamePlatform>("platform_get");
+}
+
+export function platformSet(platform: GamePlatform): Promise<LauncherSettings> {
+  // 検出結果やEpicログイン状態と矛盾する選択はエラーとして返る。
+  return invoke<LauncherSettings>("platform_set", { platform });
+}
+
+export function settingsProfileOverridesGet(
+  profilePath?: string,
+): Promise<ProfileSettingsOverrides | null> {
+  return invoke<ProfileSettingsOverrides | null>("settings_profile_overrides_get", {
+    profilePath,
+  });
+}
+
+export function settingsProfileOverridesSet(
+  overrides: ProfileSettingsOverrides,
+  profilePath?: string,
+): Promise<ProfileSettingsOverrides | null> {
+  return invoke<ProfileSettingsOverrides | null>("settings_profile_overrides_set", {
+    profilePath,
+    overrides,
+  });
+}
+
+// プロファイル関連API
+export function profilesList(parentDir: string): Promise<InstalledProfileSummary[]> {
+  return invoke<InstalledProfileSummary[]>("profiles_list", { parentDir });
+}
+
+export function profileHealth(): Promise<ProfileHealth> {
+  return invoke<ProfileHealth>("profile_health");
+}
+
+export function savedataPaths(): Promise<SaveDataPaths> {
+  return invoke<SaveDataPaths>("savedata_paths");
+}
+
+export function profileRename(newDirName: string): Promise<string> {
+  // 選択中プロファイルの改名後パスを返す。
+  return invoke<string>("profile_rename", { newDirName });
+}
+
+export function profileLegacyDetect(): Promise<LegacyProfileDetection> {
+  return invoke<LegacyProfileDetection>("profile_legacy_detect");
+}
+
+export function profileLegacyMigrate(sourcePath: string): Promise<LegacyProfileMigrationResult> {
+  // 既存プロファイルがある場合はSaveDataだけを移し、既存のSaveDataは上書きしない。
+  return invoke<LegacyProfileMigrationResult>("profile_legacy_migrate", { sourcePath });
+}
+
+export function fileHash(path: string): Promise<string> {
+  // プロファイル/ゲームフォルダ外のパスはエラーになる。
+  return invoke<string>("file_hash", { path });
+}
+
 // パス検出関連API
 export function finderDetectAmongUs(): Promise<string> {
   // 既知のインストール先候補から Among Us を自動検出する。
@@ -71,36 +222,192 @@ export function finderDetectPlatforms(): Promise<DetectedPlatform[]> {
   return invoke<DetectedPlatform[]>("finder_detect_platforms");
 }
 
+export interface FinderProgressPayload {
+  scanId: string;
+  checkingPath: string;
+  index: number;
+  total: number;
+  detected: DetectedPlatform | null;
+}
+
+export interface FinderScanResult {
+  scanId: string;
+  detected: DetectedPlatform[];
+  cancelled: boolean;
+}
+
+export function finderScan(scanId?: string): Promise<FinderScanResult> {
+  // 進捗は "finder-progress" イベントで逐次通知され、scanId で自分のスキャンを見分ける。
+  return invoke<FinderScanResult>("finder_scan", { scanId: scanId ?? null });
+}
+
+export function finderScanCancel(scanId?: string): Promise<boolean> {
+  // scanId 未指定時は実行中の全スキャンを中断する。
+  return invoke<boolean>("finder_scan_cancel", { scanId: scanId ?? null });
+}
+
+export interface PathDriftStatus {
+  savedPath: string;
+  savedPathValid: boolean;
+  // 保存済みパスが無効で、別の場所に有効な候補が見つかった場合に true。
+  stale: boolean;
+  detected: DetectedPlatform | null;
+}
+
+export function pathDriftCheck(): Promise<PathDriftStatus> {
+  return invoke<PathDriftStatus>("path_drift_check");
+}
+
 // Modインストール関連API（汎用）
 export function modReleasesList(): Promise<SnrReleaseSummary[]> {
-  // 配布元から利用可能なリリース一覧を取得する。
+  // 配布元から利用可能なリリース一覧を取得する。一定時間はバックエンドのキャッシュを返す。
   return invoke<SnrReleaseSummary[]>("mod_releases_list");
 }
 
+export function releasesRefresh(): Promise<SnrReleaseSummary[]> {
+  // 手動更新時にキャッシュを破棄し、配布元からリリース一覧を取り直す。
+  return invoke<SnrReleaseSummary[]>("releases_refresh");
+}
+
 export function modInstall(input: {
   tag: string;
   platform: GamePlatform;
   restorePreservedSaveData: boolean;
+  // 指定時は保持データのうち、この相対パスのファイルだけを復元する。
+  restorePaths?: string[];
 }): Promise<InstallResult> {
   return invoke<InstallResult>("mod_install", input);
 }
 
-export function modUninstall(preserveSaveData: boolean): Promise<UninstallResult> {
-  return invoke<UninstallResult>("mod_uninstall", { preserveSaveData });
+export function modInstallFromFile(input: {
+  archivePath: string;
+  platform: GamePlatform;
+  restorePreservedSaveData?: boolean;
+  // true の場合はパッチャー同期を省略する。
+  skipPatchers?: boolean;
+}): Promise<InstallResult> {
+  // タグはファイル名から推定し、推定できなければ "local" になる。
+  return invoke<InstallResult>("mod_install_from_file", input);
+}
+
+export function modInstallAndLaunch(input: {
+  tag: string;
+  platform: GamePlatform;
+}): Promise<InstallAndLaunchResult> {
+  // インストール進捗は mod_install と同じイベントで通知される。
+  return invoke<InstallAndLaunchResult>("mod_install_and_launch", input);
+}
+
+export function modUpdateDiff(targetTag: string, platform: GamePlatform): Promise<ModUpdateDiff> {
+  return invoke<ModUpdateDiff>("mod_update_diff", { targetTag, platform });
+}
+
+export function modDownloadRelease(input: {
+  tag: string;
+  platform: GamePlatform;
+  outputPath: string;
+}): Promise<ReleaseDownloadResult> {
+  // インストールはせず、リリースzipだけを指定先へ保存する。
+  return invoke<ReleaseDownloadResult>("mod_download_release", input);
+}
+
+export function modRepatchNeeded(): Promise<RepatchCheck> {
+  return invoke<RepatchCheck>("mod_repatch_needed");
+}
+
+export function modPatchersResync(): Promise<PatcherResyncResult> {
+  return invoke<PatcherResyncResult>("mod_patchers_resync");
+}
+
+export function modInstallRetry(input: {
+  tag: string;
+  platform: GamePlatform;
+}): Promise<InstallResult> {
+  // 検証済みのダウンロードキャッシュがあれば再ダウンロードせずに展開し直す。
+  return invoke<InstallResult>("mod_install_retry", input);
+}
+
+export function modInstallCancel(tag?: string): Promise<boolean> {
+  // プロファイル差し替え開始後は中断されず、そのまま完了する。
+  return invoke<boolean>("mod_install_cancel", { tag: tag ?? null });
+}
+
+export function modInstallInProgress(): Promise<boolean> {
+  return invoke<boolean>("mod_install_in_progress");
+}
+
+export function operationStatus(): Promise<ActiveOperation[]> {
+  // Webview再生成後に、実行中の処理と直近の進捗へ追従する。
+  return invoke<ActiveOperation[]>("operation_status");
+}
+
+export function githubRateLimitStatus(): Promise<GitHubRateLimitStatus> {
+  return invoke<GitHubRateLimitStatus>("github_rate_limit_status");
+}
+
+export function installCleanupOrphans(): Promise<OrphanCleanupResult> {
+  return invoke<OrphanCleanupResult>("install_cleanup_orphans");
+}
+
+export function managedArtifactsList(): Promise<ManagedArtifact[]> {
+  return invoke<ManagedArtifact[]>("managed_artifacts_list");
+}
+
+export function managedArtifactsCleanup(): Promise<ManagedArtifactsCleanupResult> {
+  // 実行中の処理が使う作業領域、巻き戻し失敗で残された取り込みバックアップ、
+  // 導入済み・選択中タグのキャッシュは削除しない。
+  return invoke<ManagedArtifactsCleanupResult>("managed_artifacts_cleanup");
+}
+
+export function installArchiveValidate(archivePath: string): Promise<number> {
+  // 展開前に、ダウンロード済みアーカイブが壊れていないか確認する。
+  return invoke<number>("install_archive_validate", { archivePath });
+}
+
+export function modUninstall(
+  preserveSaveData: boolean,
+  // 省略時はダウンロードキャッシュを残し、同じバージョンの再インストールで再利用する。
+  keepCache?: boolean,
+): Promise<UninstallResult> {
+  return invoke<UninstallResult>("mod_uninstall", { preserveSaveData, keepCache });
+}
+
+export function modUninstallPreview(preserveSaveData: boolean): Promise<UninstallPreview> {
+  return invoke<UninstallPreview>("mod_uninstall_preview", { preserveSaveData });
 }
 
 export function modPreservedSaveDataStatus(): Promise<PreservedSaveDataStatus> {
   return invoke<PreservedSaveDataStatus>("mod_preserved_save_data_status");
 }
 
+export function preservedSaveDataDetails(): Promise<PreservedSaveDataDetails> {
+  return invoke<PreservedSaveDataDetails>("preserved_savedata_details");
+}
+
+export function bepinexConfigPreservationGet(): Promise<BepInExConfigPreservationStatus> {
+  return invoke<BepInExConfigPreservationStatus>("bepinex_config_preservation_get");
+}
+
 export function modSaveDataPreview(sourceAmongUsPath: string): Promise<SaveDataPreviewResult> {
   return invoke<SaveDataPreviewResult>("mod_savedata_preview", { sourceAmongUsPath });
 }
 
+export function savedataCompatCheck(sourceAmongUsPath: string): Promise<SaveDataCompatReport> {
+  return invoke<SaveDataCompatReport>("savedata_compat_check", { sourceAmongUsPath });
+}
+
 export function modSaveDataImport(sourceAmongUsPath: string): Promise<SaveDataImportResult> {
   return invoke<SaveDataImportResult>("mod_savedata_import", { sourceAmongUsPath });
 }
 
+export function modSaveDataImportArchive(archivePath: string): Promise<SaveDataImportResult> {
+  return invoke<SaveDataImportResult>("mod_savedata_import_archive", { archivePath });
+}
+
+export function modSaveDataImportCancel(): Promise<void> {
+  return invoke<void>("mod_savedata_import_cancel");
+}
+
 export function modSaveDataMergePresets(
   sourceAmongUsPath: string,
 ): Promise<SaveDataPresetMergeResult> {
@@ -156,18 +463,44 @@ export function migrationExport(input: {
   outputPath?: string;
   encryptionEnabled?: boolean;
   password?: string;
+  excludedPresetIds?: number[];
 }): Promise<MigrationExportResult> {
   // 既存データをアーカイブ化して外部保存できる形式で出力する。
   return invoke<MigrationExportResult>("migration_export", input);
 }
 
+export function migrationEstimate(input: {
+  scope?: MigrationEstimateScope;
+  encryptionEnabled?: boolean;
+  excludedPresetIds?: number[];
+  presetIds?: number[];
+}): Promise<MigrationEstimateResult> {
+  // 書き出し前に、書き出しと同じ入力で出力サイズの目安を取得する。
+  return invoke<MigrationEstimateResult>("migration_estimate", input);
+}
+
 export function migrationImport(input: {
   archivePath: string;
   password?: string;
+  reexportLegacy?: boolean;
+  verifyProfile?: boolean;
 }): Promise<MigrationImportResult> {
   return invoke<MigrationImportResult>("migration_import", input);
 }
 
+export function locallowWritableCheck(): Promise<LocalLowWritableCheck> {
+  return invoke<LocalLowWritableCheck>("locallow_writable_check");
+}
+
+export function migrationImportDialog(input: {
+  password?: string;
+  reexportLegacy?: boolean;
+  verifyProfile?: boolean;
+}): Promise<MigrationDialogImportResult> {
+  // 取り込むアーカイブはネイティブの開くダイアログで選ばせる。
+  return invoke<MigrationDialogImportResult>("migration_import_dialog", input);
+}
+
 export function migrationValidateArchivePassword(input: {
   archivePath: string;
   password?: string;
@@ -175,6 +508,63 @@ export function migrationValidateArchivePassword(input: {
   return invoke<MigrationPasswordValidationResult>("migration_validate_archive_password", input);
 }
 
+export function migrationArchiveInfo(path: string): Promise<MigrationArchiveInfoResult> {
+  // ヘッダだけを読むため、暗号化アーカイブでもパスワードなしで取得できる。
+  return invoke<MigrationArchiveInfoResult>("migration_archive_info", { path });
+}
+
+export function migrationBackupsList(): Promise<RetainedMigrationBackupResult[]> {
+  return invoke<RetainedMigrationBackupResult[]>("migration_backups_list");
+}
+
+export function migrationRecoverBackup(backupId: string): Promise<void> {
+  // 巻き戻しに失敗した取り込みのバックアップから手動で復元する。
+  return invoke<void>("migration_recover_backup", { backupId });
+}
+
+export function migrationBackupDiscard(backupId: string): Promise<void> {
+  return invoke<void>("migration_backup_discard", { backupId });
+}
+
+export function modprofileValidateFile(path: string): Promise<ModConfig> {
+  // 外部の mod.config.json を同梱設定と同じ規則で検証する。
+  return invoke<ModConfig>("modprofile_validate_file", { path });
+}
+
+export interface EffectiveModProfile {
+  profile: ModConfig;
+  githubReleasesApiUrl: string;
+  githubReleaseByTagApiBaseUrl: string;
+  launcherUserAgent: string;
+  // パス類はOS依存の区切り文字で解決済み。
+  saveDataRoot: string;
+  localLowRoot: string;
+  localLowAdditionalPrefixes: string[];
+  profileRequiredFiles: string[];
+  defaultGameServerId: string | null;
+}
+
+export function modprofileEffective(): Promise<EffectiveModProfile> {
+  // 同梱設定がバックエンドでどう解釈されたかを確認する。
+  return invoke<EffectiveModProfile>("modprofile_effective");
+}
+
+export interface ModLinks {
+  wikiUrl: string;
+  // connectLinks 無効時は null / 空配列になる。
+  supportDiscordUrl: string | null;
+  official: ModConfig["links"]["official"];
+}
+
+export function linksGet(): Promise<ModLinks> {
+  return invoke<ModLinks>("links_get");
+}
+
+export function featuresGet(): Promise<ModConfig["features"]> {
+  // mod.config.json の機能フラグをバックエンド側の解釈で取得する。
+  return invoke<ModConfig["features"]>("features_get");
+}
+
 // プリセット関連API
 export function presetsListLocal(): Promise<PresetSummary[]> {
   // ローカル SaveData からプリセット一覧を読み出す。
@@ -188,31 +578,92 @@ export function presetsExport(input: {
   return invoke<PresetExportResult>("presets_export", input);
 }
 
+export function presetsExportDialog(input: {
+  presetIds: number[];
+  // 省略時はネイティブの保存ダイアログで書き出し先を選ばせる。
+  outputPath?: string;
+}): Promise<PresetDialogExportResult> {
+  return invoke<PresetDialogExportResult>("presets_export_dialog", input);
+}
+
+export function presetsExportAll(outputPath?: string): Promise<PresetExportResult> {
+  return invoke<PresetExportResult>("presets_export_all", { outputPath });
+}
+
 export function presetsInspectArchive(archivePath: string): Promise<PresetSummary[]> {
   return invoke<PresetSummary[]>("presets_inspect_archive", { archivePath });
 }
 
+export function presetsVerifyArchive(archivePath: string): Promise<PresetArchiveIntegrity> {
+  return invoke<PresetArchiveIntegrity>("presets_verify_archive", { archivePath });
+}
+
 export function presetsImportArchive(input: {
   archivePath: string;
   selections: PresetImportSelectionInput[];
+  strategy?: PresetImportStrategy;
 }): Promise<PresetImportResult> {
   return invoke<PresetImportResult>("presets_import_archive", input);
 }
 
+export function presetsImportArchiveDialog(input: {
+  // 省略時はアーカイブ内のデータを持つ全プリセットを取り込む。
+  selections?: PresetImportSelectionInput[];
+  strategy?: PresetImportStrategy;
+}): Promise<PresetDialogImportResult> {
+  return invoke<PresetDialogImportResult>("presets_import_archive_dialog", input);
+}
+
+export function optionsBackup(): Promise<OptionsBackupResult> {
+  // 編集前などに Options.data だけを退避する。
+  return invoke<OptionsBackupResult>("options_backup");
+}
+
+export function optionsRestore(backupId: string): Promise<OptionsBackupResult> {
+  return invoke<OptionsBackupResult>("options_restore", { backupId });
+}
+
+export function optionsRepair(): Promise<OptionsRepairResult> {
+  return invoke<OptionsRepairResult>("options_repair");
+}
+
 // Reporting関連API
 export function reportingPrepare(): Promise<ReportingPrepareResult> {
   // 報告機能の利用可否とトークン状態を初期化する。
   return invoke<ReportingPrepareResult>("reporting_prepare");
 }
 
+export function reportingTermsGet(): Promise<string> {
+  // アカウント作成前の同意画面で表示する規約本文を取得する。
+  return invoke<string>("reporting_terms_get");
+}
+
+export function reportingHealthcheck(): Promise<ReportingHealthcheck> {
+  // 報告タブが空になる原因をトークン・通信・サーバのどこか切り分ける。
+  return invoke<ReportingHealthcheck>("reporting_healthcheck");
+}
+
 export function reportingThreadsList(): Promise<ReportThread[]> {
   return invoke<ReportThread[]>("reporting_threads_list");
 }
 
+export function reportingStatusSummary(): Promise<ReportStatusSummary> {
+  return invoke<ReportStatusSummary>("reporting_status_summary");
+}
+
+export function reportingThreadResolve(threadId: string): Promise<ReportThread | null> {
+  // 通知からの遷移前に、スレッドが削除されていないか確認する。
+  return invoke<ReportThread | null>("reporting_thread_resolve", { threadId });
+}
+
 export function reportingMessagesList(threadId: string): Promise<ReportMessage[]> {
   return invoke<ReportMessage[]>("reporting_messages_list", { threadId });
 }
 
+export function reportingThreadExport(threadId: string, outputPath: string): Promise<string> {
+  return invoke<string>("reporting_thread_export", { threadId, outputPath });
+}
+
 export function reportingMessageSend(
   threadId: string,
   content: string,
@@ -236,11 +687,34 @@ export function notificationsTakeOpenTarget(): Promise<NotificationOpenTarget |
   return invoke<NotificationOpenTarget | null>("notifications_take_open_target");
 }
 
+export function announceRefresh(): Promise<void> {
+  return invoke<void>("announce_refresh");
+}
+
+export function notificationsPollNow(): Promise<void> {
+  return invoke<void>("notifications_poll_now");
+}
+
+export function notificationsWorkerSetPaused(paused: boolean): Promise<void> {
+  return invoke<void>("notifications_worker_set_paused", { paused });
+}
+
+export function unreadCountsGet(announceReadAt: number | null): Promise<UnreadCounts> {
+  return invoke<UnreadCounts>("unread_counts_get", { announceReadAt });
+}
+
 // ゲームサーバー関連API
 export function gameServersJoinDirect(query: string): Promise<GameServersJoinDirectResult> {
   return invoke<GameServersJoinDirectResult>("game_servers_join_direct", { query });
 }
 
+export function gameServersRoomCheck(
+  serverId: string,
+  roomCode: string,
+): Promise<GameServerRoomCheck> {
+  return invoke<GameServerRoomCheck>("game_servers_room_check", { serverId, roomCode });
+}
+
 // 起動関連API
 export function launchModded(input: {
   gameExe: string;
@@ -251,6 +725,15 @@ export function launchModded(input: {
   return invoke<void>("launch_modded", input);
 }
 
+export function launchWaitReady(timeoutMs?: number): Promise<GameReadyResult> {
+  // 起動直後の黒画面が終わり、ウィンドウが表示されるまで待つ。
+  return invoke<GameReadyResult>("launch_wait_ready", { timeoutMs });
+}
+
+export function gameProcessesList(): Promise<GameProcessInfo[]> {
+  return invoke<GameProcessInfo[]>("game_processes_list");
+}
+
 export function launchModdedElevated(input: {
   gameExe: string;
   profilePath: string;
@@ -277,10 +760,44 @@ export function launchShortcutCreate(): Promise<string> {
   return invoke<string>("launch_shortcut_create");
 }
 
+export function shortcutsList(): Promise<string[]> {
+  return invoke<string[]>("shortcuts_list");
+}
+
+export function shortcutsCleanup(): Promise<ShortcutCleanupResult> {
+  // 最初に作成された1つを残し、連番付きの重複を削除する。
+  return invoke<ShortcutCleanupResult>("shortcuts_cleanup");
+}
+
+export function startupAutolaunchGet(): Promise<StartupAutolaunchStatus> {
+  return invoke<StartupAutolaunchStatus>("startup_autolaunch_get");
+}
+
+export function startupAutolaunchSet(input: {
+  enable: boolean;
+  autolaunchModded?: boolean;
+}): Promise<StartupAutolaunchStatus> {
+  // Windowsログオン時にランチャーを常駐させるかを切り替える。
+  return invoke<StartupAutolaunchStatus>("startup_autolaunch_set", input);
+}
+
 export function launchModdedFirstSetupPending(gameExe: string): Promise<boolean> {
   return invoke<boolean>("launch_modded_first_setup_pending", { gameExe });
 }
 
+export function launchDoorstopArtifactsGet(gameExe: string): Promise<string[]> {
+  // Vanilla起動前に、手動導入の残骸がないか確認する。
+  return invoke<string[]>("launch_doorstop_artifacts_get", { gameExe });
+}
+
+export function launchDoorstopArtifactsDisable(gameExe: string): Promise<string[]> {
+  return invoke<string[]>("launch_doorstop_artifacts_disable", { gameExe });
+}
+
+export function interopGenerationStatus(gameExe: string): Promise<InteropGenerationStatus> {
+  return invoke<InteropGenerationStatus>("interop_generation_status", { gameExe });
+}
+
 export function launchAutolaunchErrorTake(): Promise<string | null> {
   return invoke<string | null>("launch_autolaunch_error_take");
 }
@@ -289,6 +806,23 @@ export function launchGameRunningGet(): Promise<boolean> {
   return invoke<boolean>("launch_game_running_get");
 }
 
+export function gameStateGet(): Promise<GameStateSnapshot> {
+  return invoke<GameStateSnapshot>("game_state_get");
+}
+
+export function launchPreflight(modded: boolean): Promise<LaunchPreflightReport> {
+  return invoke<LaunchPreflightReport>("launch_preflight", { modded });
+}
+
+export function profileValidate(path: string): Promise<ProfileValidationReport> {
+  // 任意フォルダを選択したときに、起動に必要な構成要素の有無を確認する。
+  return invoke<ProfileValidationReport>("profile_validate", { path });
+}
+
+export function avStatus(): Promise<AvStatus> {
+  return invoke<AvStatus>("av_status");
+}
+
 export function launchSteamRunningGet(): Promise<boolean> {
   return invoke<boolean>("launch_steam_running_get");
 }
@@ -324,6 +858,10 @@ export function epicStatusGet(): Promise<EpicLoginStatus> {
   return invoke<EpicLoginStatus>("epic_status_get");
 }
 
+export function epicLaunchPrecheck(): Promise<EpicLaunchPrecheckResult> {
+  return invoke<EpicLaunchPrecheckResult>("epic_launch_precheck");
+}
+
 export function epicLogout(): Promise<void> {
   return invoke<void>("epic_logout");
 }
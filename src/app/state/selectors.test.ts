@@ -67,6 +67,15 @@ describe("computeControlState", () => {
       announceNotificationsEnabled: true,
       uiLocale: "ja",
       onboardingCompleted: true,
+      migrationEncryptByDefault: false,
+      migrationDefaultOutputDir: "",
+      logLevel: "info",
+      notificationDetailLevel: "full",
+      cacheMaxSizeMb: 0,
+      startMinimized: false,
+      preserveBepinexConfig: false,
+      trayMenuPinned: false,
+      closeActionPrompt: true,
     };
 
     const result = computeControlState(state);
@@ -87,6 +96,15 @@ describe("computeControlState", () => {
       announceNotificationsEnabled: true,
       uiLocale: "ja",
       onboardingCompleted: true,
+      migrationEncryptByDefault: false,
+      migrationDefaultOutputDir: "",
+      logLevel: "info",
+      notificationDetailLevel: "full",
+      cacheMaxSizeMb: 0,
+      startMinimized: false,
+      preserveBepinexConfig: false,
+      trayMenuPinned: false,
+      closeActionPrompt: true,
     };
 
     state.profileIsReady = true;
@@ -113,6 +131,15 @@ describe("computeControlState", () => {
       announceNotificationsEnabled: true,
       uiLocale: "ja",
       onboardingCompleted: true,
+      migrationEncryptByDefault: false,
+      migrationDefaultOutputDir: "",
+      logLevel: "info",
+      notificationDetailLevel: "full",
+      cacheMaxSizeMb: 0,
+      startMinimized: false,
+      preserveBepinexConfig: false,
+      trayMenuPinned: false,
+      closeActionPrompt: true,
     };
 
     state.gameRunning = true;
@@ -137,6 +164,15 @@ describe("computeControlState", () => {
       announceNotificationsEnabled: true,
       uiLocale: "ja",
       onboardingCompleted: true,
+      migrationEncryptByDefault: false,
+      migrationDefaultOutputDir: "",
+      logLevel: "info",
+      notificationDetailLevel: "full",
+      cacheMaxSizeMb: 0,
+      startMinimized: false,
+      preserveBepinexConfig: false,
+      trayMenuPinned: false,
+      closeActionPrompt: true,
     };
 
     state.archivePresets = [{ id: 1, name: "x", hasDataFile: false }];
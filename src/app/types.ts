@@ -5,6 +5,16 @@
 
 export type GamePlatform = "steam" | "epic";
 export type ReportType = "Bug" | "Question" | "Request" | "Thanks" | "Other";
+export type LogLevel = "off" | "error" | "warn" | "info" | "debug" | "trace";
+export type NotificationDetailLevel = "full" | "titleOnly" | "countOnly";
+
+export interface AppBootstrapSummary {
+  appDataDir: string;
+  createdDirectories: string[];
+  firstRun: boolean;
+  profilePath: string;
+  profilePathInitialized: boolean;
+}
 
 export interface LauncherSettings {
   amongUsPath: string;
@@ -18,6 +28,15 @@ export interface LauncherSettings {
   announceNotificationsEnabled: boolean;
   uiLocale: string;
   onboardingCompleted: boolean;
+  migrationEncryptByDefault: boolean;
+  migrationDefaultOutputDir: string;
+  logLevel: LogLevel;
+  notificationDetailLevel: NotificationDetailLevel;
+  cacheMaxSizeMb: number;
+  startMinimized: boolean;
+  preserveBepinexConfig: boolean;
+  trayMenuPinned: boolean;
+  closeActionPrompt: boolean;
 }
 
 export interface LauncherSettingsInput {
@@ -33,12 +52,38 @@ export interface LauncherSettingsInput {
   announceNotificationsEnabled?: boolean;
   uiLocale?: string;
   onboardingCompleted?: boolean;
+  migrationEncryptByDefault?: boolean;
+  migrationDefaultOutputDir?: string;
+  logLevel?: LogLevel;
+  notificationDetailLevel?: NotificationDetailLevel;
+  cacheMaxSizeMb?: number;
+  startMinimized?: boolean;
+  preserveBepinexConfig?: boolean;
+  trayMenuPinned?: boolean;
+  closeActionPrompt?: boolean;
+}
+
+export interface ProfileSettingsOverrides {
+  // 未指定の項目はグローバル設定の値が使われる。
+  amongUsPath?: string;
+  gamePlatform?: GamePlatform;
+  selectedGameServerId?: string;
+  // Doorstop の起動対象。相対パスはプロファイル基準で解決される。
+  doorstopTargetAssembly?: string;
+  doorstopCorlibDir?: string;
+  doorstopCoreclrPath?: string;
 }
 
 export type NotificationOpenTarget =
   // バックグラウンド通知の遷移先を discriminated union で表現する。
   { kind: "report"; threadId: string } | { kind: "announce"; articleId: string };
 
+export interface UnreadCounts {
+  // 取得できなかった項目は null。
+  reportThreads: number | null;
+  announcements: number | null;
+}
+
 export interface SnrReleaseSummary {
   tag: string;
   name: string;
@@ -53,10 +98,92 @@ export interface InstallResult {
   restoredSaveFiles: number;
 }
 
+export interface InstallAndLaunchResult {
+  install: InstallResult;
+  // true の場合、起動後にBepInExのinterop生成が走るためゲーム表示まで時間がかかる。
+  firstSetupPending: boolean;
+}
+
+export interface ProfileHealth {
+  profilePath: string;
+  exists: boolean;
+  ready: boolean;
+  missingFiles: string[];
+  installedTag: string | null;
+}
+
+export interface LegacyProfileCandidate {
+  path: string;
+  ready: boolean;
+  hasSaveData: boolean;
+  installedTag: string | null;
+}
+
+export interface LegacyProfileDetection {
+  profilePath: string;
+  profileExists: boolean;
+  candidates: LegacyProfileCandidate[];
+}
+
+export interface LegacyProfileMigrationResult {
+  sourcePath: string;
+  profilePath: string;
+  movedProfile: boolean;
+  movedSaveData: boolean;
+}
+
+export interface SaveDataPaths {
+  profilePath: string;
+  saveDataDir: string;
+  saveDataDirExists: boolean;
+  optionsDataPath: string;
+  optionsDataExists: boolean;
+  presetCount: number;
+  presetsSaveDataDir: string;
+  consistent: boolean;
+}
+
+export interface ModUpdateDiff {
+  installedTag: string;
+  targetTag: string;
+  platform: string;
+  // 各一覧はプロファイル相対の "/" 区切りパス。
+  added: string[];
+  removed: string[];
+  changed: string[];
+  unchangedCount: number;
+}
+
+export interface ReleaseDownloadResult {
+  outputPath: string;
+  size: number;
+  sha256: string;
+}
+
+export interface RepatchCheck {
+  // ゲーム更新後にパッチャーの再同期が推奨される場合は true。
+  needed: boolean;
+  detectedGameVersion: string | null;
+  recordedGameVersion: string | null;
+}
+
+export interface PatcherResyncResult {
+  skipped: string[];
+  gameVersion: string | null;
+}
+
 export interface UninstallResult {
   profilePath: string;
   removedProfile: boolean;
   preservedFiles: number;
+  reusableCache: boolean;
+}
+
+export interface UninstallPreview {
+  profilePath: string;
+  profileExists: boolean;
+  preservedFiles: number;
+  removedBytes: number;
 }
 
 export interface PreservedSaveDataStatus {
@@ -65,6 +192,66 @@ export interface PreservedSaveDataStatus {
   files: number;
 }
 
+export interface PreservedSaveDataFile {
+  path: string;
+  size: number;
+  // UNIX秒。取得できない場合は null。
+  modifiedAt: number | null;
+}
+
+export interface PreservedSaveDataDetails {
+  files: PreservedSaveDataFile[];
+  totalSize: number;
+}
+
+export interface BepInExConfigPreservationStatus {
+  enabled: boolean;
+  // いずれもプロファイル相対の "/" 区切りパス。
+  profileFiles: string[];
+  preservedFiles: string[];
+}
+
+export interface GitHubRateLimitStatus {
+  limit: number;
+  remaining: number;
+  // resetAt は UNIX 秒。
+  resetAt: number;
+  resetsInSeconds: number;
+}
+
+export interface OrphanCleanupResult {
+  removedPaths: string[];
+  reclaimedBytes: number;
+  restoredProfileFromBackup: boolean;
+}
+
+export type ManagedArtifactKind =
+  | "profileStaging"
+  | "profileBackup"
+  | "saveDataImportStaging"
+  | "saveDataImportBackup"
+  | "elevatedLaunch"
+  | "migrationImportBackup"
+  | "releaseCache";
+
+export interface ManagedArtifact {
+  kind: ManagedArtifactKind;
+  path: string;
+  sizeBytes: number;
+  // modifiedAt は UNIX 秒。
+  modifiedAt: number | null;
+  ageSeconds: number | null;
+  inUse: boolean;
+  safeToDelete: boolean;
+}
+
+export interface ManagedArtifactsCleanupResult {
+  removedPaths: string[];
+  reclaimedBytes: number;
+  skippedPaths: string[];
+  restoredProfileFromBackup: boolean;
+}
+
 export interface MigrationExportResult {
   archivePath: string;
   includedFiles: number;
@@ -73,15 +260,59 @@ export interface MigrationExportResult {
   encrypted: boolean;
 }
 
+export type MigrationEstimateScope = "migration" | "presets";
+
+export interface MigrationEstimateResult {
+  includedFiles: number;
+  profileFiles: number;
+  locallowFiles: number;
+  sourceBytes: number;
+  estimatedArchiveBytes: number;
+  encrypted: boolean;
+}
+
+export type MigrationArchiveFormat = "current" | "legacy" | "plain";
+
 export interface MigrationImportResult {
   importedFiles: number;
   profileFiles: number;
   locallowFiles: number;
   encrypted: boolean;
+  format: MigrationArchiveFormat;
+  reexportedArchivePath: string | null;
+  missingProfileFiles: string[] | null;
+}
+
+export type MigrationDialogImportResult =
+  | ({ status: "imported" } & MigrationImportResult)
+  | { status: "cancelled" };
+
+export interface LocalLowWritableCheck {
+  // 書き込み可能で、ゲームも起動していない場合は true。
+  ready: boolean;
+  path: string | null;
+  writable: boolean;
+  gameRunning: boolean;
+  message: string | null;
 }
 
 export interface MigrationPasswordValidationResult {
   encrypted: boolean;
+  format: MigrationArchiveFormat;
+}
+
+export interface MigrationArchiveInfoResult {
+  format: MigrationArchiveFormat;
+  version: number | null;
+  encrypted: boolean;
+  fileSize: number;
+}
+
+export interface RetainedMigrationBackupResult {
+  backupId: string;
+  path: string;
+  hasProfileBackup: boolean;
+  hasLocallowBackup: boolean;
 }
 
 export interface PresetSummary {
@@ -95,15 +326,27 @@ export interface PresetExportResult {
   exportedPresets: number;
 }
 
+export type PresetDialogExportResult =
+  | ({ status: "exported" } & PresetExportResult)
+  | { status: "cancelled" };
+
 export interface PresetImportSelectionInput {
   sourceId: number;
   name?: string;
 }
 
+export type PresetImportStrategy = "alwaysNew" | "skipDuplicateNames" | "overwrite";
+
+// unsigned はチェックサムの無い旧形式・手作りのアーカイブ。
+export type PresetArchiveIntegrity = "unsigned" | "verified" | "modified";
+
+export type PresetImportAction = "created" | "skipped" | "overwritten";
+
 export interface ImportedPresetResult {
   sourceId: number;
   targetId: number;
   name: string;
+  action: PresetImportAction;
 }
 
 export interface PresetImportResult {
@@ -111,6 +354,22 @@ export interface PresetImportResult {
   imported: ImportedPresetResult[];
 }
 
+export type PresetDialogImportResult =
+  | ({ status: "imported" } & PresetImportResult)
+  | { status: "cancelled" };
+
+export interface OptionsBackupResult {
+  backupId: string;
+  currentPreset: number;
+  presetCount: number;
+}
+
+export interface OptionsRepairResult {
+  repaired: boolean;
+  // 修復前のファイルをそのまま退避したバックアップID。
+  backupId: string | null;
+}
+
 export interface SaveDataPreviewResult {
   sourceAmongUsPath: string;
   sourceSaveDataPath: string;
@@ -118,6 +377,14 @@ export interface SaveDataPreviewResult {
   fileCount: number;
 }
 
+export interface SaveDataCompatReport {
+  sourceSaveDataPath: string;
+  targetSaveDataPath: string;
+  sourceVersion: number | null;
+  targetVersion: number | null;
+  compatible: boolean;
+}
+
 export interface SaveDataImportResult {
   sourceSaveDataPath: string;
   targetSaveDataPath: string;
@@ -130,6 +397,14 @@ export interface SaveDataPresetMergeResult {
   importedPresets: number;
 }
 
+export interface InstalledProfileSummary {
+  name: string;
+  path: string;
+  version: string | null;
+  platform: string | null;
+  lastModified: number | null;
+}
+
 export interface InstallProgressPayload {
   stage: string;
   progress: number;
@@ -140,8 +415,109 @@ export interface InstallProgressPayload {
   entriesTotal?: number;
 }
 
+export type OperationKind =
+  | "install"
+  | "migrationExport"
+  | "migrationImport"
+  | "saveDataImport"
+  | "reportSend";
+
+export interface ActiveOperation {
+  kind: OperationKind;
+  startedAt: number;
+  progress: unknown | null;
+}
+
+export type GameLaunchPhase = "launching" | "ready";
+
 export interface GameStatePayload {
   running: boolean;
+  phase?: GameLaunchPhase;
+}
+
+export interface GameStateSnapshot {
+  running: boolean;
+  pid: number | null;
+  // UNIXミリ秒。追跡できない場合は null。
+  startedAtMs: number | null;
+}
+
+export interface ShortcutCleanupResult {
+  kept: string | null;
+  removed: string[];
+}
+
+export interface StartupAutolaunchStatus {
+  enabled: boolean;
+  autolaunchModded: boolean;
+  pathMatches: boolean;
+}
+
+export interface GameProcessInfo {
+  pid: number;
+  modded: boolean | null;
+  tracked: boolean;
+}
+
+export interface GameReadyResult {
+  ready: boolean;
+  windowDetected: boolean;
+  elapsedMs: number;
+}
+
+export type PreflightStatus = "pass" | "warn" | "fail";
+
+export interface PreflightCheck {
+  id: string;
+  status: PreflightStatus;
+  message: string;
+}
+
+export interface LaunchPreflightReport {
+  ready: boolean;
+  checks: PreflightCheck[];
+}
+
+export type ProfileComponentId =
+  | "bepinexCore"
+  | "dotnetCorlib"
+  | "dotnetCoreclr"
+  | "requiredFile"
+  | "interop";
+
+export interface ProfileComponentCheck {
+  id: ProfileComponentId;
+  path: string;
+  present: boolean;
+  required: boolean;
+}
+
+export interface ProfileValidationReport {
+  path: string;
+  valid: boolean;
+  components: ProfileComponentCheck[];
+}
+
+export interface AvProduct {
+  name: string;
+  realTimeEnabled: boolean;
+  definitionsUpToDate: boolean;
+}
+
+export interface AvStatus {
+  queryable: boolean;
+  realTimeProtectionEnabled: boolean | null;
+  activeProduct: string | null;
+  products: AvProduct[];
+  error: string | null;
+}
+
+export type InteropGenerationState = "notStarted" | "inProgress" | "stalled" | "complete";
+
+export interface InteropGenerationStatus {
+  state: InteropGenerationState;
+  fileCount: number;
+  secondsSinceLastChange: number | null;
 }
 
 export interface EpicLoginStatus {
@@ -151,6 +527,11 @@ export interface EpicLoginStatus {
   profileError: string | null;
 }
 
+export interface EpicLaunchPrecheckResult {
+  status: "ok" | "needsRelogin" | "networkError";
+  message: string | null;
+}
+
 export type SocialBrandId = "fanbox" | "discord" | "youtube" | "github" | "x";
 
 export type SocialIcon =
@@ -191,6 +572,19 @@ export interface ReportThread {
   currentStatus: ReportStatus;
 }
 
+export interface ReportStatusCount {
+  status: ReportStatus;
+  threads: number;
+  unread: number;
+}
+
+export interface ReportStatusSummary {
+  totalThreads: number;
+  unreadThreads: number;
+  // 一覧で最初に現れた順。
+  byStatus: ReportStatusCount[];
+}
+
 export interface ReportMessage {
   // messageType により本文/ステータス更新の描画分岐を行う。
   messageType: string;
@@ -202,6 +596,20 @@ export interface ReportMessage {
   mark?: string;
 }
 
+export interface ReportingHealthStep {
+  id: string;
+  success: boolean;
+  latencyMs: number;
+  message: string | null;
+}
+
+export interface ReportingHealthcheck {
+  healthy: boolean;
+  tokenSource: string | null;
+  createdAccount: boolean;
+  steps: ReportingHealthStep[];
+}
+
 export interface ReportingLogSourceInfo {
   profileCandidate: string;
   gameCandidate: string;
@@ -215,6 +623,15 @@ export interface GameServersJoinDirectResult {
   ok: boolean;
 }
 
+export interface GameServerRoomCheck {
+  serverId: string;
+  roomCode: string;
+  exists: boolean;
+  playerCount: number | null;
+  maxPlayers: number | null;
+  joinable: boolean;
+}
+
 export interface SendReportInput {
   reportType: ReportType;
   title: string;
@@ -222,6 +639,8 @@ export interface SendReportInput {
   map?: string;
   role?: string;
   timing?: string;
+  includePlayerLog?: boolean;
+  attachmentPaths?: string[];
 }
 
 export type StatusTone = "info" | "error" | "success" | "warn";
@@ -388,6 +388,8 @@ const en: Record<keyof typeof ja, string> = {
   "preset.statusInspectDone":
     "Inspection done: {total} (importable {importable} / missing {missing})",
   "preset.statusInspectFailed": "Inspection failed: {error}",
+  "preset.archiveModifiedWarning":
+    "This archive was modified after it was exported. Only import it if you trust the sender.",
   "preset.importPathRequired": "Enter the source .{presetExt} path.",
   "preset.importSelectRequired": "Select presets to import.",
   "preset.emptyName": "(empty name)",
@@ -405,6 +407,7 @@ const en: Record<keyof typeof ja, string> = {
   "install.progressPercent": "{message} ({percent}%)",
   "install.progressBytes": "{message} ({downloaded} {bytes})",
   "install.progressEntries": "{message} ({current}/{total})",
+  "install.profileMissing": "The profile is missing or incomplete. Please reinstall: {path}",
 
   "uninstall.starting": "Uninstalling...",
   "uninstall.done": "Uninstall complete",
@@ -457,6 +460,9 @@ const en: Record<keyof typeof ja, string> = {
   "launch.steamNotRunningContinue": "Launch Anyway",
   "launch.steamNotRunningWarning":
     "Steam is selected, but Steam is not running. Start Steam first.",
+  "launch.pathDriftTitle": "Among Us folder moved",
+  "launch.pathDriftConfirm":
+    "The saved Among Us folder is no longer valid:\n{saved}\n\nAmong Us was found at:\n{detected}\n\nUpdate the path?",
   "launch.gameRunning": "Game is running",
   "launch.gameStopped": "Game is stopped",
 
@@ -543,6 +549,11 @@ const en: Record<keyof typeof ja, string> = {
     "Failed to merge presets from preserved SaveData.\n\n{error}\n\nOK: Retry / Cancel: Skip",
   "installFlow.importSkippedNotice":
     "Data import was skipped. You can retry later if needed. Reason: {reason}",
+  "installFlow.importVersionMismatchPrompt":
+    "The SaveData format differs between the source and this profile (source: v{source} / profile: v{target}).\nImporting may mix incompatible data.\n\nOK: Import anyway / Cancel: Skip",
+  "installFlow.importVersionMismatchSkipped": "SaveData format version mismatch",
+  "installFlow.antivirusTip":
+    "Real-time protection from {product} is active. If the install or launch fails, add the launcher's profile folder to its exclusions.",
 
   "common.skip": "Skip",
   "common.retry": "Retry",
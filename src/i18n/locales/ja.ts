@@ -381,6 +381,8 @@ const ja = {
   "preset.statusInspecting": "アーカイブの中身を確認中...",
   "preset.statusInspectDone": "確認完了: {total}件 (インポート可能 {importable} / 欠損 {missing})",
   "preset.statusInspectFailed": "中身確認失敗: {error}",
+  "preset.archiveModifiedWarning":
+    "このアーカイブは書き出し後に変更されています。送り主を信頼できる場合のみ取り込んでください。",
   "preset.importPathRequired": "インポート元の .{presetExt} パスを入力してください。",
   "preset.importSelectRequired": "インポートするプリセットを選択してください。",
   "preset.emptyName": "(空名)",
@@ -398,6 +400,7 @@ const ja = {
   "install.progressPercent": "{message} ({percent}%)",
   "install.progressBytes": "{message} ({downloaded} {bytes})",
   "install.progressEntries": "{message} ({current}/{total})",
+  "install.profileMissing": "プロファイルが見つからないか不完全です。再インストールしてください: {path}",
 
   "uninstall.starting": "アンインストール中...",
   "uninstall.done": "アンインストール完了",
@@ -448,6 +451,9 @@ const ja = {
   "launch.steamNotRunningContinue": "それでも起動する",
   "launch.steamNotRunningWarning":
     "Steam版が選択されていますが、Steamが起動していません。先にSteamを起動してください。",
+  "launch.pathDriftTitle": "Among Usの場所が変わりました",
+  "launch.pathDriftConfirm":
+    "保存済みのAmong Usフォルダが見つかりません:\n{saved}\n\n次の場所でAmong Usが見つかりました:\n{detected}\n\nパスを更新しますか？",
   "launch.gameRunning": "ゲーム実行中",
   "launch.gameStopped": "ゲーム停止中",
 
@@ -536,6 +542,11 @@ const ja = {
     "保持セーブデータプリセットの統合に失敗しました。\n\n{error}\n\nOK: 再試行 / キャンセル: スキップ",
   "installFlow.importSkippedNotice":
     "データ取り込みはスキップされました。必要なら後で再実行してください。理由: {reason}",
+  "installFlow.importVersionMismatchPrompt":
+    "取り込み元とプロファイルでSaveDataの形式が異なります（取り込み元: v{source} / プロファイル: v{target}）。\n取り込むと互換性のないデータが混在する可能性があります。\n\nOK: そのまま取り込む / キャンセル: スキップ",
+  "installFlow.importVersionMismatchSkipped": "SaveDataの形式バージョン不一致",
+  "installFlow.antivirusTip":
+    "{product} のリアルタイム保護が有効です。インストールや起動に失敗する場合は、ランチャーのプロファイルフォルダを除外設定に追加してください。",
 
   "common.skip": "スキップ",
   "common.retry": "再試行",
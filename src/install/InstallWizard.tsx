@@ -8,6 +8,7 @@ import {
 } from "../app/modConfig";
 import { isPlatformSelectable } from "../app/platformSelection";
 import {
+  avStatus,
   epicLoginWebview,
   epicSessionRestore,
   epicStatusGet,
@@ -21,6 +22,7 @@ import {
   modSaveDataImport,
   modSaveDataMergePresets,
   modSaveDataPreview,
+  savedataCompatCheck,
   settingsUpdate,
 } from "../app/services/tauriClient";
 import { type ThemePreference, applyTheme, getStoredTheme, setStoredTheme } from "../app/theme";
@@ -98,6 +100,7 @@ export default function InstallWizard() {
   const [preservedSaveDataAvailable, setPreservedSaveDataAvailable] = useState(false);
   const [epicLoggedIn, setEpicLoggedIn] = useState(false);
   const [epicUserDisplay, setEpicUserDisplay] = useState<string | null>(null);
+  const [antivirusProduct, setAntivirusProduct] = useState<string | null>(null);
   const releasesRequestIdRef = useRef(0);
   const migrationPasswordValidationRequestIdRef = useRef(0);
   const installInProgressRef = useRef(false);
@@ -433,6 +436,21 @@ export default function InstallWizard() {
         // インストール本体完了後の補助処理として進捗 99% 帯で表示する。
         setProgress(99);
         setProgressMessage(t("installFlow.importingSaveData"));
+        // 形式の異なる Options.data が混在しないよう、取り込み前に確認を挟む。
+        const compat = await savedataCompatCheck(importSourceAmongUsPath).catch(() => null);
+        if (
+          compat &&
+          !compat.compatible &&
+          !window.confirm(
+            t("installFlow.importVersionMismatchPrompt", {
+              source: compat.sourceVersion ?? "-",
+              target: compat.targetVersion ?? "-",
+            }),
+          )
+        ) {
+          markImportSkipped(t("installFlow.importVersionMismatchSkipped"));
+          return false;
+        }
         return runImportOperationWithRetryPrompt({
           operation: () => modSaveDataImport(importSourceAmongUsPath),
           promptKey: "installFlow.importRetrySkipPrompt",
@@ -569,6 +587,24 @@ export default function InstallWizard() {
     }
   }, [step, platform]);
 
+  useEffect(() => {
+    if (step !== "confirm") {
+      return;
+    }
+    // 確認画面でリアルタイム保護を検出したら、除外設定の案内を表示する。照会失敗時は何も出さない。
+    let cancelled = false;
+    void avStatus()
+      .then((status) => {
+        if (!cancelled) {
+          setAntivirusProduct(status.realTimeProtectionEnabled ? status.activeProduct : null);
+        }
+      })
+      .catch(() => {});
+    return () => {
+      cancelled = true;
+    };
+  }, [step]);
+
   useEffect(() => {
     // バックエンド進捗イベントを購読してバー表示へ反映する。
     const unlisten = listen<InstallProgressPayload>(installProgressEventName, (event) => {
@@ -757,6 +793,7 @@ export default function InstallWizard() {
           showRestoreSaveDataOption={preservedSaveDataAvailable}
           restoreSaveData={restoreSaveData}
           onRestoreChange={setRestoreSaveData}
+          antivirusProduct={antivirusProduct}
           installing={installInProgress}
           onInstall={onConfirmInstall}
           onBack={onBack}
@@ -16,6 +16,8 @@ interface ConfirmStepProps {
   showRestoreSaveDataOption: boolean;
   restoreSaveData: boolean;
   onRestoreChange: (value: boolean) => void;
+  // リアルタイム保護が有効なウイルス対策製品名。検出なし・照会不可なら null。
+  antivirusProduct: string | null;
   installing: boolean;
   onInstall: () => void;
   onBack: () => void;
@@ -35,6 +37,7 @@ export default function ConfirmStep({
   showRestoreSaveDataOption,
   restoreSaveData,
   onRestoreChange,
+  antivirusProduct,
   installing,
   onInstall,
   onBack,
@@ -125,6 +128,11 @@ export default function ConfirmStep({
             {t("launcher.restoreSavedDataOnInstall")}
           </label>
         )}
+        {antivirusProduct && (
+          <p className="confirm-av-tip">
+            {t("installFlow.antivirusTip", { product: antivirusProduct })}
+          </p>
+        )}
       </div>
       <div className="confirm-actions">
         <button type="button" className="btn-primary" onClick={onInstall} disabled={installing}>
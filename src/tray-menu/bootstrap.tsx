@@ -1,3 +1,4 @@
+import { listen } from "@tauri-apps/api/event";
 import { getCurrentWindow } from "@tauri-apps/api/window";
 import { useEffect, useMemo, useState } from "react";
 import { createRoot } from "react-dom/client";
@@ -8,8 +9,11 @@ import {
   trayShowMainWindow,
 } from "../app/services/tauriClient";
 import { applyTheme, getStoredTheme, initTheme } from "../app/theme";
+import type { LauncherSettings } from "../app/types";
 import snrLogo from "../assets/snr_logo.png";
 
+const SETTINGS_CHANGED_EVENT = "settings-changed";
+
 type TrayLocale = "ja" | "en";
 
 interface TrayLabels {
@@ -64,6 +68,13 @@ function TrayMenuApp() {
         setLocale(settings.uiLocale === "en" ? "en" : "ja");
       })
       .catch(() => undefined);
+    // 表示中のメインウィンドウで言語が切り替わった場合も、再生成を待たずに追従する。
+    const unlisten = listen<LauncherSettings>(SETTINGS_CHANGED_EVENT, (event) => {
+      setLocale(event.payload.uiLocale === "en" ? "en" : "ja");
+    });
+    return () => {
+      void unlisten.then((dispose) => dispose());
+    };
   }, []);
 
   useEffect(() => {